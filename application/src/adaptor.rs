@@ -1,9 +1,35 @@
 mod account;
 mod profile;
-mod rest_api;
+mod session;
+mod oauth;
+mod verification;
+mod follow;
+mod note;
+mod hashtag;
+mod reaction;
+mod boost;
+mod timeline;
+mod activitypub;
+mod discovery;
+mod signature;
+mod inbox;
+mod notification;
 
 pub use self::{
     account::*,
     profile::*,
-    rest_api::*
+    session::*,
+    oauth::*,
+    verification::*,
+    follow::*,
+    note::*,
+    hashtag::*,
+    reaction::*,
+    boost::*,
+    timeline::*,
+    activitypub::*,
+    discovery::*,
+    signature::*,
+    inbox::*,
+    notification::*,
 };
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{transfer::{AccountDto, CreateAccountDto}, ApplicationError};
+use crate::{transfer::{AccountDto, CreateAccountDto, UpdateAccountDto, LoginDto, SessionDto}, ApplicationError};
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 #[async_trait::async_trait]
@@ -6,8 +6,26 @@ pub trait CreateAccountAdaptor: 'static + Send + Sync {
     async fn create(&self, account: CreateAccountDto) -> Result<AccountDto, ApplicationError>;
 }
 
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait UpdateAccountAdaptor: 'static + Send + Sync {
+    async fn update(&self, id: i64, account: UpdateAccountDto) -> Result<AccountDto, ApplicationError>;
+}
+
 #[cfg_attr(feature = "mock", mockall::automock)]
 #[async_trait::async_trait]
 pub trait DeleteAccountAdaptor: 'static + Send + Sync {
     async fn delete(&self, id: i64) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait LoginAdaptor: 'static + Send + Sync {
+    async fn login(&self, login: LoginDto) -> Result<SessionDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetAccountAdaptor: 'static + Send + Sync {
+    async fn get_by_name(&self, name: String) -> Result<AccountDto, ApplicationError>;
 }
\ No newline at end of file
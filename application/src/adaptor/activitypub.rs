@@ -0,0 +1,10 @@
+use crate::{
+    transfer::ActorDto,
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetActorAdaptor: 'static + Send + Sync {
+    async fn actor(&self, name: String) -> Result<ActorDto, ApplicationError>;
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use uuid::Uuid;
+
+use crate::{
+    transfer::{BoostDto, BoostNoteDto},
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait BoostNoteAdaptor: 'static + Send + Sync {
+    /// Returns the existing boost if `account` has boosted the note already.
+    async fn boost(&self, account: i64, note: Uuid, boost: BoostNoteDto) -> Result<BoostDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait UnboostNoteAdaptor: 'static + Send + Sync {
+    async fn unboost(&self, account: i64, note: Uuid) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetBoostsAdaptor: 'static + Send + Sync {
+    /// Boosts of the note, oldest first.
+    async fn boosted_by(&self, viewer: Option<i64>, note: Uuid) -> Result<Vec<BoostDto>, ApplicationError>;
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use crate::{
+    transfer::{WebFingerDto, HostMetaDto, NodeInfoLinksDto, NodeInfoDto},
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait WebFingerAdaptor: 'static + Send + Sync {
+    /// `resource` is either `acct:name@host` or the actor url of a local account.
+    async fn webfinger(&self, resource: String) -> Result<WebFingerDto, ApplicationError>;
+    async fn host_meta(&self) -> Result<HostMetaDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetNodeInfoAdaptor: 'static + Send + Sync {
+    async fn links(&self) -> Result<NodeInfoLinksDto, ApplicationError>;
+    async fn nodeinfo(&self) -> Result<NodeInfoDto, ApplicationError>;
+}
\ No newline at end of file
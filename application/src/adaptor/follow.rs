@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::{transfer::{FollowDto, RelationshipDto}, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait FollowAccountAdaptor: 'static + Send + Sync {
+    async fn follow(&self, account: i64, target: String) -> Result<RelationshipDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait UnfollowAccountAdaptor: 'static + Send + Sync {
+    async fn unfollow(&self, account: i64, target: String) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetFollowersAdaptor: 'static + Send + Sync {
+    async fn followers(&self, name: String) -> Result<Vec<FollowDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetFollowingAdaptor: 'static + Send + Sync {
+    async fn following(&self, name: String) -> Result<Vec<FollowDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetFollowRequestsAdaptor: 'static + Send + Sync {
+    async fn requests(&self, account: i64) -> Result<Vec<FollowDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait AcceptFollowRequestAdaptor: 'static + Send + Sync {
+    async fn accept(&self, account: i64, id: Uuid) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RejectFollowRequestAdaptor: 'static + Send + Sync {
+    async fn reject(&self, account: i64, id: Uuid) -> Result<(), ApplicationError>;
+}
\ No newline at end of file
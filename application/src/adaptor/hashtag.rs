@@ -0,0 +1,8 @@
+use crate::{transfer::{NoteDto, PaginationDto}, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetHashtagNotesAdaptor: 'static + Send + Sync {
+    /// Public notes tagged with `name`, newest first. `name` is normalized before the lookup.
+    async fn notes(&self, name: String, page: PaginationDto) -> Result<Vec<NoteDto>, ApplicationError>;
+}
\ No newline at end of file
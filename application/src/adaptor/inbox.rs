@@ -0,0 +1,12 @@
+use crate::{
+    transfer::{ActivityDto, SignerDto},
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReceiveActivityAdaptor: 'static + Send + Sync {
+    /// `recipient` is the name of the account whose inbox received the activity,
+    /// or `None` for the shared inbox.
+    async fn receive(&self, signer: SignerDto, recipient: Option<String>, activity: ActivityDto) -> Result<(), ApplicationError>;
+}
\ No newline at end of file
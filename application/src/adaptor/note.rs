@@ -0,0 +1,28 @@
+use uuid::Uuid;
+
+use crate::{transfer::{NoteDto, NoteContextDto, CreateNoteDto}, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait CreateNoteAdaptor: 'static + Send + Sync {
+    async fn create(&self, account: i64, note: CreateNoteDto) -> Result<NoteDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetNoteAdaptor: 'static + Send + Sync {
+    /// `viewer` is `None` for anonymous reads. Notes hidden from `viewer` are reported as missing.
+    async fn get(&self, viewer: Option<i64>, id: Uuid) -> Result<NoteDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetNoteContextAdaptor: 'static + Send + Sync {
+    async fn context(&self, viewer: Option<i64>, id: Uuid) -> Result<NoteContextDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait DeleteNoteAdaptor: 'static + Send + Sync {
+    async fn delete(&self, account: i64, id: Uuid) -> Result<(), ApplicationError>;
+}
\ No newline at end of file
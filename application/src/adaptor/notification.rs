@@ -0,0 +1,8 @@
+use crate::{transfer::{NotificationDto, PaginationDto}, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetNotificationsAdaptor: 'static + Send + Sync {
+    /// Notifications of `account`, newest first.
+    async fn notifications(&self, account: i64, page: PaginationDto) -> Result<Vec<NotificationDto>, ApplicationError>;
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+use crate::{
+    transfer::{
+        ApplicationDto, RegisterApplicationDto,
+        AuthorizeDto, AuthorizationRequestDto, AuthorizationCodeDto,
+        TokenRequestDto, TokenDto, RevokeTokenDto
+    },
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RegisterApplicationAdaptor: 'static + Send + Sync {
+    async fn register(&self, app: RegisterApplicationDto) -> Result<ApplicationDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait VerifyAuthorizationAdaptor: 'static + Send + Sync {
+    async fn verify(&self, request: AuthorizeDto) -> Result<AuthorizationRequestDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait AuthorizeAdaptor: 'static + Send + Sync {
+    async fn authorize(&self, account: i64, request: AuthorizeDto) -> Result<AuthorizationCodeDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait IssueTokenAdaptor: 'static + Send + Sync {
+    async fn issue(&self, request: TokenRequestDto) -> Result<TokenDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RevokeTokenAdaptor: 'static + Send + Sync {
+    async fn revoke(&self, request: RevokeTokenDto) -> Result<(), ApplicationError>;
+}
\ No newline at end of file
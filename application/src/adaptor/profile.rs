@@ -13,4 +13,11 @@ pub trait CreateProfileAdaptor: 'static + Send + Sync {
 #[async_trait::async_trait]
 pub trait UpdateProfileAdaptor: 'static + Send + Sync {
     async fn update(&self, account: i64, profile: UpdateProfileDto) -> Result<ProfileDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetProfileAdaptor: 'static + Send + Sync {
+    async fn get_by_id(&self, account: i64) -> Result<ProfileDto, ApplicationError>;
+    async fn get_by_name(&self, name: String) -> Result<ProfileDto, ApplicationError>;
 }
\ No newline at end of file
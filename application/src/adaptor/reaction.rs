@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::{
+    transfer::{CreateReactionDto, CreateReactionAssetDto, ReactionAssetDto, ReactionCountDto},
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReactToNoteAdaptor: 'static + Send + Sync {
+    /// Replaces the reaction `account` already has on the note. Returns the updated counts.
+    async fn react(&self, account: i64, note: Uuid, reaction: CreateReactionDto) -> Result<Vec<ReactionCountDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait UnreactToNoteAdaptor: 'static + Send + Sync {
+    async fn unreact(&self, account: i64, note: Uuid) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetReactionsAdaptor: 'static + Send + Sync {
+    async fn reactions(&self, viewer: Option<i64>, note: Uuid) -> Result<Vec<ReactionCountDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RegisterReactionAssetAdaptor: 'static + Send + Sync {
+    /// Only administrators can register assets.
+    async fn register(&self, account: i64, asset: CreateReactionAssetDto) -> Result<ReactionAssetDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait DeleteReactionAssetAdaptor: 'static + Send + Sync {
+    /// Only administrators can delete assets. Reactions using the asset go away with it.
+    async fn delete(&self, account: i64, id: Uuid) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetReactionAssetsAdaptor: 'static + Send + Sync {
+    async fn assets(&self) -> Result<Vec<ReactionAssetDto>, ApplicationError>;
+}
\ No newline at end of file
@@ -1,4 +0,0 @@
-#[async_trait::async_trait]
-pub trait RestApiAdaptor: 'static + Send + Sync {
-    // Todo: Impl WebApi Interaction
-}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use crate::{transfer::CredentialDto, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait AuthenticateAdaptor: 'static + Send + Sync {
+    async fn authenticate(&self, token: String) -> Result<CredentialDto, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait LogoutAdaptor: 'static + Send + Sync {
+    async fn logout(&self, token: String) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RevokeSessionAdaptor: 'static + Send + Sync {
+    async fn revoke_all(&self, account: i64) -> Result<(), ApplicationError>;
+}
\ No newline at end of file
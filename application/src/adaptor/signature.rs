@@ -0,0 +1,10 @@
+use crate::{
+    transfer::{SignedRequestDto, SignerDto},
+    ApplicationError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait VerifySignatureAdaptor: 'static + Send + Sync {
+    async fn verify(&self, request: SignedRequestDto) -> Result<SignerDto, ApplicationError>;
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::{transfer::{PaginationDto, TimelineEntryDto}, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetAccountNotesAdaptor: 'static + Send + Sync {
+    /// Notes and boosts of the account named `name` that `viewer` can see, newest first.
+    async fn notes(&self, viewer: Option<i64>, name: String, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetHomeTimelineAdaptor: 'static + Send + Sync {
+    /// Notes and boosts of `account` and the accounts it follows, newest first.
+    async fn home(&self, account: i64, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetLocalTimelineAdaptor: 'static + Send + Sync {
+    /// Public notes written on this server, newest first.
+    async fn local(&self, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait GetFederatedTimelineAdaptor: 'static + Send + Sync {
+    /// Public notes known to this server, newest first.
+    async fn federated(&self, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError>;
+}
\ No newline at end of file
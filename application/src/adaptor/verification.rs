@@ -0,0 +1,25 @@
+use crate::{transfer::{VerifyAccountDto, RequestPasswordResetDto, ResetPasswordDto}, ApplicationError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait VerifyAccountAdaptor: 'static + Send + Sync {
+    async fn verify(&self, verify: VerifyAccountDto) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ResendVerificationAdaptor: 'static + Send + Sync {
+    async fn resend(&self, account: i64) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RequestPasswordResetAdaptor: 'static + Send + Sync {
+    async fn request(&self, request: RequestPasswordResetDto) -> Result<(), ApplicationError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ResetPasswordAdaptor: 'static + Send + Sync {
+    async fn reset(&self, reset: ResetPasswordDto) -> Result<(), ApplicationError>;
+}
\ No newline at end of file
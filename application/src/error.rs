@@ -8,8 +8,29 @@ pub enum ApplicationError {
         entity: &'static str,
         id: String
     },
+    #[error("`{id}:{entity}` already exists.")]
+    Conflict {
+        entity: &'static str,
+        id: String
+    },
     #[error("this value illegal. {0}")]
     Convert(String),
+    /// A request field failed validation. `field` is the name used in the request body.
+    #[error("`{field}` is invalid. {reason}")]
+    InvalidField {
+        field: &'static str,
+        reason: String
+    },
+    #[error("failed to authenticate.")]
+    Unauthorized,
+    #[error("insufficient permission. {0}")]
+    Forbidden(String),
+    /// Error defined by RFC 6749 Section 5.2, such as `invalid_grant`.
+    #[error("{error}: {description}")]
+    OAuth {
+        error: &'static str,
+        description: String
+    },
     #[error(transparent)]
     External(anyhow::Error)
 }
@@ -19,9 +40,21 @@ impl From<KernelError> for ApplicationError {
         match kernel {
             KernelError::NotFound { method, entity, id } 
               => ApplicationError::NotFound { method, entity, id },
+            KernelError::Conflict { entity, id } => ApplicationError::Conflict { entity, id },
             KernelError::Convert(msg) => ApplicationError::Convert(msg),
             KernelError::External(err) => ApplicationError::External(err),
             KernelError::Driver(err) => ApplicationError::External(err)
         }
     }
+}
+
+impl ApplicationError {
+    /// Attributes a conversion failure to the request field `field`.
+    /// Other errors are passed through unchanged.
+    pub fn field(field: &'static str) -> impl FnOnce(KernelError) -> ApplicationError {
+        move |e| match e {
+            KernelError::Convert(reason) => ApplicationError::InvalidField { field, reason },
+            other => other.into()
+        }
+    }
 }
\ No newline at end of file
@@ -1,38 +1,154 @@
 use kernel::{
-    repository::AccountRepository, 
-    entities::{AccountId, Account}
+    repository::{
+        AccountRepository, ConfidentialRepository, DeliveryRepository, FollowRepository, SessionRepository,
+        TimelineCacheRepository, VerificationRepository
+    },
+    entities::{
+        AccountId, Account, AccountName, AccountTypes, Address, Confidential, ConfidentialId, DestructUpdateTime,
+        FollowState, IsLocked, IsBot, Password, ReservedNames, Session, SessionToken, UpdateTime
+    },
+    service::Mailer
 };
 use time::OffsetDateTime;
 
 use crate::{
-    adaptor::{CreateAccountAdaptor, DeleteAccountAdaptor},
-    transfer::{AccountDto, CreateAccountDto}, ApplicationError
+    adaptor::{CreateAccountAdaptor, UpdateAccountAdaptor, DeleteAccountAdaptor, GetAccountAdaptor, LoginAdaptor},
+    transfer::{AccountDto, CreateAccountDto, UpdateAccountDto, LoginDto, SessionDto},
+    service::{generate_key, hash_password, invalidate_home, verify_password},
+    ApplicationError
 };
 
-pub struct CreateAccountInteractor<T> {
-    repo: T
+use super::{follow::answer, verification::issue_verification};
+
+pub struct CreateAccountInteractor<A, C, V, M> {
+    account_repo: A,
+    confidential_repo: C,
+    verification_repo: V,
+    mailer: M,
+    reserved: ReservedNames
 }
 
-impl<T> CreateAccountInteractor<T> {
-    pub fn new(repo: T) -> Self {
-        Self { repo }
+impl<A, C, V, M> CreateAccountInteractor<A, C, V, M> {
+    pub fn new(account_repo: A, confidential_repo: C, verification_repo: V, mailer: M, reserved: ReservedNames) -> Self {
+        Self { account_repo, confidential_repo, verification_repo, mailer, reserved }
     }
 }
 
 #[async_trait::async_trait]
-impl<T> CreateAccountAdaptor for CreateAccountInteractor<T>
-  where T: AccountRepository
+impl<A, C, V, M> CreateAccountAdaptor for CreateAccountInteractor<A, C, V, M>
+  where A: AccountRepository,
+        C: ConfidentialRepository,
+        V: VerificationRepository,
+        M: Mailer
 {
     async fn create(&self, account: CreateAccountDto) -> Result<AccountDto, ApplicationError> {
         let id = AccountId::default();
-        let CreateAccountDto { name, bot } = account;
-        let account = Account::new(id, name, bot, OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
+        let CreateAccountDto { name, bot, address, pass } = account;
+
+        let name = AccountName::parse(name, &self.reserved)
+            .map_err(ApplicationError::field("name"))?;
+        if self.account_repo.find_by_name(&name).await?.is_some() {
+            return Err(ApplicationError::Conflict {
+                entity: "account",
+                id: name.into()
+            });
+        }
+
+        let address = Address::try_from(address)
+            .map_err(ApplicationError::field("address"))?;
+        if self.confidential_repo.find_by_address(&address).await?.is_some() {
+            return Err(ApplicationError::Conflict {
+                entity: "address",
+                id: address.into()
+            });
+        }
+
+        let pass = hash_password(pass).await
+            .map_err(ApplicationError::field("pass"))?;
+        let key = generate_key(&id).await?;
 
-        self.repo.create(&account).await?;
+        let (created_at, updated_at) = (OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
+        let account = Account::new(id, name, bot, false, created_at, updated_at);
+        let confidential = Confidential::new(ConfidentialId::default(), id, created_at, updated_at, address, pass, false);
+
+        // The checks above give the usual answer. A concurrent signup that slips past them still conflicts here.
+        self.account_repo.register(&account, &confidential, &key).await?;
+
+        // The account exists either way. A lost mail can be sent again with `ResendVerificationAdaptor`.
+        if let Err(e) = issue_verification(&self.verification_repo, &self.mailer, &confidential).await {
+            tracing::warn!("failed to send verification mail. {:?}", e);
+        }
 
         Ok(account.into())
     }
 }
+
+pub struct UpdateAccountInteractor<A, D, F, K> {
+    account_repo: A,
+    delivery_repo: D,
+    follow_repo: F,
+    timeline_cache: K,
+    host: String
+}
+
+impl<A, D, F, K> UpdateAccountInteractor<A, D, F, K> {
+    pub fn new(account_repo: A, delivery_repo: D, follow_repo: F, timeline_cache: K, host: impl Into<String>) -> Self {
+        Self { account_repo, delivery_repo, follow_repo, timeline_cache, host: host.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, D, F, K> UpdateAccountAdaptor for UpdateAccountInteractor<A, D, F, K>
+  where A: AccountRepository,
+        D: DeliveryRepository,
+        F: FollowRepository,
+        K: TimelineCacheRepository
+{
+    async fn update(&self, id: i64, account: UpdateAccountDto) -> Result<AccountDto, ApplicationError> {
+        let id = AccountId::new(id);
+
+        let Some(found) = self.account_repo.find_by_id(&id).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "update",
+                entity: "account",
+                id: format!("{:?}", id)
+            });
+        };
+
+        let was_locked = *found.locked().as_ref();
+
+        let mut destructed = found.into_destruct();
+        let DestructUpdateTime { created_at, .. } = destructed.date.into_destruct();
+
+        let UpdateAccountDto { bot, locked } = account;
+        if let Some(bot) = bot {
+            destructed.bot = IsBot::new(bot);
+        }
+        if let Some(locked) = locked {
+            destructed.locked = IsLocked::new(locked);
+        }
+        destructed.date = UpdateTime::new(created_at, OffsetDateTime::now_utc());
+
+        let patched = destructed.freeze();
+
+        self.account_repo.update(&patched).await?;
+
+        // Nobody is left to approve the pending requests once the account is unlocked.
+        if was_locked && !*patched.locked().as_ref() {
+            for request in self.follow_repo.find_requests(&AccountTypes::Local(id)).await? {
+                let mut request = request.into_destruct();
+                request.state = FollowState::Accepted;
+                let request = request.freeze();
+                self.follow_repo.update(&request).await?;
+                invalidate_home(&self.timeline_cache, request.source()).await?;
+                answer(&self.account_repo, &self.delivery_repo, &self.host, &request, "Accept").await?;
+            }
+        }
+
+        Ok(patched.into())
+    }
+}
+
 pub struct DeleteAccountInteractor<T> {
     repo: T
 }
@@ -62,4 +178,173 @@ impl<T> DeleteAccountAdaptor for DeleteAccountInteractor<T>
 
         Ok(())
     }
+}
+
+pub struct LoginInteractor<A, C, S> {
+    account_repo: A,
+    confidential_repo: C,
+    session_repo: S
+}
+
+impl<A, C, S> LoginInteractor<A, C, S> {
+    pub fn new(account_repo: A, confidential_repo: C, session_repo: S) -> Self {
+        Self { account_repo, confidential_repo, session_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, C, S> LoginAdaptor for LoginInteractor<A, C, S>
+  where A: AccountRepository,
+        C: ConfidentialRepository,
+        S: SessionRepository
+{
+    async fn login(&self, login: LoginDto) -> Result<SessionDto, ApplicationError> {
+        let LoginDto { address, pass } = login;
+
+        // Unknown address and wrong password must be indistinguishable to the caller.
+        let Ok(address) = Address::try_from(address) else {
+            verify_password(Password::dummy(), pass).await?;
+            return Err(ApplicationError::Unauthorized);
+        };
+
+        let Some(confidential) = self.confidential_repo.find_by_address(&address).await? else {
+            verify_password(Password::dummy(), pass).await?;
+            return Err(ApplicationError::Unauthorized);
+        };
+
+        if !verify_password(confidential.pass().clone(), pass).await? {
+            return Err(ApplicationError::Unauthorized);
+        }
+
+        let Some(account) = self.account_repo.find_by_id(confidential.account()).await? else {
+            return Err(ApplicationError::Unauthorized);
+        };
+
+        let created_at = OffsetDateTime::now_utc();
+        let session = Session::new(
+            SessionToken::default(),
+            *account.id(),
+            created_at,
+            created_at + Session::LIFETIME
+        );
+
+        self.session_repo.create(&session).await?;
+
+        Ok(session.into())
+    }
+}
+
+pub struct GetAccountInteractor<T> {
+    repo: T
+}
+
+impl<T> GetAccountInteractor<T> {
+    pub fn new(repo: T) -> Self {
+        Self { repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> GetAccountAdaptor for GetAccountInteractor<T>
+  where T: AccountRepository
+{
+    async fn get_by_name(&self, name: String) -> Result<AccountDto, ApplicationError> {
+        let name = AccountName::new(name);
+
+        let Some(account) = self.repo.find_by_name(&name).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "get_by_name",
+                entity: "account",
+                id: name.into()
+            });
+        };
+
+        Ok(account.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{
+        repository::{
+            MockAccountRepository, MockConfidentialRepository, MockDeliveryRepository, MockFollowRepository, MockSessionRepository,
+            MockTimelineCacheRepository
+        },
+        entities::{Account, AccountId, AccountTypes, Follow, FollowState}
+    };
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use crate::{
+        adaptor::{LoginAdaptor, UpdateAccountAdaptor},
+        transfer::{LoginDto, UpdateAccountDto},
+        ApplicationError
+    };
+
+    use super::{LoginInteractor, UpdateAccountInteractor};
+
+    #[tokio::test]
+    async fn test_unlock() {
+        let now = OffsetDateTime::now_utc();
+        let account = Account::new(AccountId::default(), "shuttle", false, true, now, now);
+        let id = *account.id();
+        let local = AccountId::default();
+        let remote = "https://remote.example/users/alice";
+        let requests = vec![
+            Follow::new(Uuid::new_v4(), local, id, FollowState::Pending, now),
+            Follow::new(Uuid::new_v4(), AccountTypes::Federate(remote.to_string()), id, FollowState::Pending, now)
+        ];
+
+        let mut account_repo = MockAccountRepository::new();
+        let found = account.clone();
+        account_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(found.clone())));
+        account_repo.expect_update()
+            .withf(|update| !*update.locked().as_ref())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut follow_repo = MockFollowRepository::new();
+        follow_repo.expect_find_requests()
+            .returning(move |_| Ok(requests.clone()));
+        follow_repo.expect_update()
+            .withf(|update| update.state().is_accepted())
+            .times(2)
+            .returning(|_| Ok(()));
+
+        // Only the local follower has a home timeline here.
+        let mut timeline_cache = MockTimelineCacheRepository::new();
+        timeline_cache.expect_delete()
+            .withf(move |account| *account == local)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // The remote follower is told that the request was accepted.
+        let mut delivery_repo = MockDeliveryRepository::new();
+        delivery_repo.expect_create()
+            .withf(move |delivery| delivery.host() == "remote.example"
+                && delivery.recipients() == [remote]
+                && delivery.activity().contains(r#""type":"Accept""#))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let interactor = UpdateAccountInteractor::new(account_repo, delivery_repo, follow_repo, timeline_cache, "local.example");
+        let updated = interactor.update(id.into(), UpdateAccountDto { bot: None, locked: Some(false) }).await.unwrap();
+        assert!(!updated.locked);
+    }
+
+    #[tokio::test]
+    async fn test_login_unknown() {
+        let mut confidential_repo = MockConfidentialRepository::new();
+        confidential_repo.expect_find_by_address()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        // Neither a malformed nor an unknown address is told apart from a wrong password.
+        let interactor = LoginInteractor::new(MockAccountRepository::new(), confidential_repo, MockSessionRepository::new());
+        for address in ["shuttle", "shuttle@local.example"] {
+            let login = LoginDto::new(address, "correct horse battery staple");
+            assert!(matches!(interactor.login(login).await, Err(ApplicationError::Unauthorized)));
+        }
+    }
 }
\ No newline at end of file
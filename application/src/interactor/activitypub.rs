@@ -0,0 +1,50 @@
+use kernel::{
+    entities::AccountName,
+    repository::{AccountRepository, AccountKeyRepository, ProfileRepository}
+};
+
+use crate::{
+    adaptor::GetActorAdaptor,
+    service::{account_key, ActorUrls},
+    transfer::ActorDto,
+    ApplicationError
+};
+
+pub struct GetActorInteractor<A, P, K> {
+    account_repo: A,
+    profile_repo: P,
+    key_repo: K,
+    host: String
+}
+
+impl<A, P, K> GetActorInteractor<A, P, K> {
+    /// `host` is the domain of this server, which every actor url is built on.
+    pub fn new(account_repo: A, profile_repo: P, key_repo: K, host: impl Into<String>) -> Self {
+        Self { account_repo, profile_repo, key_repo, host: host.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, P, K> GetActorAdaptor for GetActorInteractor<A, P, K>
+  where A: AccountRepository,
+        P: ProfileRepository,
+        K: AccountKeyRepository
+{
+    async fn actor(&self, name: String) -> Result<ActorDto, ApplicationError> {
+        let name = AccountName::new(name);
+
+        let Some(account) = self.account_repo.find_by_name(&name).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "actor",
+                entity: "account",
+                id: name.into()
+            });
+        };
+
+        let profile = self.profile_repo.find_by_account_id(account.id()).await?;
+        let key = account_key(&self.key_repo, account.id()).await?;
+        let urls = ActorUrls::new(&self.host, account.name());
+
+        Ok(ActorDto::new(&account, profile.as_ref(), &key, urls))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use kernel::{
+    repository::{AccountRepository, BoostRepository, FollowRepository, NoteRepository, TimelineCacheRepository},
+    entities::{AccountId, AccountTypes, Boost, BoostId, NoteId, NoteTypes, TimelineCursor, Visibility}
+};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    adaptor::{BoostNoteAdaptor, UnboostNoteAdaptor, GetBoostsAdaptor},
+    transfer::{BoostDto, BoostNoteDto},
+    service::{check_reshare, fan_out, find_accounts, visible_note},
+    ApplicationError
+};
+
+pub struct BoostNoteInteractor<A, B, F, N, K> {
+    account_repo: A,
+    boost_repo: B,
+    follow_repo: F,
+    note_repo: N,
+    timeline_cache: K
+}
+
+impl<A, B, F, N, K> BoostNoteInteractor<A, B, F, N, K> {
+    pub fn new(account_repo: A, boost_repo: B, follow_repo: F, note_repo: N, timeline_cache: K) -> Self {
+        Self { account_repo, boost_repo, follow_repo, note_repo, timeline_cache }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, B, F, N, K> BoostNoteAdaptor for BoostNoteInteractor<A, B, F, N, K>
+  where A: AccountRepository,
+        B: BoostRepository,
+        F: FollowRepository,
+        N: NoteRepository,
+        K: TimelineCacheRepository
+{
+    async fn boost(&self, account: i64, note: Uuid, boost: BoostNoteDto) -> Result<BoostDto, ApplicationError> {
+        let account = AccountId::new(account);
+        let note = visible_note(&self.follow_repo, &self.note_repo, Some(&account), &NoteId::new(note), "boost").await?;
+
+        let BoostNoteDto { visibility } = boost;
+        let visibility = visibility
+            .map(|visibility| Visibility::try_from(visibility.as_str()))
+            .transpose()
+            .map_err(ApplicationError::field("visibility"))?
+            .unwrap_or(*note.visibility());
+        if visibility == Visibility::Direct {
+            return Err(ApplicationError::InvalidField {
+                field: "visibility",
+                reason: "a boost cannot be direct.".to_string()
+            });
+        }
+        check_reshare(&account, &note, &visibility)?;
+
+        let booster = AccountTypes::Local(account);
+        let target = NoteTypes::Local(*note.id());
+
+        let boost = Boost::new(BoostId::default(), booster.clone(), target.clone(), visibility, false, OffsetDateTime::now_utc());
+        self.boost_repo.create(&boost).await?;
+
+        // Boosting twice keeps the first boost.
+        let stored = self.boost_repo.find(&booster, &target).await?;
+        let boost = match stored {
+            Some(stored) if stored.id() != boost.id() => stored,
+            _ => {
+                let entry = TimelineCursor::new(*boost.id().as_ref(), *boost.created_at().as_ref());
+                if let Err(e) = fan_out(&self.follow_repo, &self.timeline_cache, &account, &entry, boost.visibility(), &[]).await {
+                    tracing::warn!("failed to push a boost into home timelines. {:?}", e);
+                }
+                boost
+            }
+        };
+
+        let accounts = find_accounts(&self.account_repo, [&booster]).await?;
+        BoostDto::new(&boost, &accounts)
+            .ok_or_else(|| ApplicationError::NotFound {
+                method: "boost",
+                entity: "account",
+                id: account.as_ref().to_string()
+            })
+    }
+}
+
+pub struct UnboostNoteInteractor<B> {
+    boost_repo: B
+}
+
+impl<B> UnboostNoteInteractor<B> {
+    pub fn new(boost_repo: B) -> Self {
+        Self { boost_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<B> UnboostNoteAdaptor for UnboostNoteInteractor<B>
+  where B: BoostRepository
+{
+    async fn unboost(&self, account: i64, note: Uuid) -> Result<(), ApplicationError> {
+        let account = AccountTypes::Local(AccountId::new(account));
+        let note = NoteTypes::Local(NoteId::new(note));
+
+        // Nothing to remove is not an error, so that retries are safe.
+        self.boost_repo.delete(&account, &note).await?;
+
+        Ok(())
+    }
+}
+
+pub struct GetBoostsInteractor<A, B, F, N> {
+    account_repo: A,
+    boost_repo: B,
+    follow_repo: F,
+    note_repo: N
+}
+
+impl<A, B, F, N> GetBoostsInteractor<A, B, F, N> {
+    pub fn new(account_repo: A, boost_repo: B, follow_repo: F, note_repo: N) -> Self {
+        Self { account_repo, boost_repo, follow_repo, note_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, B, F, N> GetBoostsAdaptor for GetBoostsInteractor<A, B, F, N>
+  where A: AccountRepository,
+        B: BoostRepository,
+        F: FollowRepository,
+        N: NoteRepository
+{
+    async fn boosted_by(&self, viewer: Option<i64>, note: Uuid) -> Result<Vec<BoostDto>, ApplicationError> {
+        let viewer = viewer.map(AccountId::new);
+        let note = visible_note(&self.follow_repo, &self.note_repo, viewer.as_ref(), &NoteId::new(note), "boosted_by").await?;
+
+        let boosts = self.boost_repo.find_by_note(&NoteTypes::Local(*note.id())).await?;
+        let accounts = find_accounts(&self.account_repo, boosts.iter().map(Boost::account)).await?;
+
+        Ok(boosts.iter()
+            .filter_map(|boost| BoostDto::new(boost, &accounts))
+            .collect())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use kernel::{
+    entities::AccountName,
+    repository::{AccountRepository, NoteRepository}
+};
+use time::{Duration, OffsetDateTime};
+
+use crate::{
+    adaptor::{WebFingerAdaptor, GetNodeInfoAdaptor},
+    service::{local_actor_name, ActorUrls},
+    transfer::{
+        WebFingerDto, LinkDto, HostMetaDto, NodeInfoLinksDto, NodeInfoDto,
+        SoftwareDto, ServicesDto, UsageDto, UsersDto, MetadataDto, NODEINFO_SCHEMA
+    },
+    ApplicationError
+};
+
+pub struct WebFingerInteractor<A> {
+    account_repo: A,
+    host: String
+}
+
+impl<A> WebFingerInteractor<A> {
+    /// `host` is the domain of this server. Resources on other servers are not found.
+    pub fn new(account_repo: A, host: impl Into<String>) -> Self {
+        Self { account_repo, host: host.into() }
+    }
+
+    /// `None` if `resource` belongs to another server.
+    fn parse<'a>(&self, resource: &'a str) -> Result<Option<&'a str>, ApplicationError> {
+        if let Some(acct) = resource.strip_prefix("acct:") {
+            let Some((name, host)) = acct.trim_start_matches('@').split_once('@') else {
+                return Err(ApplicationError::InvalidField {
+                    field: "resource",
+                    reason: "`acct:` resources must be `acct:name@host`.".to_string()
+                });
+            };
+            return Ok(Some(name).filter(|_| host.eq_ignore_ascii_case(&self.host)));
+        }
+
+        if resource.starts_with("https://") || resource.starts_with("http://") {
+            return Ok(local_actor_name(&self.host, resource));
+        }
+
+        Err(ApplicationError::InvalidField {
+            field: "resource",
+            reason: "must be an `acct:` uri or an actor url.".to_string()
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<A> WebFingerAdaptor for WebFingerInteractor<A>
+  where A: AccountRepository
+{
+    async fn webfinger(&self, resource: String) -> Result<WebFingerDto, ApplicationError> {
+        let not_found = || ApplicationError::NotFound {
+            method: "webfinger",
+            entity: "account",
+            id: resource.clone()
+        };
+
+        let name = self.parse(&resource)?.ok_or_else(not_found)?;
+        let account = self.account_repo.find_by_name(&AccountName::new(name)).await?
+            .ok_or_else(not_found)?;
+
+        let urls = ActorUrls::new(&self.host, account.name());
+
+        Ok(WebFingerDto {
+            subject: format!("acct:{}@{}", account.name().as_ref(), self.host),
+            aliases: vec![urls.id.clone()],
+            links: vec![LinkDto {
+                rel: "self",
+                kind: Some("application/activity+json"),
+                href: urls.id
+            }]
+        })
+    }
+
+    async fn host_meta(&self) -> Result<HostMetaDto, ApplicationError> {
+        Ok(HostMetaDto {
+            lrdd: format!("https://{}/.well-known/webfinger?resource={{uri}}", self.host)
+        })
+    }
+}
+
+pub struct GetNodeInfoInteractor<A, N> {
+    account_repo: A,
+    note_repo: N,
+    host: String
+}
+
+impl<A, N> GetNodeInfoInteractor<A, N> {
+    pub fn new(account_repo: A, note_repo: N, host: impl Into<String>) -> Self {
+        Self { account_repo, note_repo, host: host.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, N> GetNodeInfoAdaptor for GetNodeInfoInteractor<A, N>
+  where A: AccountRepository,
+        N: NoteRepository
+{
+    async fn links(&self) -> Result<NodeInfoLinksDto, ApplicationError> {
+        Ok(NodeInfoLinksDto {
+            links: vec![LinkDto {
+                rel: NODEINFO_SCHEMA,
+                kind: None,
+                href: format!("https://{}/nodeinfo/2.1", self.host)
+            }]
+        })
+    }
+
+    /// Active users are those who posted within the last 30 and 180 days.
+    async fn nodeinfo(&self) -> Result<NodeInfoDto, ApplicationError> {
+        let now = OffsetDateTime::now_utc();
+
+        Ok(NodeInfoDto {
+            version: "2.1",
+            software: SoftwareDto {
+                name: "shuttlepub",
+                version: env!("CARGO_PKG_VERSION")
+            },
+            protocols: vec!["activitypub"],
+            services: ServicesDto { inbound: Vec::new(), outbound: Vec::new() },
+            open_registrations: true,
+            usage: UsageDto {
+                users: UsersDto {
+                    total: self.account_repo.count().await?,
+                    active_month: self.note_repo.count_authors(&(now - Duration::days(30))).await?,
+                    active_halfyear: self.note_repo.count_authors(&(now - Duration::days(180))).await?
+                },
+                local_posts: self.note_repo.count().await?
+            },
+            metadata: MetadataDto {}
+        })
+    }
+}
\ No newline at end of file
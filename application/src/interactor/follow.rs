@@ -0,0 +1,298 @@
+use kernel::{
+    repository::{AccountRepository, DeliveryRepository, FollowRepository, TimelineCacheRepository},
+    entities::{Account, AccountId, AccountName, AccountTypes, Follow, FollowId, FollowState}
+};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    adaptor::{
+        FollowAccountAdaptor, UnfollowAccountAdaptor, GetFollowersAdaptor, GetFollowingAdaptor,
+        GetFollowRequestsAdaptor, AcceptFollowRequestAdaptor, RejectFollowRequestAdaptor
+    },
+    transfer::{FollowDto, FollowObjectDto, OutgoingActivityDto, RelationshipDto},
+    service::{enqueue, find_accounts, invalidate_home, ActorUrls},
+    ApplicationError
+};
+
+async fn find_by_name(repo: &impl AccountRepository, method: &'static str, name: String) -> Result<Account, ApplicationError> {
+    let name = AccountName::new(name);
+    repo.find_by_name(&name).await?
+        .ok_or_else(|| ApplicationError::NotFound {
+            method,
+            entity: "account",
+            id: name.into()
+        })
+}
+
+/// Resolves the local accounts in `ends` with a single lookup and builds the listing.
+/// Entries whose local account no longer exists are dropped.
+async fn listing(
+    repo: &impl AccountRepository,
+    ends: Vec<(AccountTypes, Follow)>
+) -> Result<Vec<FollowDto>, ApplicationError> {
+    let accounts = find_accounts(repo, ends.iter().map(|(account, _)| account)).await?;
+
+    Ok(ends.iter()
+        .filter_map(|(account, follow)| FollowDto::new(follow, account, &accounts))
+        .collect())
+}
+
+pub struct FollowAccountInteractor<A, F, K> {
+    account_repo: A,
+    follow_repo: F,
+    timeline_cache: K
+}
+
+impl<A, F, K> FollowAccountInteractor<A, F, K> {
+    pub fn new(account_repo: A, follow_repo: F, timeline_cache: K) -> Self {
+        Self { account_repo, follow_repo, timeline_cache }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F, K> FollowAccountAdaptor for FollowAccountInteractor<A, F, K>
+  where A: AccountRepository,
+        F: FollowRepository,
+        K: TimelineCacheRepository
+{
+    async fn follow(&self, account: i64, target: String) -> Result<RelationshipDto, ApplicationError> {
+        let source = AccountId::new(account);
+        let target = find_by_name(&self.account_repo, "follow", target).await?;
+
+        if *target.id() == source {
+            return Err(ApplicationError::Convert("cannot follow yourself.".to_string()));
+        }
+
+        let state = if *target.locked().as_ref() { FollowState::Pending } else { FollowState::Accepted };
+
+        // Following an already followed account is a no-op in the repository and keeps its state.
+        let follow = Follow::new(FollowId::default(), source, *target.id(), state, OffsetDateTime::now_utc());
+        self.follow_repo.create(&follow).await?;
+
+        let stored = self.follow_repo.find(follow.source(), follow.destination()).await?;
+
+        if stored.as_ref().is_some_and(|stored| stored.state().is_accepted()) {
+            invalidate_home(&self.timeline_cache, follow.source()).await?;
+        }
+
+        Ok(RelationshipDto::from(stored.as_ref()))
+    }
+}
+
+pub struct UnfollowAccountInteractor<A, F, K> {
+    account_repo: A,
+    follow_repo: F,
+    timeline_cache: K
+}
+
+impl<A, F, K> UnfollowAccountInteractor<A, F, K> {
+    pub fn new(account_repo: A, follow_repo: F, timeline_cache: K) -> Self {
+        Self { account_repo, follow_repo, timeline_cache }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F, K> UnfollowAccountAdaptor for UnfollowAccountInteractor<A, F, K>
+  where A: AccountRepository,
+        F: FollowRepository,
+        K: TimelineCacheRepository
+{
+    async fn unfollow(&self, account: i64, target: String) -> Result<(), ApplicationError> {
+        let source = AccountTypes::Local(AccountId::new(account));
+        let target = find_by_name(&self.account_repo, "unfollow", target).await?;
+
+        self.follow_repo.delete(&source, &AccountTypes::Local(*target.id())).await?;
+
+        invalidate_home(&self.timeline_cache, &source).await?;
+
+        Ok(())
+    }
+}
+
+pub struct GetFollowersInteractor<A, F> {
+    account_repo: A,
+    follow_repo: F
+}
+
+impl<A, F> GetFollowersInteractor<A, F> {
+    pub fn new(account_repo: A, follow_repo: F) -> Self {
+        Self { account_repo, follow_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F> GetFollowersAdaptor for GetFollowersInteractor<A, F>
+  where A: AccountRepository,
+        F: FollowRepository
+{
+    async fn followers(&self, name: String) -> Result<Vec<FollowDto>, ApplicationError> {
+        let account = find_by_name(&self.account_repo, "followers", name).await?;
+
+        let followers = self.follow_repo.find_followers(&AccountTypes::Local(*account.id())).await?
+            .into_iter()
+            .map(|follow| (follow.source().clone(), follow))
+            .collect();
+
+        listing(&self.account_repo, followers).await
+    }
+}
+
+pub struct GetFollowingInteractor<A, F> {
+    account_repo: A,
+    follow_repo: F
+}
+
+impl<A, F> GetFollowingInteractor<A, F> {
+    pub fn new(account_repo: A, follow_repo: F) -> Self {
+        Self { account_repo, follow_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F> GetFollowingAdaptor for GetFollowingInteractor<A, F>
+  where A: AccountRepository,
+        F: FollowRepository
+{
+    async fn following(&self, name: String) -> Result<Vec<FollowDto>, ApplicationError> {
+        let account = find_by_name(&self.account_repo, "following", name).await?;
+
+        let following = self.follow_repo.find_followings(&AccountTypes::Local(*account.id())).await?
+            .into_iter()
+            .map(|follow| (follow.destination().clone(), follow))
+            .collect();
+
+        listing(&self.account_repo, following).await
+    }
+}
+
+/// Pending follow `id` addressed to `account`. Requests to other accounts are reported as missing.
+async fn find_request(repo: &impl FollowRepository, method: &'static str, account: i64, id: Uuid) -> Result<Follow, ApplicationError> {
+    let id = FollowId::new(id);
+    repo.find_by_id(&id).await?
+        .filter(|follow| *follow.destination() == AccountTypes::Local(AccountId::new(account)))
+        .filter(|follow| !follow.state().is_accepted())
+        .ok_or_else(|| ApplicationError::NotFound {
+            method,
+            entity: "follow request",
+            id: id.as_ref().to_string()
+        })
+}
+
+/// Sends `kind` (`Accept` or `Reject`) for `request` to its source if that is a remote actor.
+pub(crate) async fn answer(
+    account_repo: &impl AccountRepository,
+    delivery_repo: &impl DeliveryRepository,
+    host: &str,
+    request: &Follow,
+    kind: &'static str
+) -> Result<(), ApplicationError> {
+    let (AccountTypes::Federate(actor), AccountTypes::Local(destination)) = (request.source(), request.destination()) else {
+        return Ok(());
+    };
+    let Some(account) = account_repo.find_by_id(destination).await? else {
+        return Ok(());
+    };
+
+    let urls = ActorUrls::new(host, account.name());
+    // The id of the `Follow` is not kept, so it is described by its actor and object instead.
+    let object = FollowObjectDto::new(None, actor, &urls.id);
+    let id = format!("{}#{}s/{}", urls.id, kind.to_lowercase(), request.id().as_ref());
+    let activity = OutgoingActivityDto::new(id, kind, urls.id, object);
+
+    enqueue(delivery_repo, host, &account, &activity, [actor.clone()]).await
+}
+
+pub struct GetFollowRequestsInteractor<A, F> {
+    account_repo: A,
+    follow_repo: F
+}
+
+impl<A, F> GetFollowRequestsInteractor<A, F> {
+    pub fn new(account_repo: A, follow_repo: F) -> Self {
+        Self { account_repo, follow_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F> GetFollowRequestsAdaptor for GetFollowRequestsInteractor<A, F>
+  where A: AccountRepository,
+        F: FollowRepository
+{
+    async fn requests(&self, account: i64) -> Result<Vec<FollowDto>, ApplicationError> {
+        let account = AccountTypes::Local(AccountId::new(account));
+
+        let requests = self.follow_repo.find_requests(&account).await?
+            .into_iter()
+            .map(|follow| (follow.source().clone(), follow))
+            .collect();
+
+        listing(&self.account_repo, requests).await
+    }
+}
+
+pub struct AcceptFollowRequestInteractor<A, D, F, K> {
+    account_repo: A,
+    delivery_repo: D,
+    follow_repo: F,
+    timeline_cache: K,
+    host: String
+}
+
+impl<A, D, F, K> AcceptFollowRequestInteractor<A, D, F, K> {
+    pub fn new(account_repo: A, delivery_repo: D, follow_repo: F, timeline_cache: K, host: impl Into<String>) -> Self {
+        Self { account_repo, delivery_repo, follow_repo, timeline_cache, host: host.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, D, F, K> AcceptFollowRequestAdaptor for AcceptFollowRequestInteractor<A, D, F, K>
+  where A: AccountRepository,
+        D: DeliveryRepository,
+        F: FollowRepository,
+        K: TimelineCacheRepository
+{
+    async fn accept(&self, account: i64, id: Uuid) -> Result<(), ApplicationError> {
+        let mut request = find_request(&self.follow_repo, "accept", account, id).await?.into_destruct();
+        request.state = FollowState::Accepted;
+        let request = request.freeze();
+
+        self.follow_repo.update(&request).await?;
+
+        invalidate_home(&self.timeline_cache, request.source()).await?;
+
+        answer(&self.account_repo, &self.delivery_repo, &self.host, &request, "Accept").await?;
+
+        Ok(())
+    }
+}
+
+pub struct RejectFollowRequestInteractor<A, D, F> {
+    account_repo: A,
+    delivery_repo: D,
+    follow_repo: F,
+    host: String
+}
+
+impl<A, D, F> RejectFollowRequestInteractor<A, D, F> {
+    pub fn new(account_repo: A, delivery_repo: D, follow_repo: F, host: impl Into<String>) -> Self {
+        Self { account_repo, delivery_repo, follow_repo, host: host.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, D, F> RejectFollowRequestAdaptor for RejectFollowRequestInteractor<A, D, F>
+  where A: AccountRepository,
+        D: DeliveryRepository,
+        F: FollowRepository
+{
+    async fn reject(&self, account: i64, id: Uuid) -> Result<(), ApplicationError> {
+        let request = find_request(&self.follow_repo, "reject", account, id).await?;
+
+        self.follow_repo.delete(request.source(), request.destination()).await?;
+
+        answer(&self.account_repo, &self.delivery_repo, &self.host, &request, "Reject").await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+use kernel::{
+    repository::{HashtagRepository, NoteRepository},
+    entities::{HashtagName, Pagination}
+};
+
+use crate::{
+    adaptor::GetHashtagNotesAdaptor,
+    transfer::{NoteDto, PaginationDto},
+    ApplicationError
+};
+
+pub struct GetHashtagNotesInteractor<H, N> {
+    hashtag_repo: H,
+    note_repo: N
+}
+
+impl<H, N> GetHashtagNotesInteractor<H, N> {
+    pub fn new(hashtag_repo: H, note_repo: N) -> Self {
+        Self { hashtag_repo, note_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<H, N> GetHashtagNotesAdaptor for GetHashtagNotesInteractor<H, N>
+  where H: HashtagRepository,
+        N: NoteRepository
+{
+    async fn notes(&self, name: String, page: PaginationDto) -> Result<Vec<NoteDto>, ApplicationError> {
+        let name = HashtagName::try_from(name.trim_start_matches('#'))
+            .map_err(ApplicationError::field("name"))?;
+
+        // A tag nobody has used yet is just empty.
+        let Some(hashtag) = self.hashtag_repo.find_by_name(&name).await? else {
+            return Ok(Vec::new());
+        };
+
+        let page = Pagination::from(page);
+
+        let notes = self.note_repo.find_by_hashtag(hashtag.id(), &page).await?;
+
+        Ok(notes.into_iter().map(Into::into).collect())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,690 @@
+use kernel::{
+    repository::{
+        AccountRepository, BoostRepository, DeliveryRepository, FollowRepository, NoteRepository,
+        ReactionRepository, ReceivedActivityRepository, RemoteNoteRepository
+    },
+    entities::{
+        AccountName, AccountTypes, Activity, ActivityKind, Boost, BoostId, Emoji, Follow, FollowId,
+        FollowState, NoteTypes, Reaction, ReactionContent, ReactionId, RemoteNote,
+        RemoteNoteId, Visibility
+    }
+};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    adaptor::ReceiveActivityAdaptor,
+    service::{
+        enqueue, is_visible_to_remote, local_actor_name, local_note_id, url_host, visibility_of,
+        ActorUrls
+    },
+    transfer::{ActivityDto, FollowObjectDto, ObjectDto, OutgoingActivityDto, SignerDto},
+    ApplicationError
+};
+
+/// Reaction stored for a `Like` that does not carry an emoji, such as a Mastodon favourite.
+const DEFAULT_LIKE: &str = "❤️";
+
+/// Reads `dto` into one of the activities the inbox acts on.
+/// Unknown types and activities missing what their handler needs are `None`.
+fn parse(dto: ActivityDto, host: &str) -> Option<Activity> {
+    let id = dto.id?;
+    let actor = dto.actor.as_ref().and_then(ObjectDto::id)?.to_string();
+    let object = dto.object;
+
+    let kind = match dto.kind.as_str() {
+        "Follow" => ActivityKind::Follow { object: object?.id()?.to_string() },
+        "Undo" => {
+            let ObjectDto::Object(object) = object? else {
+                // Only the id of the undone activity is not enough to tell what to revert.
+                return None;
+            };
+            ActivityKind::Undo { object: Box::new(parse(*object, host)?) }
+        },
+        "Create" => {
+            let ObjectDto::Object(note) = object? else {
+                return None;
+            };
+            ActivityKind::Create { note: remote_note(*note, host)? }
+        },
+        "Delete" => ActivityKind::Delete { object: object?.id()?.to_string() },
+        "Like" => {
+            let content = dto.misskey_reaction.or(dto.content)
+                .and_then(|content| Emoji::try_from(content.as_str()).ok());
+            ActivityKind::Like { object: object?.id()?.to_string(), content }
+        },
+        "Announce" => {
+            let visibility = visibility_of(&dto.to.into_vec(), &dto.cc.into_vec());
+            ActivityKind::Announce { object: object?.id()?.to_string(), visibility }
+        },
+        _ => return None
+    };
+
+    Some(Activity::new(id, actor, kind))
+}
+
+fn remote_note(dto: ActivityDto, host: &str) -> Option<RemoteNote> {
+    if dto.kind != "Note" {
+        return None;
+    }
+    let id = dto.id?;
+    let author = dto.attributed_to.as_ref().and_then(ObjectDto::id)?.to_string();
+    let in_reply_to = dto.in_reply_to.as_ref()
+        .and_then(ObjectDto::id)
+        .map(|url| match local_note_id(host, url) {
+            Some(id) => NoteTypes::Local(id),
+            None => NoteTypes::Federate(url.to_string())
+        });
+    let created_at = dto.published
+        .and_then(|published| OffsetDateTime::parse(&published, &Rfc3339).ok())
+        .unwrap_or_else(OffsetDateTime::now_utc);
+    let visibility = visibility_of(&dto.to.into_vec(), &dto.cc.into_vec());
+
+    Some(RemoteNote::new(
+        id,
+        author,
+        dto.content.unwrap_or_default(),
+        dto.summary.filter(|cw| !cw.is_empty()),
+        visibility,
+        in_reply_to,
+        created_at
+    ))
+}
+
+pub struct ReceiveActivityInteractor<A, B, D, F, I, N, O, R> {
+    account_repo: A,
+    boost_repo: B,
+    delivery_repo: D,
+    follow_repo: F,
+    received_repo: I,
+    note_repo: N,
+    remote_note_repo: O,
+    reaction_repo: R,
+    host: String
+}
+
+impl<A, B, D, F, I, N, O, R> ReceiveActivityInteractor<A, B, D, F, I, N, O, R> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_repo: A,
+        boost_repo: B,
+        delivery_repo: D,
+        follow_repo: F,
+        received_repo: I,
+        note_repo: N,
+        remote_note_repo: O,
+        reaction_repo: R,
+        host: impl Into<String>
+    ) -> Self {
+        Self {
+            account_repo,
+            boost_repo,
+            delivery_repo,
+            follow_repo,
+            received_repo,
+            note_repo,
+            remote_note_repo,
+            reaction_repo,
+            host: host.into()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, B, D, F, I, N, O, R> ReceiveActivityAdaptor for ReceiveActivityInteractor<A, B, D, F, I, N, O, R>
+  where A: AccountRepository,
+        B: BoostRepository,
+        D: DeliveryRepository,
+        F: FollowRepository,
+        I: ReceivedActivityRepository,
+        N: NoteRepository,
+        O: RemoteNoteRepository,
+        R: ReactionRepository
+{
+    async fn receive(&self, signer: SignerDto, recipient: Option<String>, activity: ActivityDto) -> Result<(), ApplicationError> {
+        if let Some(recipient) = recipient {
+            let name = AccountName::new(recipient);
+            if self.account_repo.find_by_name(&name).await?.is_none() {
+                return Err(ApplicationError::NotFound {
+                    method: "receive",
+                    entity: "account",
+                    id: name.into()
+                });
+            }
+        }
+
+        let kind = activity.kind.clone();
+        let Some(activity) = parse(activity, &self.host) else {
+            tracing::debug!("ignored an incoming `{}` activity.", kind);
+            return Ok(());
+        };
+
+        // Relayed activities would need their embedded signatures checked, which is not supported.
+        if activity.actor() != signer.owner {
+            return Err(ApplicationError::Unauthorized);
+        }
+
+        // Ids are recorded to drop redeliveries, so an actor must not claim the id of an activity from elsewhere.
+        let origin = url_host(activity.actor());
+        if origin.is_none() || url_host(activity.id().as_ref()) != origin {
+            return Err(ApplicationError::Forbidden("the activity id must be on the host of its actor.".to_string()));
+        }
+
+        // Claiming before handling keeps concurrent redeliveries from being applied twice.
+        if !self.received_repo.claim(activity.id()).await? {
+            return Ok(());
+        }
+
+        // A failed activity is released so that the sender's retry is handled rather than dropped.
+        if let Err(e) = self.handle(&activity).await {
+            self.received_repo.release(activity.id()).await?;
+            return Err(e);
+        }
+
+        Ok(())
+    }
+}
+
+impl<A, B, D, F, I, N, O, R> ReceiveActivityInteractor<A, B, D, F, I, N, O, R>
+  where A: AccountRepository,
+        B: BoostRepository,
+        D: DeliveryRepository,
+        F: FollowRepository,
+        I: ReceivedActivityRepository,
+        N: NoteRepository,
+        O: RemoteNoteRepository,
+        R: ReactionRepository
+{
+    async fn handle(&self, activity: &Activity) -> Result<(), ApplicationError> {
+        let actor = activity.actor();
+        let remote = AccountTypes::Federate(actor.to_string());
+
+        match activity.kind() {
+            ActivityKind::Follow { object } => {
+                let Some(name) = local_actor_name(&self.host, object) else {
+                    return Ok(());
+                };
+                let name = AccountName::new(name);
+                let Some(account) = self.account_repo.find_by_name(&name).await? else {
+                    return Err(ApplicationError::NotFound {
+                        method: "follow",
+                        entity: "account",
+                        id: name.into()
+                    });
+                };
+
+                let local = AccountTypes::Local(*account.id());
+                let state = if *account.locked().as_ref() { FollowState::Pending } else { FollowState::Accepted };
+                let follow = Follow::new(FollowId::default(), remote.clone(), local.clone(), state, OffsetDateTime::now_utc());
+                self.follow_repo.create(&follow).await?;
+
+                // A repeated Follow keeps the stored follow, so the answer goes by that one.
+                let Some(follow) = self.follow_repo.find(&remote, &local).await? else {
+                    return Ok(());
+                };
+
+                // Locked accounts answer once the request is accepted or rejected.
+                if follow.state().is_accepted() {
+                    let urls = ActorUrls::new(&self.host, account.name());
+                    let object = FollowObjectDto::new(Some(activity.id().as_ref().to_string()), actor, &urls.id);
+                    let accept = OutgoingActivityDto::new(
+                        format!("{}#accepts/{}", urls.id, follow.id().as_ref()),
+                        "Accept",
+                        urls.id,
+                        object
+                    );
+                    enqueue(&self.delivery_repo, &self.host, &account, &accept, [actor.to_string()]).await?;
+                }
+            },
+            ActivityKind::Undo { object } => {
+                if object.actor() != actor {
+                    return Err(ApplicationError::Forbidden("cannot undo an activity of another actor.".to_string()));
+                }
+                match object.kind() {
+                    ActivityKind::Follow { object } => {
+                        let Some(name) = local_actor_name(&self.host, object) else {
+                            return Ok(());
+                        };
+                        if let Some(account) = self.account_repo.find_by_name(&AccountName::new(name)).await? {
+                            self.follow_repo.delete(&remote, &AccountTypes::Local(*account.id())).await?;
+                        }
+                    },
+                    ActivityKind::Like { object, .. } => {
+                        if let Some(note) = local_note_id(&self.host, object) {
+                            self.reaction_repo.delete(&note, &remote).await?;
+                        }
+                    },
+                    ActivityKind::Announce { object, .. } => {
+                        self.boost_repo.delete(&remote, &self.note_types(object)).await?;
+                    },
+                    _ => ()
+                }
+            },
+            ActivityKind::Create { note } => {
+                let same_origin = url_host(note.id().as_ref()).is_some_and(|host| url_host(actor) == Some(host));
+                if note.author() != actor || !same_origin {
+                    return Err(ApplicationError::Forbidden("cannot create a note on behalf of another actor.".to_string()));
+                }
+                self.remote_note_repo.create(note).await?;
+            },
+            ActivityKind::Delete { object } => {
+                // Deleting a note of someone else removes nothing.
+                self.remote_note_repo.delete(&RemoteNoteId::new(object.clone()), actor).await?;
+            },
+            ActivityKind::Like { object, content } => {
+                let Some(id) = local_note_id(&self.host, object) else {
+                    return Ok(());
+                };
+                let Some(note) = self.note_repo.find_by_id(&id).await? else {
+                    return Ok(());
+                };
+                if !is_visible_to_remote(&self.follow_repo, actor, &note).await? {
+                    return Ok(());
+                }
+
+                let content = content.clone().unwrap_or_else(|| Emoji::new(DEFAULT_LIKE));
+                let reaction = Reaction::new(ReactionId::default(), id, remote, ReactionContent::Emoji(content), OffsetDateTime::now_utc());
+                self.reaction_repo.create(&reaction).await?;
+            },
+            ActivityKind::Announce { object, visibility } => {
+                let target = self.note_types(object);
+                let mut visibility = *visibility;
+                if let NoteTypes::Local(id) = &target {
+                    let Some(note) = self.note_repo.find_by_id(id).await? else {
+                        return Ok(());
+                    };
+                    // Only the author may pass on followers-only and direct notes.
+                    if !matches!(note.visibility(), Visibility::Public | Visibility::Unlisted) {
+                        return Ok(());
+                    }
+                    if visibility.is_wider_than(note.visibility()) {
+                        visibility = *note.visibility();
+                    }
+                }
+
+                let boost = Boost::new(BoostId::default(), remote, target, visibility, false, OffsetDateTime::now_utc());
+                self.boost_repo.create(&boost).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn note_types(&self, url: &str) -> NoteTypes {
+        match local_note_id(&self.host, url) {
+            Some(id) => NoteTypes::Local(id),
+            None => NoteTypes::Federate(url.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{
+        repository::{
+            MockAccountRepository, MockBoostRepository, MockDeliveryRepository, MockFollowRepository, MockNoteRepository,
+            MockReactionRepository, MockReceivedActivityRepository, MockRemoteNoteRepository
+        },
+        entities::{
+            Account, AccountId, AccountTypes, Emoji, Follow, FollowState, Note, NoteId, NoteTypes, ReactionContent,
+            RemoteNoteId, Visibility
+        }
+    };
+    use serde_json::{json, Value};
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use crate::{
+        adaptor::ReceiveActivityAdaptor,
+        service::note_url,
+        transfer::{ActivityDto, SignerDto},
+        ApplicationError
+    };
+
+    use super::ReceiveActivityInteractor;
+
+    const HOST: &str = "local.example";
+    const ALICE: &str = "https://remote.example/users/alice";
+    const MALLORY: &str = "https://evil.example/users/mallory";
+    const PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+
+    #[derive(Default)]
+    struct Repositories {
+        accounts: MockAccountRepository,
+        boosts: MockBoostRepository,
+        deliveries: MockDeliveryRepository,
+        follows: MockFollowRepository,
+        received: MockReceivedActivityRepository,
+        notes: MockNoteRepository,
+        remote_notes: MockRemoteNoteRepository,
+        reactions: MockReactionRepository
+    }
+
+    impl Repositories {
+        /// Every activity is new, so that it is handled.
+        fn new() -> Self {
+            let mut repos = Self::default();
+            repos.received.expect_claim().returning(|_| Ok(true));
+            repos
+        }
+
+        fn find_account(&mut self, account: &Account) {
+            let (name, found) = (account.name().clone(), account.clone());
+            self.accounts.expect_find_by_name()
+                .withf(move |candidate| *candidate == name)
+                .returning(move |_| Ok(Some(found.clone())));
+        }
+
+        fn find_note(&mut self, note: &Note) {
+            let (id, found) = (*note.id(), note.clone());
+            self.notes.expect_find_by_id()
+                .withf(move |candidate| *candidate == id)
+                .returning(move |_| Ok(Some(found.clone())));
+        }
+
+        fn interactor(self) -> Interactor {
+            ReceiveActivityInteractor::new(
+                self.accounts,
+                self.boosts,
+                self.deliveries,
+                self.follows,
+                self.received,
+                self.notes,
+                self.remote_notes,
+                self.reactions,
+                HOST
+            )
+        }
+
+        /// Receives one activity with these repositories.
+        async fn receive(self, actor: &str, activity: Value) -> Result<(), ApplicationError> {
+            receive(&self.interactor(), actor, activity).await
+        }
+    }
+
+    type Interactor = ReceiveActivityInteractor<
+        MockAccountRepository, MockBoostRepository, MockDeliveryRepository, MockFollowRepository,
+        MockReceivedActivityRepository, MockNoteRepository, MockRemoteNoteRepository, MockReactionRepository
+    >;
+
+    /// Receives `activity` at the shared inbox, signed by `actor`.
+    async fn receive(interactor: &Interactor, actor: &str, activity: Value) -> Result<(), ApplicationError> {
+        let signer = SignerDto { key_id: format!("{}#main-key", actor), owner: actor.to_string() };
+        let activity = serde_json::from_value::<ActivityDto>(activity).unwrap();
+        interactor.receive(signer, None, activity).await
+    }
+
+    fn account(name: &str, locked: bool) -> Account {
+        let now = OffsetDateTime::now_utc();
+        Account::new(AccountId::default(), name, false, locked, now, now)
+    }
+
+    fn note(author: &Account, visibility: Visibility) -> Note {
+        Note::new(
+            NoteId::default(), *author.id(), "note", None::<String>, visibility, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), OffsetDateTime::now_utc()
+        )
+    }
+
+    fn actor_of(account: &Account) -> String {
+        format!("https://{}/users/{}", HOST, account.name().as_ref())
+    }
+
+    fn remote_note(id: &str, author: &str) -> Value {
+        json!({ "id": id, "type": "Note", "attributedTo": author, "content": "<p>Hi</p>", "to": [PUBLIC] })
+    }
+
+    fn alice() -> AccountTypes {
+        AccountTypes::Federate(ALICE.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_follow() {
+        // Open accounts accept at once and answer with an `Accept`.
+        // It carries the id of the stored follow, which is an older one when the Follow is repeated.
+        let open = account("open", false);
+        let open_id = *open.id();
+        let stored = Follow::new(Uuid::new_v4(), alice(), open_id, FollowState::Accepted, OffsetDateTime::now_utc());
+        let stored_id = *stored.id().as_ref();
+
+        let mut repos = Repositories::new();
+        repos.find_account(&open);
+        repos.follows.expect_create()
+            .withf(move |follow| *follow.source() == alice() && *follow.destination() == AccountTypes::Local(open_id) && follow.state().is_accepted())
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.follows.expect_find()
+            .withf(move |source, destination| *source == alice() && *destination == AccountTypes::Local(open_id))
+            .returning(move |_, _| Ok(Some(stored.clone())));
+        repos.deliveries.expect_create()
+            .withf(move |delivery| delivery.host() == "remote.example"
+                && delivery.recipients() == [ALICE]
+                && delivery.activity().contains(r#""type":"Accept""#)
+                && delivery.activity().contains(&format!("#accepts/{}", stored_id)))
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/follows/1", "type": "Follow", "actor": ALICE, "object": actor_of(&open)
+        })).await.unwrap();
+
+        // Locked accounts keep the request pending and do not answer yet.
+        let locked = account("locked", true);
+        let locked_id = *locked.id();
+        let mut repos = Repositories::new();
+        repos.find_account(&locked);
+        repos.follows.expect_create()
+            .withf(|follow| *follow.state() == FollowState::Pending)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.follows.expect_find()
+            .returning(move |_, _| Ok(Some(Follow::new(Uuid::new_v4(), alice(), locked_id, FollowState::Pending, OffsetDateTime::now_utc()))));
+        repos.deliveries.expect_create().never();
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/follows/2", "type": "Follow", "actor": ALICE, "object": actor_of(&locked)
+        })).await.unwrap();
+
+        // Follows of remote actors are ignored.
+        let mut repos = Repositories::new();
+        repos.follows.expect_create().never();
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/follows/3", "type": "Follow", "actor": ALICE, "object": MALLORY
+        })).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_like() {
+        let author = account("shuttle", false);
+
+        // A favourite without an emoji gets the default reaction.
+        let public = note(&author, Visibility::Public);
+        let public_id = *public.id();
+        let mut repos = Repositories::new();
+        repos.find_note(&public);
+        repos.reactions.expect_create()
+            .withf(move |reaction| *reaction.note() == public_id
+                && *reaction.account() == alice()
+                && *reaction.content() == ReactionContent::Emoji(Emoji::new("❤️")))
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/likes/1", "type": "Like", "actor": ALICE, "object": note_url(HOST, public.id())
+        })).await.unwrap();
+
+        // Notes the actor cannot see are not reacted to.
+        let direct = note(&author, Visibility::Direct);
+        let mut repos = Repositories::new();
+        repos.find_note(&direct);
+        repos.reactions.expect_create().never();
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/likes/2", "type": "Like", "actor": ALICE, "object": note_url(HOST, direct.id())
+        })).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_announce() {
+        let author = account("shuttle", false);
+
+        // Boosts of public notes are stored, those of direct notes are not.
+        let public = note(&author, Visibility::Public);
+        let public_id = *public.id();
+        let mut repos = Repositories::new();
+        repos.find_note(&public);
+        repos.boosts.expect_create()
+            .withf(move |boost| *boost.account() == alice()
+                && *boost.note() == NoteTypes::Local(public_id)
+                && *boost.visibility() == Visibility::Public)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/announces/1", "type": "Announce", "actor": ALICE,
+            "object": note_url(HOST, public.id()), "to": [PUBLIC]
+        })).await.unwrap();
+
+        let direct = note(&author, Visibility::Direct);
+        let mut repos = Repositories::new();
+        repos.find_note(&direct);
+        repos.boosts.expect_create().never();
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/announces/2", "type": "Announce", "actor": ALICE,
+            "object": note_url(HOST, direct.id()), "to": [PUBLIC]
+        })).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_undo() {
+        let account = account("shuttle", false);
+        let account_id = *account.id();
+        let note = note(&account, Visibility::Public);
+        let note_id = *note.id();
+
+        let follow = json!({ "id": "https://remote.example/follows/1", "type": "Follow", "actor": ALICE, "object": actor_of(&account) });
+        let like = json!({ "id": "https://remote.example/likes/1", "type": "Like", "actor": ALICE, "object": note_url(HOST, note.id()) });
+        let announce = json!({
+            "id": "https://remote.example/announces/1", "type": "Announce", "actor": ALICE,
+            "object": note_url(HOST, note.id()), "to": [PUBLIC]
+        });
+
+        // Undoing an activity of another actor is refused.
+        let mut repos = Repositories::new();
+        repos.received.expect_release().times(1).returning(|_| Ok(()));
+        repos.follows.expect_delete().never();
+        let result = repos.receive(MALLORY, json!({
+            "id": "https://evil.example/undos/1", "type": "Undo", "actor": MALLORY, "object": follow.clone()
+        })).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+
+        let mut repos = Repositories::new();
+        repos.find_account(&account);
+        repos.follows.expect_delete()
+            .withf(move |source, destination| *source == alice() && *destination == AccountTypes::Local(account_id))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repos.reactions.expect_delete()
+            .withf(move |note, account| *note == note_id && *account == alice())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repos.boosts.expect_delete()
+            .withf(move |account, note| *account == alice() && *note == NoteTypes::Local(note_id))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let interactor = repos.interactor();
+        for (index, activity) in [follow, like, announce].into_iter().enumerate() {
+            receive(&interactor, ALICE, json!({
+                "id": format!("https://remote.example/undos/{}", index), "type": "Undo", "actor": ALICE, "object": activity
+            })).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_delete() {
+        let id = RemoteNoteId::new("https://remote.example/notes/1");
+
+        let mut repos = Repositories::new();
+        repos.remote_notes.expect_create()
+            .withf(|note| note.id().as_ref() == "https://remote.example/notes/1"
+                && note.author() == ALICE
+                && *note.visibility() == Visibility::Public)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.receive(ALICE, json!({
+            "id": "https://remote.example/notes/1/activity", "type": "Create", "actor": ALICE,
+            "object": remote_note(id.as_ref(), ALICE)
+        })).await.unwrap();
+
+        // A note must be by the actor and hosted where the actor is.
+        let mut repos = Repositories::new();
+        repos.received.expect_release().times(2).returning(|_| Ok(()));
+        repos.remote_notes.expect_create().never();
+        let interactor = repos.interactor();
+        let result = receive(&interactor, ALICE, json!({
+            "id": "https://remote.example/notes/2/activity", "type": "Create", "actor": ALICE,
+            "object": remote_note("https://remote.example/notes/2", MALLORY)
+        })).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+        let result = receive(&interactor, ALICE, json!({
+            "id": "https://remote.example/notes/3/activity", "type": "Create", "actor": ALICE,
+            "object": remote_note("https://evil.example/notes/3", ALICE)
+        })).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+
+        // The actor is passed on as the author, so that a note of someone else is not deleted.
+        let mut repos = Repositories::new();
+        let deleted = id.clone();
+        repos.remote_notes.expect_delete()
+            .withf(move |note, author| *note == deleted && author == MALLORY)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repos.receive(MALLORY, json!({
+            "id": "https://evil.example/deletes/1", "type": "Delete", "actor": MALLORY, "object": id.as_ref()
+        })).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_redelivery() {
+        let account = account("shuttle", false);
+        let follow = json!({ "id": "https://remote.example/follows/1", "type": "Follow", "actor": ALICE, "object": actor_of(&account) });
+
+        // An activity claimed before is not handled again.
+        let mut repos = Repositories::default();
+        repos.received.expect_claim()
+            .withf(|id| id.as_ref() == "https://remote.example/follows/1")
+            .times(1)
+            .returning(|_| Ok(false));
+        repos.follows.expect_create().never();
+        repos.deliveries.expect_create().never();
+        repos.receive(ALICE, follow).await.unwrap();
+
+        // A failed activity is released, so that the sender's retry is handled.
+        let mut repos = Repositories::new();
+        repos.accounts.expect_find_by_name().returning(|_| Ok(None));
+        repos.received.expect_release()
+            .withf(|id| id.as_ref() == "https://remote.example/follows/2")
+            .times(1)
+            .returning(|_| Ok(()));
+        let result = repos.receive(ALICE, json!({
+            "id": "https://remote.example/follows/2", "type": "Follow", "actor": ALICE, "object": format!("https://{}/users/later", HOST)
+        })).await;
+        assert!(matches!(result, Err(ApplicationError::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_signer() {
+        let account = account("shuttle", false);
+
+        let mut repos = Repositories::default();
+        repos.received.expect_claim().never();
+        let interactor = repos.interactor();
+
+        // The activity must be signed by its actor.
+        let result = receive(&interactor, MALLORY, json!({
+            "id": "https://remote.example/follows/1", "type": "Follow", "actor": ALICE, "object": actor_of(&account)
+        })).await;
+        assert!(matches!(result, Err(ApplicationError::Unauthorized)));
+
+        // An actor cannot use an id from another host, which would keep the real activity from being handled.
+        let result = receive(&interactor, MALLORY, json!({
+            "id": "https://remote.example/follows/1", "type": "Follow", "actor": MALLORY, "object": actor_of(&account)
+        })).await;
+        assert!(matches!(result, Err(ApplicationError::Forbidden(_))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,506 @@
+use kernel::{
+    repository::{
+        AccountRepository, ConfidentialRepository, DeliveryRepository, FollowRepository, HashtagRepository,
+        NoteRepository, RemoteNoteRepository, TimelineCacheRepository
+    },
+    entities::{
+        AccountId, AccountTypes, Content, ContentWarning, Note, NoteId, NoteTypes, RemoteNote, RemoteNoteId, TimelineCursor,
+        Visibility
+    },
+    service::ActorResolver
+};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    adaptor::{CreateNoteAdaptor, GetNoteAdaptor, GetNoteContextAdaptor, DeleteNoteAdaptor},
+    transfer::{AnyNoteDto, NoteDto, NoteContextDto, CreateNoteDto, NoteObjectDto, OutgoingActivityDto, TombstoneDto},
+    service::{
+        addressing, check_reshare, enqueue, fan_out, is_remote_visible, is_visible, note_url, parse_hashtags, parse_mentions,
+        remote_followers, resolve_mentions, ActorUrls
+    },
+    ApplicationError
+};
+
+/// Actor ids of the remote accounts in `accounts`.
+fn remote_actors(accounts: &[AccountTypes]) -> Vec<String> {
+    accounts.iter()
+        .filter_map(|account| match account {
+            AccountTypes::Federate(actor) => Some(actor.clone()),
+            AccountTypes::Local(_) => None
+        })
+        .collect()
+}
+
+fn not_found(method: &'static str, id: &NoteId) -> ApplicationError {
+    ApplicationError::NotFound {
+        method,
+        entity: "note",
+        id: id.as_ref().to_string()
+    }
+}
+
+pub struct CreateNoteInteractor<A, C, D, F, H, N, K, W> {
+    account_repo: A,
+    confidential_repo: C,
+    delivery_repo: D,
+    follow_repo: F,
+    hashtag_repo: H,
+    note_repo: N,
+    timeline_cache: K,
+    resolver: W,
+    host: String
+}
+
+impl<A, C, D, F, H, N, K, W> CreateNoteInteractor<A, C, D, F, H, N, K, W> {
+    /// `host` is the domain of this server, so that `@name@host` mentions resolve locally.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_repo: A,
+        confidential_repo: C,
+        delivery_repo: D,
+        follow_repo: F,
+        hashtag_repo: H,
+        note_repo: N,
+        timeline_cache: K,
+        resolver: W,
+        host: impl Into<String>
+    ) -> Self {
+        Self {
+            account_repo,
+            confidential_repo,
+            delivery_repo,
+            follow_repo,
+            hashtag_repo,
+            note_repo,
+            timeline_cache,
+            resolver,
+            host: host.into()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, C, D, F, H, N, K, W> CreateNoteAdaptor for CreateNoteInteractor<A, C, D, F, H, N, K, W>
+  where A: AccountRepository,
+        C: ConfidentialRepository,
+        D: DeliveryRepository,
+        F: FollowRepository,
+        H: HashtagRepository,
+        N: NoteRepository,
+        K: TimelineCacheRepository,
+        W: ActorResolver
+{
+    async fn create(&self, account: i64, note: CreateNoteDto) -> Result<NoteDto, ApplicationError> {
+        let author = AccountId::new(account);
+
+        let verified = self.confidential_repo.find_by_account_id(&author).await?
+            .is_some_and(|confidential| confidential.is_verified());
+        if !verified {
+            return Err(ApplicationError::Forbidden("the email address must be verified before posting.".to_string()));
+        }
+
+        let CreateNoteDto { content, cw, visibility, in_reply_to, quote_of } = note;
+        let content = Content::try_from(content)
+            .map_err(ApplicationError::field("content"))?;
+        let cw = cw
+            .filter(|cw| !cw.is_empty())
+            .map(ContentWarning::try_from)
+            .transpose()
+            .map_err(ApplicationError::field("cw"))?;
+        let visibility = visibility
+            .map(|visibility| Visibility::try_from(visibility.as_str()))
+            .transpose()
+            .map_err(ApplicationError::field("visibility"))?
+            .unwrap_or_default();
+
+        // Replying to a note the author cannot see must not reveal that it exists.
+        let in_reply_to = match in_reply_to.map(NoteId::new) {
+            Some(target) => {
+                let visible = match self.note_repo.find_by_id(&target).await? {
+                    Some(found) => is_visible(&self.follow_repo, Some(&author), &found).await?,
+                    None => false
+                };
+                if !visible {
+                    return Err(not_found("create", &target));
+                }
+                Some(NoteTypes::Local(target))
+            },
+            None => None
+        };
+
+        let quote_of = match quote_of.map(NoteId::new) {
+            Some(target) => {
+                let Some(quoted) = self.note_repo.find_by_id(&target).await? else {
+                    return Err(not_found("create", &target));
+                };
+                if !is_visible(&self.follow_repo, Some(&author), &quoted).await? {
+                    return Err(not_found("create", &target));
+                }
+                check_reshare(&author, &quoted, &visibility)?;
+                Some(NoteTypes::Local(target))
+            },
+            None => None
+        };
+
+        let mentions = resolve_mentions(&self.account_repo, &self.resolver, &self.host, &parse_mentions(content.as_ref())).await?;
+
+        let hashtags = parse_hashtags(content.as_ref());
+        let hashtags = if hashtags.is_empty() {
+            Vec::new()
+        } else {
+            self.hashtag_repo.upsert(&hashtags).await?
+        };
+
+        let note = Note::new(
+            NoteId::default(),
+            author,
+            content,
+            cw,
+            visibility,
+            in_reply_to,
+            quote_of,
+            mentions,
+            Vec::<Uuid>::new(),
+            hashtags.iter().map(|hashtag| *hashtag.id().as_ref()),
+            OffsetDateTime::now_utc()
+        );
+
+        self.note_repo.create(&note).await?;
+
+        // The note exists either way. Timelines that missed it get it back when they are rebuilt.
+        let entry = TimelineCursor::new(*note.id().as_ref(), *note.created_at().as_ref());
+        if let Err(e) = fan_out(&self.follow_repo, &self.timeline_cache, &author, &entry, note.visibility(), note.mentions()).await {
+            tracing::warn!("failed to push a note into home timelines. {:?}", e);
+        }
+
+        if let Err(e) = self.federate(&note).await {
+            tracing::warn!("failed to queue a note for remote followers. {:?}", e);
+        }
+
+        Ok(note.into())
+    }
+}
+
+impl<A, C, D, F, H, N, K, W> CreateNoteInteractor<A, C, D, F, H, N, K, W>
+  where A: AccountRepository,
+        D: DeliveryRepository,
+        F: FollowRepository
+{
+    /// Sends a `Create` to the remote followers of the author and the remote accounts mentioned.
+    async fn federate(&self, note: &Note) -> Result<(), ApplicationError> {
+        let Some(author) = self.account_repo.find_by_id(note.author()).await? else {
+            return Ok(());
+        };
+
+        let mentions = remote_actors(note.mentions());
+        let mut recipients = mentions.clone();
+        if *note.visibility() != Visibility::Direct {
+            recipients.extend(remote_followers(&self.follow_repo, note.author()).await?);
+        }
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let urls = ActorUrls::new(&self.host, author.name());
+        let (to, cc) = addressing(note.visibility(), &urls.followers, &mentions);
+        let object = NoteObjectDto::new(note, &self.host, &urls.id, to.clone(), cc.clone());
+        let mut activity = OutgoingActivityDto::new(format!("{}/activity", object.id), "Create", urls.id, object);
+        activity.to = to;
+        activity.cc = cc;
+
+        enqueue(&self.delivery_repo, &self.host, &author, &activity, recipients).await
+    }
+}
+
+pub struct GetNoteInteractor<F, N> {
+    follow_repo: F,
+    note_repo: N
+}
+
+impl<F, N> GetNoteInteractor<F, N> {
+    pub fn new(follow_repo: F, note_repo: N) -> Self {
+        Self { follow_repo, note_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, N> GetNoteAdaptor for GetNoteInteractor<F, N>
+  where F: FollowRepository,
+        N: NoteRepository
+{
+    async fn get(&self, viewer: Option<i64>, id: Uuid) -> Result<NoteDto, ApplicationError> {
+        let id = NoteId::new(id);
+        let viewer = viewer.map(AccountId::new);
+
+        let Some(note) = self.note_repo.find_by_id(&id).await? else {
+            return Err(not_found("get", &id));
+        };
+
+        if !is_visible(&self.follow_repo, viewer.as_ref(), &note).await? {
+            return Err(not_found("get", &id));
+        }
+
+        Ok(note.into())
+    }
+}
+
+pub struct GetNoteContextInteractor<F, N, O> {
+    follow_repo: F,
+    note_repo: N,
+    remote_note_repo: O
+}
+
+impl<F, N, O> GetNoteContextInteractor<F, N, O> {
+    /// Levels of replies followed in each direction.
+    pub const MAX_DEPTH: i64 = 40;
+
+    pub fn new(follow_repo: F, note_repo: N, remote_note_repo: O) -> Self {
+        Self { follow_repo, note_repo, remote_note_repo }
+    }
+
+    async fn visible(&self, viewer: Option<&AccountId>, notes: Vec<Note>) -> Result<Vec<Note>, ApplicationError>
+      where F: FollowRepository
+    {
+        let mut visible = Vec::with_capacity(notes.len());
+        for note in notes {
+            if is_visible(&self.follow_repo, viewer, &note).await? {
+                visible.push(note);
+            }
+        }
+        Ok(visible)
+    }
+
+    async fn remote_visible(&self, viewer: Option<&AccountId>, notes: Vec<RemoteNote>) -> Result<Vec<RemoteNote>, ApplicationError>
+      where F: FollowRepository
+    {
+        let mut visible = Vec::with_capacity(notes.len());
+        for note in notes {
+            if is_remote_visible(&self.follow_repo, viewer, &note).await? {
+                visible.push(note);
+            }
+        }
+        Ok(visible)
+    }
+
+    /// Remote notes the thread above `top` started with, root first.
+    /// Stops at the first note that is not stored here.
+    async fn remote_ancestors(&self, top: &Note, depth: i64) -> Result<Vec<RemoteNote>, ApplicationError>
+      where O: RemoteNoteRepository
+    {
+        let mut ancestors = Vec::new();
+        let mut parent = top.in_reply_to().cloned();
+        while let Some(NoteTypes::Federate(id)) = parent {
+            if ancestors.len() as i64 >= depth {
+                break;
+            }
+            let Some(note) = self.remote_note_repo.find_by_id(&RemoteNoteId::new(id)).await? else {
+                break;
+            };
+            parent = note.in_reply_to().cloned();
+            ancestors.push(note);
+        }
+        ancestors.reverse();
+        Ok(ancestors)
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, N, O> GetNoteContextAdaptor for GetNoteContextInteractor<F, N, O>
+  where F: FollowRepository,
+        N: NoteRepository,
+        O: RemoteNoteRepository
+{
+    async fn context(&self, viewer: Option<i64>, id: Uuid) -> Result<NoteContextDto, ApplicationError> {
+        let id = NoteId::new(id);
+        let viewer = viewer.map(AccountId::new);
+
+        let Some(note) = self.note_repo.find_by_id(&id).await? else {
+            return Err(not_found("context", &id));
+        };
+
+        if !is_visible(&self.follow_repo, viewer.as_ref(), &note).await? {
+            return Err(not_found("context", &id));
+        }
+
+        let ancestors = self.note_repo.find_ancestors(&id, Self::MAX_DEPTH).await?;
+        let top = ancestors.first().unwrap_or(&note);
+        let remote_ancestors = self.remote_ancestors(top, Self::MAX_DEPTH - ancestors.len() as i64).await?;
+
+        let descendants = self.note_repo.find_descendants(&id, Self::MAX_DEPTH).await?;
+        let descendants = self.visible(viewer.as_ref(), descendants).await?;
+
+        // Remote replies are only listed under notes the viewer can see.
+        let targets = std::iter::once(id)
+            .chain(descendants.iter().map(|note| *note.id()))
+            .collect::<Vec<_>>();
+        let replies = self.remote_note_repo.find_replies(&targets, Self::MAX_DEPTH).await?;
+        let replies = self.remote_visible(viewer.as_ref(), replies).await?;
+
+        let mut descendants = descendants.into_iter()
+            .map(|note| (*note.created_at().as_ref(), AnyNoteDto::from(note)))
+            .chain(replies.into_iter().map(|note| (*note.created_at().as_ref(), AnyNoteDto::from(note))))
+            .collect::<Vec<_>>();
+        descendants.sort_by_key(|&(created_at, _)| created_at);
+
+        Ok(NoteContextDto {
+            ancestors: self.remote_visible(viewer.as_ref(), remote_ancestors).await?.into_iter()
+                .map(AnyNoteDto::from)
+                .chain(self.visible(viewer.as_ref(), ancestors).await?.into_iter().map(AnyNoteDto::from))
+                .collect(),
+            descendants: descendants.into_iter().map(|(_, note)| note).collect()
+        })
+    }
+}
+
+pub struct DeleteNoteInteractor<A, D, F, N> {
+    account_repo: A,
+    delivery_repo: D,
+    follow_repo: F,
+    note_repo: N,
+    host: String
+}
+
+impl<A, D, F, N> DeleteNoteInteractor<A, D, F, N> {
+    pub fn new(account_repo: A, delivery_repo: D, follow_repo: F, note_repo: N, host: impl Into<String>) -> Self {
+        Self { account_repo, delivery_repo, follow_repo, note_repo, host: host.into() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, D, F, N> DeleteNoteAdaptor for DeleteNoteInteractor<A, D, F, N>
+  where A: AccountRepository,
+        D: DeliveryRepository,
+        F: FollowRepository,
+        N: NoteRepository
+{
+    async fn delete(&self, account: i64, id: Uuid) -> Result<(), ApplicationError> {
+        let id = NoteId::new(id);
+        let account = AccountId::new(account);
+
+        let Some(note) = self.note_repo.find_by_id(&id).await? else {
+            return Err(not_found("delete", &id));
+        };
+
+        if *note.author() != account {
+            if !is_visible(&self.follow_repo, Some(&account), &note).await? {
+                return Err(not_found("delete", &id));
+            }
+            return Err(ApplicationError::Forbidden("only the author can delete the note.".to_string()));
+        }
+
+        self.note_repo.delete(&id).await?;
+
+        if let Err(e) = self.federate(&note).await {
+            tracing::warn!("failed to queue a note deletion for remote followers. {:?}", e);
+        }
+
+        Ok(())
+    }
+}
+
+impl<A, D, F, N> DeleteNoteInteractor<A, D, F, N>
+  where A: AccountRepository,
+        D: DeliveryRepository,
+        F: FollowRepository
+{
+    /// Sends a `Delete` to the servers the note was sent to.
+    async fn federate(&self, note: &Note) -> Result<(), ApplicationError> {
+        let Some(author) = self.account_repo.find_by_id(note.author()).await? else {
+            return Ok(());
+        };
+
+        let mut recipients = remote_actors(note.mentions());
+        if *note.visibility() != Visibility::Direct {
+            recipients.extend(remote_followers(&self.follow_repo, note.author()).await?);
+        }
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let urls = ActorUrls::new(&self.host, author.name());
+        let id = note_url(&self.host, note.id());
+        let activity = OutgoingActivityDto::new(format!("{}#delete", id), "Delete", urls.id, TombstoneDto::new(id));
+
+        enqueue(&self.delivery_repo, &self.host, &author, &activity, recipients).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{
+        repository::{MockFollowRepository, MockNoteRepository, MockRemoteNoteRepository},
+        entities::{AccountId, Note, NoteId, NoteTypes, RemoteNote, Visibility}
+    };
+    use time::{Duration, OffsetDateTime};
+    use uuid::Uuid;
+
+    use crate::{adaptor::GetNoteContextAdaptor, transfer::AnyNoteDto};
+
+    use super::GetNoteContextInteractor;
+
+    fn note(author: AccountId, in_reply_to: Option<NoteTypes>, created_at: OffsetDateTime) -> Note {
+        Note::new(
+            NoteId::default(), author, "note", None::<String>, Visibility::Public, in_reply_to, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at
+        )
+    }
+
+    fn remote(id: &str, visibility: Visibility, in_reply_to: Option<NoteTypes>, created_at: OffsetDateTime) -> RemoteNote {
+        RemoteNote::new(id, "https://remote.example/users/shuttle", "<p>note</p>", None::<String>, visibility, in_reply_to, created_at)
+    }
+
+    fn ids(notes: &[AnyNoteDto]) -> Vec<String> {
+        notes.iter()
+            .map(|note| match note {
+                AnyNoteDto::Local(note) => note.id.to_string(),
+                AnyNoteDto::Remote(note) => note.id.clone()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_context() {
+        let author = AccountId::default();
+        let now = OffsetDateTime::now_utc();
+
+        let origin = remote("https://remote.example/notes/1", Visibility::Public, Some(NoteTypes::Federate("https://remote.example/notes/0".to_string())), now);
+        let root = note(author, Some(NoteTypes::Federate(origin.id().as_ref().to_string())), now + Duration::seconds(1));
+        let viewed = note(author, Some((*root.id()).into()), now + Duration::seconds(2));
+        let local_reply = note(author, Some((*viewed.id()).into()), now + Duration::seconds(4));
+        let remote_reply = remote("https://remote.example/notes/2", Visibility::Public, Some((*viewed.id()).into()), now + Duration::seconds(3));
+        let hidden = remote("https://remote.example/notes/3", Visibility::FollowersOnly, Some((*viewed.id()).into()), now + Duration::seconds(5));
+        let direct = remote("https://remote.example/notes/4", Visibility::Direct, Some((*local_reply.id()).into()), now + Duration::seconds(6));
+
+        let mut note_repo = MockNoteRepository::new();
+        let found = viewed.clone();
+        note_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(found.clone())));
+        let ancestors = vec![root.clone()];
+        note_repo.expect_find_ancestors()
+            .returning(move |_, _| Ok(ancestors.clone()));
+        let descendants = vec![local_reply.clone()];
+        note_repo.expect_find_descendants()
+            .returning(move |_, _| Ok(descendants.clone()));
+
+        let mut remote_note_repo = MockRemoteNoteRepository::new();
+        let stored = origin.clone();
+        remote_note_repo.expect_find_by_id()
+            .returning(move |id| Ok((id == stored.id()).then(|| stored.clone())));
+        let targets = vec![*viewed.id(), *local_reply.id()];
+        let replies = vec![remote_reply.clone(), hidden.clone(), direct.clone()];
+        remote_note_repo.expect_find_replies()
+            .withf(move |found, _| found == targets)
+            .returning(move |_, _| Ok(replies.clone()));
+
+        let mut follow_repo = MockFollowRepository::new();
+        follow_repo.expect_find()
+            .returning(|_, _| Ok(None));
+
+        let interactor = GetNoteContextInteractor::new(follow_repo, note_repo, remote_note_repo);
+        let context = interactor.context(None, *viewed.id().as_ref()).await.unwrap();
+
+        assert_eq!(ids(&context.ancestors), vec![origin.id().as_ref().to_string(), root.id().as_ref().to_string()]);
+        assert_eq!(ids(&context.descendants), vec![remote_reply.id().as_ref().to_string(), local_reply.id().as_ref().to_string()]);
+    }
+}
\ No newline at end of file
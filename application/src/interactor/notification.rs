@@ -0,0 +1,74 @@
+use kernel::{
+    repository::TimelineRepository,
+    entities::{AccountId, Pagination}
+};
+
+use crate::{
+    adaptor::GetNotificationsAdaptor,
+    transfer::{NotificationDto, PaginationDto},
+    ApplicationError
+};
+
+/// Lists the notes mentioning an account as its notifications.
+/// Mentions are stored with the notes, so there is nothing to record when a note is created.
+pub struct GetNotificationsInteractor<T> {
+    timeline_repo: T
+}
+
+impl<T> GetNotificationsInteractor<T> {
+    pub fn new(timeline_repo: T) -> Self {
+        Self { timeline_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> GetNotificationsAdaptor for GetNotificationsInteractor<T>
+  where T: TimelineRepository
+{
+    async fn notifications(&self, account: i64, page: PaginationDto) -> Result<Vec<NotificationDto>, ApplicationError> {
+        let account = AccountId::new(account);
+        let entries = self.timeline_repo.find_mentions(&account, &Pagination::from(page)).await?;
+
+        Ok(entries.into_iter()
+            .filter_map(NotificationDto::mention)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{
+        repository::MockTimelineRepository,
+        entities::{AccountId, AccountTypes, Note, NoteId, TimelineEntry, Visibility}
+    };
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use crate::{adaptor::GetNotificationsAdaptor, transfer::{AnyNoteDto, PaginationDto}};
+
+    use super::GetNotificationsInteractor;
+
+    #[tokio::test]
+    async fn test_mentions() {
+        let account = AccountId::default();
+        let note = Note::new(
+            NoteId::default(), AccountId::default(), "hi @shuttle", None::<String>, Visibility::Direct, None, None,
+            vec![AccountTypes::Local(account)], Vec::<Uuid>::new(), Vec::<Uuid>::new(), OffsetDateTime::now_utc()
+        );
+        let id = *note.id().as_ref();
+
+        let mut timeline_repo = MockTimelineRepository::new();
+        let found = note.clone();
+        timeline_repo.expect_find_mentions()
+            .withf(move |mentioned, _| *mentioned == account)
+            .times(1)
+            .returning(move |_, _| Ok(vec![TimelineEntry::Note(found.clone())]));
+
+        let interactor = GetNotificationsInteractor::new(timeline_repo);
+        let notifications = interactor.notifications(account.into(), PaginationDto::default()).await.unwrap();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].id, id);
+        assert_eq!(notifications[0].kind, "mention");
+        assert!(matches!(&notifications[0].note, AnyNoteDto::Local(dto) if dto.id == id));
+    }
+}
\ No newline at end of file
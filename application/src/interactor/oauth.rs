@@ -0,0 +1,321 @@
+use time::OffsetDateTime;
+
+use kernel::{
+    entities::{
+        AccessToken, Application, ApplicationId, ApplicationName, AuthorizationCode, ChallengeMethod,
+        ClientId, ClientSecret, Code, CodeChallenge, OAuthToken, RedirectUri, Scopes, Website
+    },
+    repository::{ApplicationRepository, AuthorizationCodeRepository, OAuthTokenRepository}
+};
+
+use crate::{
+    adaptor::{
+        RegisterApplicationAdaptor, VerifyAuthorizationAdaptor, AuthorizeAdaptor,
+        IssueTokenAdaptor, RevokeTokenAdaptor
+    },
+    transfer::{
+        ApplicationDto, RegisterApplicationDto,
+        AuthorizeDto, AuthorizationRequestDto, AuthorizationCodeDto,
+        TokenRequestDto, TokenDto, RevokeTokenDto
+    },
+    ApplicationError
+};
+
+fn oauth_error(error: &'static str, description: impl Into<String>) -> ApplicationError {
+    ApplicationError::OAuth { error, description: description.into() }
+}
+
+pub struct RegisterApplicationInteractor<T> {
+    repo: T
+}
+
+impl<T> RegisterApplicationInteractor<T> {
+    pub fn new(repo: T) -> Self {
+        Self { repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> RegisterApplicationAdaptor for RegisterApplicationInteractor<T>
+  where T: ApplicationRepository
+{
+    async fn register(&self, app: RegisterApplicationDto) -> Result<ApplicationDto, ApplicationError> {
+        let RegisterApplicationDto { client_name, redirect_uris, scopes, website } = app;
+
+        let name = ApplicationName::try_from(client_name)?;
+        let website = website
+            .filter(|website| !website.is_empty())
+            .map(Website::try_from)
+            .transpose()?;
+        let redirect_uris = redirect_uris.split_whitespace()
+            .map(|uri| RedirectUri::try_from(uri.to_string()))
+            .collect::<Result<Vec<RedirectUri>, _>>()?;
+        if redirect_uris.is_empty() {
+            return Err(ApplicationError::Convert("at least one redirect uri is required.".to_string()));
+        }
+        // Mastodon defaults to `read` when no scope is requested.
+        let scopes = Scopes::try_from(scopes.as_deref().unwrap_or("read"))?;
+
+        let (created_at, updated_at) = (OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
+        let app = Application::new(
+            ApplicationId::default(),
+            created_at,
+            updated_at,
+            name,
+            website,
+            ClientId::default(),
+            ClientSecret::default(),
+            redirect_uris,
+            scopes
+        );
+
+        self.repo.create(&app).await?;
+
+        Ok(app.into())
+    }
+}
+
+/// Validated form of [`AuthorizeDto`].
+struct AuthorizationRequest {
+    app: Application,
+    redirect_uri: RedirectUri,
+    scopes: Scopes,
+    challenge: Option<CodeChallenge>,
+    state: Option<String>
+}
+
+async fn validate_authorization(
+    repo: &impl ApplicationRepository,
+    request: AuthorizeDto
+) -> Result<AuthorizationRequest, ApplicationError> {
+    let AuthorizeDto {
+        response_type,
+        client_id,
+        redirect_uri,
+        scope,
+        state,
+        code_challenge,
+        code_challenge_method
+    } = request;
+
+    let Some(app) = repo.find_by_client_id(&ClientId::new(client_id)).await? else {
+        return Err(oauth_error("invalid_client", "unknown client."));
+    };
+
+    let redirect_uri = RedirectUri::new(redirect_uri);
+    if !app.is_registered_redirect(&redirect_uri) {
+        return Err(oauth_error("invalid_request", "redirect_uri is not registered for this client."));
+    }
+
+    if response_type != "code" {
+        return Err(oauth_error("unsupported_response_type", "only `code` is supported."));
+    }
+
+    let scopes = match scope {
+        Some(scope) => Scopes::try_from(scope.as_str())
+            .map_err(|e| oauth_error("invalid_scope", e.to_string()))?,
+        None => app.scopes().clone()
+    };
+    if scopes.is_empty() || !scopes.is_subset(app.scopes()) {
+        return Err(oauth_error("invalid_scope", "requested scope exceeds the registered scope."));
+    }
+
+    let challenge = code_challenge
+        .map(|challenge| -> Result<CodeChallenge, ApplicationError> {
+            // RFC 7636 4.3: defaults to `plain` when the method is absent.
+            let method = ChallengeMethod::try_from(code_challenge_method.as_deref().unwrap_or("plain"))
+                .map_err(|e| oauth_error("invalid_request", e.to_string()))?;
+            Ok(CodeChallenge::new(challenge, method))
+        })
+        .transpose()?;
+
+    Ok(AuthorizationRequest { app, redirect_uri, scopes, challenge, state })
+}
+
+pub struct VerifyAuthorizationInteractor<T> {
+    repo: T
+}
+
+impl<T> VerifyAuthorizationInteractor<T> {
+    pub fn new(repo: T) -> Self {
+        Self { repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> VerifyAuthorizationAdaptor for VerifyAuthorizationInteractor<T>
+  where T: ApplicationRepository
+{
+    async fn verify(&self, request: AuthorizeDto) -> Result<AuthorizationRequestDto, ApplicationError> {
+        let AuthorizationRequest { app, redirect_uri, scopes, .. } = validate_authorization(&self.repo, request).await?;
+
+        Ok(AuthorizationRequestDto {
+            client_name: app.name().as_ref().to_string(),
+            website: app.website().map(|website| website.as_ref().to_string()),
+            redirect_uri: redirect_uri.into(),
+            scopes: scopes.into()
+        })
+    }
+}
+
+pub struct AuthorizeInteractor<A, C> {
+    app_repo: A,
+    code_repo: C
+}
+
+impl<A, C> AuthorizeInteractor<A, C> {
+    pub fn new(app_repo: A, code_repo: C) -> Self {
+        Self { app_repo, code_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, C> AuthorizeAdaptor for AuthorizeInteractor<A, C>
+  where A: ApplicationRepository,
+        C: AuthorizationCodeRepository
+{
+    async fn authorize(&self, account: i64, request: AuthorizeDto) -> Result<AuthorizationCodeDto, ApplicationError> {
+        let AuthorizationRequest { app, redirect_uri, scopes, challenge, state } = validate_authorization(&self.app_repo, request).await?;
+
+        let created_at = OffsetDateTime::now_utc();
+        let code = AuthorizationCode::new(
+            Code::default(),
+            *app.id(),
+            account,
+            redirect_uri,
+            scopes,
+            challenge,
+            created_at,
+            created_at + AuthorizationCode::LIFETIME
+        );
+
+        self.code_repo.create(&code).await?;
+
+        Ok(AuthorizationCodeDto {
+            code: code.code().as_ref().to_string(),
+            redirect_uri: code.redirect_uri().as_ref().to_string(),
+            state
+        })
+    }
+}
+
+pub struct IssueTokenInteractor<A, C, T> {
+    app_repo: A,
+    code_repo: C,
+    token_repo: T
+}
+
+impl<A, C, T> IssueTokenInteractor<A, C, T> {
+    pub fn new(app_repo: A, code_repo: C, token_repo: T) -> Self {
+        Self { app_repo, code_repo, token_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, C, T> IssueTokenAdaptor for IssueTokenInteractor<A, C, T>
+  where A: ApplicationRepository,
+        C: AuthorizationCodeRepository,
+        T: OAuthTokenRepository
+{
+    async fn issue(&self, request: TokenRequestDto) -> Result<TokenDto, ApplicationError> {
+        let TokenRequestDto {
+            grant_type,
+            code,
+            redirect_uri,
+            client_id,
+            client_secret,
+            code_verifier
+        } = request;
+
+        if grant_type != "authorization_code" {
+            return Err(oauth_error("unsupported_grant_type", "only `authorization_code` is supported."));
+        }
+
+        let Some(app) = self.app_repo.find_by_client_id(&ClientId::new(client_id)).await? else {
+            return Err(oauth_error("invalid_client", "unknown client."));
+        };
+
+        if let Some(secret) = &client_secret {
+            if !app.client_secret().verify(secret) {
+                return Err(oauth_error("invalid_client", "client authentication failed."));
+            }
+        }
+
+        let Some(code) = code else {
+            return Err(oauth_error("invalid_request", "code is required."));
+        };
+
+        let Some(code) = self.code_repo.take(&Code::new(code)).await? else {
+            return Err(oauth_error("invalid_grant", "code is invalid or already used."));
+        };
+
+        if code.application() != app.id() || code.expires_at().is_expired() {
+            return Err(oauth_error("invalid_grant", "code is invalid or expired."));
+        }
+
+        if redirect_uri.as_deref() != Some(code.redirect_uri().as_ref()) {
+            return Err(oauth_error("invalid_grant", "redirect_uri does not match the authorization request."));
+        }
+
+        match (code.challenge(), code_verifier) {
+            (Some(challenge), Some(verifier)) if challenge.verify(&verifier) => {},
+            (Some(_), _) => return Err(oauth_error("invalid_grant", "code_verifier does not match the code_challenge.")),
+            // Without PKCE the client has to prove itself with its secret instead.
+            (None, _) if client_secret.is_none() => return Err(oauth_error("invalid_client", "client authentication failed.")),
+            (None, _) => {}
+        }
+
+        let token = OAuthToken::new(
+            AccessToken::default(),
+            *app.id(),
+            *code.account(),
+            code.scopes().clone(),
+            OffsetDateTime::now_utc()
+        );
+
+        self.token_repo.create(&token).await?;
+
+        Ok(TokenDto {
+            access_token: token.token().as_ref().to_string(),
+            token_type: "Bearer",
+            scope: token.scopes().to_string(),
+            created_at: token.created_at().as_ref().unix_timestamp()
+        })
+    }
+}
+
+pub struct RevokeTokenInteractor<A, T> {
+    app_repo: A,
+    token_repo: T
+}
+
+impl<A, T> RevokeTokenInteractor<A, T> {
+    pub fn new(app_repo: A, token_repo: T) -> Self {
+        Self { app_repo, token_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, T> RevokeTokenAdaptor for RevokeTokenInteractor<A, T>
+  where A: ApplicationRepository,
+        T: OAuthTokenRepository
+{
+    async fn revoke(&self, request: RevokeTokenDto) -> Result<(), ApplicationError> {
+        let RevokeTokenDto { token, client_id, client_secret } = request;
+
+        let app = self.app_repo.find_by_client_id(&ClientId::new(client_id)).await?
+            .filter(|app| app.client_secret().verify(&client_secret))
+            .ok_or_else(|| oauth_error("invalid_client", "client authentication failed."))?;
+
+        let token = AccessToken::new(token);
+
+        // RFC 7009 2.2: an unknown token or a token of another client is not an error.
+        if let Some(found) = self.token_repo.find_by_token(&token).await? {
+            if found.application() == app.id() {
+                self.token_repo.delete(&token).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
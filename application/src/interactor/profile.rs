@@ -2,16 +2,49 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use kernel::{
-    entities::{AccountId, Profile, ProfileId, DisplayName, Summary, Icon, Banner, UpdateTime, DestructUpdateTime},
-    repository::ProfileRepository, 
+    entities::{AccountId, AccountName, Profile, ProfileId, DisplayName, Summary, Icon, Banner, UpdateTime, DestructUpdateTime},
+    repository::{AccountRepository, ProfileRepository}, 
 };
 
 use crate::{
-    adaptor::{CreateProfileAdaptor, UpdateProfileAdaptor},
+    adaptor::{CreateProfileAdaptor, UpdateProfileAdaptor, GetProfileAdaptor},
     transfer::{CreateProfileDto, ProfileDto, UpdateProfileDto}, 
     ApplicationError
 };
 
+/// Validated profile fields shared by create and update.
+struct ProfileFields {
+    name: DisplayName,
+    summary: Summary,
+    icon: Option<Icon>,
+    banner: Option<Banner>
+}
+
+impl ProfileFields {
+    /// An empty `icon` or `banner` clears the image.
+    fn parse(
+        display_name: String,
+        summary: String,
+        icon: Option<String>,
+        banner: Option<String>
+    ) -> Result<Self, ApplicationError> {
+        Ok(Self {
+            name: DisplayName::try_from(display_name)
+                .map_err(ApplicationError::field("display_name"))?,
+            summary: Summary::try_from(summary)
+                .map_err(ApplicationError::field("summary"))?,
+            icon: icon.filter(|url| !url.is_empty())
+                .map(Icon::try_from)
+                .transpose()
+                .map_err(ApplicationError::field("icon"))?,
+            banner: banner.filter(|url| !url.is_empty())
+                .map(Banner::try_from)
+                .transpose()
+                .map_err(ApplicationError::field("banner"))?
+        })
+    }
+}
+
 pub struct CreateProfileInteractor<T> {
     repo: T
 }
@@ -30,6 +63,14 @@ impl<T> CreateProfileAdaptor for CreateProfileInteractor<T>
         let profile_id = ProfileId::new(Uuid::new_v4());
         let account_id = AccountId::new(account);
 
+        // Profiles are changed with `UpdateProfileAdaptor`. The repository also refuses a concurrent second one.
+        if self.repo.find_by_account_id(&account_id).await?.is_some() {
+            return Err(ApplicationError::Conflict {
+                entity: "profile",
+                id: account_id.as_ref().to_string()
+            });
+        }
+
         let (create_at, updated_at) = (OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
 
         let CreateProfileDto {
@@ -39,12 +80,14 @@ impl<T> CreateProfileAdaptor for CreateProfileInteractor<T>
             banner
         } = profile;
 
+        let ProfileFields { name, summary, icon, banner } = ProfileFields::parse(display_name, summary, icon, banner)?;
+
         let profile = Profile::new(
             profile_id,
             account_id,
             create_at,
             updated_at,
-            display_name,
+            name,
             summary,
             icon,
             banner
@@ -85,10 +128,13 @@ impl<T> UpdateProfileAdaptor for UpdateProfileInteractor<T>
 
         let updated_at = OffsetDateTime::now_utc();
 
-        destructed.name    = DisplayName::new(profile.display_name);
-        destructed.summary = Summary::new(profile.summary);
-        destructed.icon    = Icon::new(profile.icon);
-        destructed.banner  = Banner::new(profile.banner);
+        let UpdateProfileDto { display_name, summary, icon, banner } = profile;
+        let ProfileFields { name, summary, icon, banner } = ProfileFields::parse(display_name, summary, icon, banner)?;
+
+        destructed.name    = name;
+        destructed.summary = summary;
+        destructed.icon    = icon;
+        destructed.banner  = banner;
         destructed.date    = UpdateTime::new(created_at, updated_at);
 
         let patched = destructed.freeze();
@@ -97,4 +143,49 @@ impl<T> UpdateProfileAdaptor for UpdateProfileInteractor<T>
 
         Ok(patched.into())
     }
+}
+
+pub struct GetProfileInteractor<A, P> {
+    account_repo: A,
+    profile_repo: P
+}
+
+impl<A, P> GetProfileInteractor<A, P> {
+    pub fn new(account_repo: A, profile_repo: P) -> Self {
+        Self { account_repo, profile_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, P> GetProfileAdaptor for GetProfileInteractor<A, P>
+  where A: AccountRepository,
+        P: ProfileRepository
+{
+    async fn get_by_id(&self, account: i64) -> Result<ProfileDto, ApplicationError> {
+        let id = AccountId::new(account);
+
+        let Some(profile) = self.profile_repo.find_by_account_id(&id).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "get_by_id",
+                entity: "profile",
+                id: format!("{:?}", id)
+            });
+        };
+
+        Ok(profile.into())
+    }
+
+    async fn get_by_name(&self, name: String) -> Result<ProfileDto, ApplicationError> {
+        let name = AccountName::new(name);
+
+        let Some(account) = self.account_repo.find_by_name(&name).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "get_by_name",
+                entity: "account",
+                id: name.into()
+            });
+        };
+
+        self.get_by_id(*account.id().as_ref()).await
+    }
 }
\ No newline at end of file
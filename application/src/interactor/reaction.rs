@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+
+use kernel::{
+    repository::{AccountRepository, FollowRepository, NoteRepository, ReactionRepository, ReactionAssetRepository},
+    entities::{
+        AccountId, AccountTypes, Administrators, AssetUrl, Emoji, License, NoteId, Reaction, ReactionAlias, ReactionAsset,
+        ReactionAssetId, ReactionContent, ReactionId
+    }
+};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{
+    adaptor::{
+        ReactToNoteAdaptor, UnreactToNoteAdaptor, GetReactionsAdaptor,
+        RegisterReactionAssetAdaptor, DeleteReactionAssetAdaptor, GetReactionAssetsAdaptor
+    },
+    transfer::{CreateReactionDto, CreateReactionAssetDto, ReactionAssetDto, ReactionCountDto},
+    service::visible_note,
+    ApplicationError
+};
+
+/// Reaction counts of `note` with the assets they use resolved.
+async fn counts(
+    reaction_repo: &impl ReactionRepository,
+    asset_repo: &impl ReactionAssetRepository,
+    viewer: Option<&AccountId>,
+    note: &NoteId
+) -> Result<Vec<ReactionCountDto>, ApplicationError> {
+    let counts = reaction_repo.count(note).await?;
+
+    let mine = match viewer {
+        Some(viewer) => reaction_repo.find(note, &AccountTypes::Local(*viewer)).await?.map(|reaction| reaction.content().clone()),
+        None => None
+    };
+
+    let ids = counts.iter()
+        .filter_map(|count| match count.content() {
+            ReactionContent::Asset(id) => Some(*id),
+            ReactionContent::Emoji(_) => None
+        })
+        .collect::<Vec<ReactionAssetId>>();
+    let mut assets = asset_repo.find_by_ids(&ids).await?
+        .into_iter()
+        .map(|asset| (*asset.id(), asset))
+        .collect::<HashMap<_, _>>();
+
+    let counts = counts.into_iter()
+        .filter_map(|count| {
+            let me = mine.as_ref() == Some(count.content());
+            let (reaction, asset) = match count.content() {
+                ReactionContent::Emoji(emoji) => (emoji.as_ref().to_string(), None),
+                ReactionContent::Asset(id) => {
+                    let asset = assets.remove(id)?;
+                    (format!(":{}:", asset.alias().as_ref()), Some(asset.into()))
+                }
+            };
+            Some(ReactionCountDto { reaction, asset, count: count.count(), me })
+        })
+        .collect();
+
+    Ok(counts)
+}
+
+/// Rejects `account` unless it is one of `admins`.
+async fn require_admin(
+    account_repo: &impl AccountRepository,
+    admins: &Administrators,
+    account: &AccountId
+) -> Result<(), ApplicationError> {
+    let is_admin = account_repo.find_by_id(account).await?
+        .is_some_and(|account| admins.contains(account.name()));
+    if !is_admin {
+        return Err(ApplicationError::Forbidden("only administrators can manage reaction assets.".to_string()));
+    }
+    Ok(())
+}
+
+pub struct ReactToNoteInteractor<F, N, R, E> {
+    follow_repo: F,
+    note_repo: N,
+    reaction_repo: R,
+    asset_repo: E
+}
+
+impl<F, N, R, E> ReactToNoteInteractor<F, N, R, E> {
+    pub fn new(follow_repo: F, note_repo: N, reaction_repo: R, asset_repo: E) -> Self {
+        Self { follow_repo, note_repo, reaction_repo, asset_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, N, R, E> ReactToNoteAdaptor for ReactToNoteInteractor<F, N, R, E>
+  where F: FollowRepository,
+        N: NoteRepository,
+        R: ReactionRepository,
+        E: ReactionAssetRepository
+{
+    async fn react(&self, account: i64, note: Uuid, reaction: CreateReactionDto) -> Result<Vec<ReactionCountDto>, ApplicationError> {
+        let account = AccountId::new(account);
+        let note = NoteId::new(note);
+
+        visible_note(&self.follow_repo, &self.note_repo, Some(&account), &note, "react").await?;
+
+        let CreateReactionDto { reaction } = reaction;
+        let content = if reaction.starts_with(':') {
+            let alias = ReactionAlias::try_from(reaction.as_str())
+                .map_err(ApplicationError::field("reaction"))?;
+            let Some(asset) = self.asset_repo.find_by_alias(&alias).await? else {
+                return Err(ApplicationError::InvalidField {
+                    field: "reaction",
+                    reason: format!("`:{}:` is not a registered reaction.", alias.as_ref())
+                });
+            };
+            ReactionContent::Asset(*asset.id())
+        } else {
+            let emoji = Emoji::try_from(reaction.as_str())
+                .map_err(ApplicationError::field("reaction"))?;
+            ReactionContent::Emoji(emoji)
+        };
+
+        let reaction = Reaction::new(ReactionId::default(), note, account, content, OffsetDateTime::now_utc());
+
+        self.reaction_repo.create(&reaction).await?;
+
+        counts(&self.reaction_repo, &self.asset_repo, Some(&account), &note).await
+    }
+}
+
+pub struct UnreactToNoteInteractor<F, N, R> {
+    follow_repo: F,
+    note_repo: N,
+    reaction_repo: R
+}
+
+impl<F, N, R> UnreactToNoteInteractor<F, N, R> {
+    pub fn new(follow_repo: F, note_repo: N, reaction_repo: R) -> Self {
+        Self { follow_repo, note_repo, reaction_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, N, R> UnreactToNoteAdaptor for UnreactToNoteInteractor<F, N, R>
+  where F: FollowRepository,
+        N: NoteRepository,
+        R: ReactionRepository
+{
+    async fn unreact(&self, account: i64, note: Uuid) -> Result<(), ApplicationError> {
+        let account = AccountId::new(account);
+        let note = NoteId::new(note);
+
+        visible_note(&self.follow_repo, &self.note_repo, Some(&account), &note, "unreact").await?;
+
+        // Nothing to remove is not an error, so that retries are safe.
+        self.reaction_repo.delete(&note, &account.into()).await?;
+
+        Ok(())
+    }
+}
+
+pub struct GetReactionsInteractor<F, N, R, E> {
+    follow_repo: F,
+    note_repo: N,
+    reaction_repo: R,
+    asset_repo: E
+}
+
+impl<F, N, R, E> GetReactionsInteractor<F, N, R, E> {
+    pub fn new(follow_repo: F, note_repo: N, reaction_repo: R, asset_repo: E) -> Self {
+        Self { follow_repo, note_repo, reaction_repo, asset_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F, N, R, E> GetReactionsAdaptor for GetReactionsInteractor<F, N, R, E>
+  where F: FollowRepository,
+        N: NoteRepository,
+        R: ReactionRepository,
+        E: ReactionAssetRepository
+{
+    async fn reactions(&self, viewer: Option<i64>, note: Uuid) -> Result<Vec<ReactionCountDto>, ApplicationError> {
+        let viewer = viewer.map(AccountId::new);
+        let note = NoteId::new(note);
+
+        visible_note(&self.follow_repo, &self.note_repo, viewer.as_ref(), &note, "reactions").await?;
+
+        counts(&self.reaction_repo, &self.asset_repo, viewer.as_ref(), &note).await
+    }
+}
+
+pub struct RegisterReactionAssetInteractor<A, E> {
+    account_repo: A,
+    asset_repo: E,
+    admins: Administrators
+}
+
+impl<A, E> RegisterReactionAssetInteractor<A, E> {
+    pub fn new(account_repo: A, asset_repo: E, admins: Administrators) -> Self {
+        Self { account_repo, asset_repo, admins }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, E> RegisterReactionAssetAdaptor for RegisterReactionAssetInteractor<A, E>
+  where A: AccountRepository,
+        E: ReactionAssetRepository
+{
+    async fn register(&self, account: i64, asset: CreateReactionAssetDto) -> Result<ReactionAssetDto, ApplicationError> {
+        require_admin(&self.account_repo, &self.admins, &AccountId::new(account)).await?;
+
+        let CreateReactionAssetDto { alias, url, license_url, license_spdx } = asset;
+
+        let alias = ReactionAlias::try_from(alias.as_str())
+            .map_err(ApplicationError::field("alias"))?;
+        if self.asset_repo.find_by_alias(&alias).await?.is_some() {
+            return Err(ApplicationError::Conflict {
+                entity: "reaction_asset",
+                id: alias.into()
+            });
+        }
+
+        let url = AssetUrl::try_from(url)
+            .map_err(ApplicationError::field("url"))?;
+        let license = License::parse(license_url, license_spdx)
+            .map_err(ApplicationError::field("license"))?;
+
+        let asset = ReactionAsset::new(ReactionAssetId::default(), alias, url, license, OffsetDateTime::now_utc());
+
+        self.asset_repo.create(&asset).await?;
+
+        Ok(asset.into())
+    }
+}
+
+pub struct DeleteReactionAssetInteractor<A, E> {
+    account_repo: A,
+    asset_repo: E,
+    admins: Administrators
+}
+
+impl<A, E> DeleteReactionAssetInteractor<A, E> {
+    pub fn new(account_repo: A, asset_repo: E, admins: Administrators) -> Self {
+        Self { account_repo, asset_repo, admins }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, E> DeleteReactionAssetAdaptor for DeleteReactionAssetInteractor<A, E>
+  where A: AccountRepository,
+        E: ReactionAssetRepository
+{
+    async fn delete(&self, account: i64, id: Uuid) -> Result<(), ApplicationError> {
+        require_admin(&self.account_repo, &self.admins, &AccountId::new(account)).await?;
+
+        let id = ReactionAssetId::new(id);
+        if self.asset_repo.find_by_id(&id).await?.is_none() {
+            return Err(ApplicationError::NotFound {
+                method: "delete",
+                entity: "reaction_asset",
+                id: id.as_ref().to_string()
+            });
+        }
+
+        self.asset_repo.delete(&id).await?;
+
+        Ok(())
+    }
+}
+
+pub struct GetReactionAssetsInteractor<E> {
+    asset_repo: E
+}
+
+impl<E> GetReactionAssetsInteractor<E> {
+    pub fn new(asset_repo: E) -> Self {
+        Self { asset_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<E> GetReactionAssetsAdaptor for GetReactionAssetsInteractor<E>
+  where E: ReactionAssetRepository
+{
+    async fn assets(&self) -> Result<Vec<ReactionAssetDto>, ApplicationError> {
+        let assets = self.asset_repo.find_all().await?;
+        Ok(assets.into_iter().map(Into::into).collect())
+    }
+}
\ No newline at end of file
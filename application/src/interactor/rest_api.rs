@@ -1 +0,0 @@
-// Todo: Impl WebApi Interaction
\ No newline at end of file
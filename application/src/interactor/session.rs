@@ -0,0 +1,99 @@
+use kernel::{
+    repository::{SessionRepository, OAuthTokenRepository},
+    entities::{AccessToken, AccountId, Scopes, SessionToken}
+};
+
+use crate::{
+    adaptor::{AuthenticateAdaptor, LogoutAdaptor, RevokeSessionAdaptor},
+    transfer::CredentialDto,
+    ApplicationError
+};
+
+pub struct AuthenticateInteractor<S, T> {
+    session_repo: S,
+    token_repo: T
+}
+
+impl<S, T> AuthenticateInteractor<S, T> {
+    pub fn new(session_repo: S, token_repo: T) -> Self {
+        Self { session_repo, token_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<S, T> AuthenticateAdaptor for AuthenticateInteractor<S, T>
+  where S: SessionRepository,
+        T: OAuthTokenRepository
+{
+    async fn authenticate(&self, token: String) -> Result<CredentialDto, ApplicationError> {
+        let session_token = SessionToken::new(&token);
+
+        if let Some(session) = self.session_repo.find_by_token(&session_token).await? {
+            if session.expires_at().is_expired() {
+                self.session_repo.delete(&session_token).await?;
+                return Err(ApplicationError::Unauthorized);
+            }
+
+            // A first-party session may do anything the account itself can.
+            return Ok(CredentialDto {
+                account: (*session.account()).into(),
+                token,
+                scopes: Scopes::all().into(),
+                session: true
+            });
+        }
+
+        let Some(oauth) = self.token_repo.find_by_token(&AccessToken::new(&token)).await? else {
+            return Err(ApplicationError::Unauthorized);
+        };
+
+        Ok(CredentialDto {
+            account: (*oauth.account()).into(),
+            token,
+            scopes: oauth.scopes().clone().into(),
+            session: false
+        })
+    }
+}
+
+pub struct LogoutInteractor<T> {
+    repo: T
+}
+
+impl<T> LogoutInteractor<T> {
+    pub fn new(repo: T) -> Self {
+        Self { repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> LogoutAdaptor for LogoutInteractor<T>
+  where T: SessionRepository
+{
+    async fn logout(&self, token: String) -> Result<(), ApplicationError> {
+        let token = SessionToken::new(token);
+        self.repo.delete(&token).await?;
+        Ok(())
+    }
+}
+
+pub struct RevokeSessionInteractor<T> {
+    repo: T
+}
+
+impl<T> RevokeSessionInteractor<T> {
+    pub fn new(repo: T) -> Self {
+        Self { repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> RevokeSessionAdaptor for RevokeSessionInteractor<T>
+  where T: SessionRepository
+{
+    async fn revoke_all(&self, account: i64) -> Result<(), ApplicationError> {
+        let account = AccountId::new(account);
+        self.repo.delete_all_by_account(&account).await?;
+        Ok(())
+    }
+}
\ No newline at end of file
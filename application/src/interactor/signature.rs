@@ -0,0 +1,186 @@
+use kernel::{
+    repository::RemoteKeyRepository,
+    entities::{body_digest, parse_http_date, HttpSignature, RemoteKey},
+    service::KeyFetcher
+};
+use time::OffsetDateTime;
+
+use crate::{
+    adaptor::VerifySignatureAdaptor,
+    transfer::{SignedRequestDto, SignerDto},
+    ApplicationError
+};
+
+pub struct VerifySignatureInteractor<R, F> {
+    remote_key_repo: R,
+    key_fetcher: F
+}
+
+impl<R, F> VerifySignatureInteractor<R, F> {
+    pub fn new(remote_key_repo: R, key_fetcher: F) -> Self {
+        Self { remote_key_repo, key_fetcher }
+    }
+}
+
+/// Whether the `SHA-256` entry of a `Digest` header is that of `body`. Other algorithms are ignored.
+fn matches_digest(header: &str, body: &[u8]) -> bool {
+    let expected = body_digest(body);
+    header.split(',')
+        .map(str::trim)
+        .filter_map(|entry| entry.split_once('='))
+        .find(|(algorithm, _)| algorithm.eq_ignore_ascii_case("sha-256"))
+        .is_some_and(|(_, value)| expected.strip_prefix("SHA-256=") == Some(value))
+}
+
+fn invalid(reason: impl Into<String>) -> ApplicationError {
+    ApplicationError::InvalidField { field: "signature", reason: reason.into() }
+}
+
+#[async_trait::async_trait]
+impl<R, F> VerifySignatureAdaptor for VerifySignatureInteractor<R, F>
+  where R: RemoteKeyRepository,
+        F: KeyFetcher
+{
+    /// Requires `(request-target)`, `host` and `date` to be signed, and `digest` for requests with a body.
+    /// Cached keys that fail are fetched again, in case the actor rotated its key,
+    /// but no more often than [`RemoteKey::REFETCH_INTERVAL`].
+    async fn verify(&self, request: SignedRequestDto) -> Result<SignerDto, ApplicationError> {
+        let SignedRequestDto { method, path, headers, body } = request;
+
+        let header = headers.get("signature")
+            .map(String::as_str)
+            .or_else(|| headers.get("authorization").and_then(|value| value.strip_prefix("Signature ")))
+            .ok_or(ApplicationError::Unauthorized)?;
+        let signature = HttpSignature::parse(header)
+            .map_err(|e| invalid(e.to_string()))?;
+
+        if signature.algorithm().is_some_and(|algorithm| !HttpSignature::ALGORITHMS.contains(&algorithm)) {
+            return Err(invalid("only `rsa-sha256` signatures are supported."));
+        }
+
+        let mut required = vec!["(request-target)", "host", "date"];
+        if !body.is_empty() {
+            required.push("digest");
+        }
+        if let Some(missing) = required.iter().find(|header| !signature.covers(header)) {
+            return Err(invalid(format!("`{}` must be signed.", missing)));
+        }
+
+        let date = headers.get("date")
+            .and_then(|date| parse_http_date(date))
+            .ok_or_else(|| invalid("`date` is not a valid http date."))?;
+        if (OffsetDateTime::now_utc() - date).abs() > HttpSignature::MAX_SKEW {
+            return Err(ApplicationError::Unauthorized);
+        }
+
+        if !body.is_empty() && !headers.get("digest").is_some_and(|digest| matches_digest(digest, &body)) {
+            return Err(ApplicationError::Unauthorized);
+        }
+
+        let signing_string = signature.signing_string(&method, &path, |name| headers.get(name).cloned())
+            .map_err(|e| invalid(e.to_string()))?;
+
+        let verified = |key: &RemoteKey| signature.verify(&signing_string, key.public_key());
+
+        if let Some(cached) = self.remote_key_repo.find_by_id(signature.key_id()).await? {
+            if verified(&cached) {
+                return Ok(SignerDto { key_id: cached.id().to_string(), owner: cached.owner().to_string() });
+            }
+            // Otherwise any request naming the key would make us fetch it from its server.
+            if !cached.can_refetch(&OffsetDateTime::now_utc()) {
+                return Err(ApplicationError::Unauthorized);
+            }
+        }
+
+        let Some(fetched) = self.key_fetcher.fetch(signature.key_id()).await? else {
+            return Err(ApplicationError::Unauthorized);
+        };
+        self.remote_key_repo.save(&fetched).await?;
+
+        if !verified(&fetched) {
+            return Err(ApplicationError::Unauthorized);
+        }
+
+        Ok(SignerDto { key_id: fetched.id().to_string(), owner: fetched.owner().to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use kernel::{
+        repository::MockRemoteKeyRepository,
+        entities::{AccountId, AccountKey, RemoteKey, RequestSigner},
+        service::MockKeyFetcher
+    };
+    use time::OffsetDateTime;
+    use url::Url;
+
+    use crate::{adaptor::VerifySignatureAdaptor, transfer::SignedRequestDto, ApplicationError};
+
+    use super::VerifySignatureInteractor;
+
+    const KEY_ID: &str = "https://remote.example/users/a#main-key";
+    const OWNER: &str = "https://remote.example/users/a";
+
+    fn signed(key: &AccountKey) -> SignedRequestDto {
+        let url = Url::parse("https://local.example/inbox").unwrap();
+        let body = br#"{"type":"Follow"}"#.to_vec();
+        let signed = RequestSigner::new(KEY_ID, key.private_key().clone()).sign("POST", &url, Some(&body)).unwrap();
+        let headers = HashMap::from([
+            ("host".to_string(), signed.host),
+            ("date".to_string(), signed.date),
+            ("digest".to_string(), signed.digest.unwrap()),
+            ("signature".to_string(), signed.signature)
+        ]);
+        SignedRequestDto { method: "POST".to_string(), path: "/inbox".to_string(), headers, body }
+    }
+
+    fn remote(key: &AccountKey, fetched_at: OffsetDateTime) -> RemoteKey {
+        RemoteKey::new(KEY_ID, OWNER, key.public_key().as_ref(), fetched_at)
+    }
+
+    #[tokio::test]
+    async fn test_rotated_key() {
+        let old = AccountKey::generate(AccountId::default()).unwrap();
+        let new = AccountKey::generate(AccountId::default()).unwrap();
+        let stale = remote(&old, OffsetDateTime::now_utc() - RemoteKey::REFETCH_INTERVAL);
+        let fetched = remote(&new, OffsetDateTime::now_utc());
+
+        let mut remote_key_repo = MockRemoteKeyRepository::new();
+        remote_key_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(stale.clone())));
+        remote_key_repo.expect_save()
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut key_fetcher = MockKeyFetcher::new();
+        key_fetcher.expect_fetch()
+            .times(1)
+            .returning(move |_| Ok(Some(fetched.clone())));
+
+        let interactor = VerifySignatureInteractor::new(remote_key_repo, key_fetcher);
+        let signer = interactor.verify(signed(&new)).await.unwrap();
+        assert_eq!(signer.key_id, KEY_ID);
+        assert_eq!(signer.owner, OWNER);
+    }
+
+    #[tokio::test]
+    async fn test_refetch_interval() {
+        let cached = AccountKey::generate(AccountId::default()).unwrap();
+        let other = AccountKey::generate(AccountId::default()).unwrap();
+        let fresh = remote(&cached, OffsetDateTime::now_utc());
+
+        // A key fetched a moment ago is not fetched again for a signature it does not verify.
+        let mut remote_key_repo = MockRemoteKeyRepository::new();
+        remote_key_repo.expect_find_by_id()
+            .returning(move |_| Ok(Some(fresh.clone())));
+        remote_key_repo.expect_save().never();
+        let mut key_fetcher = MockKeyFetcher::new();
+        key_fetcher.expect_fetch().never();
+
+        let interactor = VerifySignatureInteractor::new(remote_key_repo, key_fetcher);
+        assert!(matches!(interactor.verify(signed(&other)).await, Err(ApplicationError::Unauthorized)));
+        assert!(interactor.verify(signed(&cached)).await.is_ok());
+    }
+}
\ No newline at end of file
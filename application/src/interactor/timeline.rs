@@ -0,0 +1,369 @@
+use kernel::{
+    repository::{AccountRepository, FollowRepository, TimelineCacheRepository, TimelineRepository},
+    entities::{AccountId, AccountName, AccountTypes, Pagination, TimelineCursor, TimelineEntry, Visibility}
+};
+
+use crate::{
+    adaptor::{GetAccountNotesAdaptor, GetHomeTimelineAdaptor, GetLocalTimelineAdaptor, GetFederatedTimelineAdaptor},
+    transfer::{PaginationDto, TimelineEntryDto},
+    service::{find_accounts, is_visible},
+    ApplicationError
+};
+
+/// Drops boosts of notes `viewer` cannot see and attributes the rest to their boosters.
+async fn listing(
+    account_repo: &impl AccountRepository,
+    follow_repo: &impl FollowRepository,
+    viewer: Option<&AccountId>,
+    entries: Vec<TimelineEntry>
+) -> Result<Vec<TimelineEntryDto>, ApplicationError> {
+    // Boosted notes have audiences of their own.
+    let mut visible = Vec::with_capacity(entries.len());
+    for entry in entries {
+        if let TimelineEntry::Boost(_, note) = &entry {
+            if !is_visible(follow_repo, viewer, note).await? {
+                continue;
+            }
+        }
+        visible.push(entry);
+    }
+
+    let boosters = visible.iter()
+        .filter_map(|entry| match entry {
+            TimelineEntry::Boost(boost, _) => Some(boost.account()),
+            TimelineEntry::Note(_) | TimelineEntry::Remote(..) => None
+        });
+    let accounts = find_accounts(account_repo, boosters).await?;
+
+    Ok(visible.into_iter()
+        .filter_map(|entry| TimelineEntryDto::new(entry, &accounts))
+        .collect())
+}
+
+pub struct GetAccountNotesInteractor<A, F, T> {
+    account_repo: A,
+    follow_repo: F,
+    timeline_repo: T
+}
+
+impl<A, F, T> GetAccountNotesInteractor<A, F, T> {
+    pub fn new(account_repo: A, follow_repo: F, timeline_repo: T) -> Self {
+        Self { account_repo, follow_repo, timeline_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F, T> GetAccountNotesAdaptor for GetAccountNotesInteractor<A, F, T>
+  where A: AccountRepository,
+        F: FollowRepository,
+        T: TimelineRepository
+{
+    async fn notes(&self, viewer: Option<i64>, name: String, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError> {
+        let viewer = viewer.map(AccountId::new);
+        let name = AccountName::new(name);
+        let Some(account) = self.account_repo.find_by_name(&name).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "notes",
+                entity: "account",
+                id: name.into()
+            });
+        };
+
+        let visibilities: &[Visibility] = match viewer {
+            Some(viewer) if viewer == *account.id() => &[
+                Visibility::Public, Visibility::Unlisted, Visibility::FollowersOnly, Visibility::Direct
+            ],
+            Some(viewer) => {
+                let follow = self.follow_repo.find(&AccountTypes::Local(viewer), &AccountTypes::Local(*account.id())).await?;
+                if follow.is_some_and(|follow| follow.state().is_accepted()) {
+                    &[Visibility::Public, Visibility::Unlisted, Visibility::FollowersOnly]
+                } else {
+                    &[Visibility::Public, Visibility::Unlisted]
+                }
+            },
+            None => &[Visibility::Public, Visibility::Unlisted]
+        };
+
+        let page = Pagination::from(page);
+
+        let entries = self.timeline_repo.find_by_account(account.id(), visibilities, viewer, &page).await?;
+
+        listing(&self.account_repo, &self.follow_repo, viewer.as_ref(), entries).await
+    }
+}
+
+/// Reads the home timeline from `timeline_cache` first, and from the database on a miss.
+pub struct GetHomeTimelineInteractor<A, F, T, K> {
+    account_repo: A,
+    follow_repo: F,
+    timeline_repo: T,
+    timeline_cache: K
+}
+
+impl<A, F, T, K> GetHomeTimelineInteractor<A, F, T, K> {
+    pub fn new(account_repo: A, follow_repo: F, timeline_repo: T, timeline_cache: K) -> Self {
+        Self { account_repo, follow_repo, timeline_repo, timeline_cache }
+    }
+}
+
+#[async_trait::async_trait]
+impl<A, F, T, K> GetHomeTimelineAdaptor for GetHomeTimelineInteractor<A, F, T, K>
+  where A: AccountRepository,
+        F: FollowRepository,
+        T: TimelineRepository,
+        K: TimelineCacheRepository
+{
+    async fn home(&self, account: i64, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError> {
+        let account = AccountId::new(account);
+        let page = Pagination::from(page);
+
+        // The cache only saves work, so the database still answers while it is unavailable.
+        let cached = self.timeline_cache.find(&account, &page).await
+            .unwrap_or_else(|e| {
+                tracing::warn!("failed to read a cached home timeline. {:?}", e);
+                None
+            });
+
+        // Entries deleted since they were cached are skipped, which can leave the page short.
+        if let Some(ids) = cached {
+            let entries = self.timeline_repo.find_by_ids(&ids).await?;
+            return listing(&self.account_repo, &self.follow_repo, Some(&account), entries).await;
+        }
+
+        // Only accepted follows are listed, so followers-only notes are fine to include.
+        let following = self.follow_repo.find_followings(&AccountTypes::Local(account)).await?
+            .into_iter()
+            .filter_map(|follow| match follow.destination() {
+                AccountTypes::Local(id) => Some(*id),
+                AccountTypes::Federate(_) => None
+            })
+            .collect::<Vec<AccountId>>();
+
+        // Missing the newest page means the timeline is not cached at all.
+        if page.max_id().is_none() && page.newer_than().is_none() {
+            let cursors = self.timeline_repo.find_home_cursors(&account, &following, TimelineCursor::HOME_CAPACITY).await?;
+            if let Err(e) = self.timeline_cache.rebuild(&account, &cursors).await {
+                tracing::warn!("failed to rebuild a cached home timeline. {:?}", e);
+            }
+        }
+
+        let entries = self.timeline_repo.find_home(&account, &following, &page).await?;
+
+        listing(&self.account_repo, &self.follow_repo, Some(&account), entries).await
+    }
+}
+
+pub struct GetLocalTimelineInteractor<T> {
+    timeline_repo: T
+}
+
+impl<T> GetLocalTimelineInteractor<T> {
+    pub fn new(timeline_repo: T) -> Self {
+        Self { timeline_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> GetLocalTimelineAdaptor for GetLocalTimelineInteractor<T>
+  where T: TimelineRepository
+{
+    async fn local(&self, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError> {
+        let entries = self.timeline_repo.find_public(&Pagination::from(page)).await?;
+
+        // Public listings carry no boosts, so there are no boosters to look up.
+        Ok(entries.into_iter()
+            .filter_map(|entry| TimelineEntryDto::new(entry, &Default::default()))
+            .collect())
+    }
+}
+
+pub struct GetFederatedTimelineInteractor<T> {
+    timeline_repo: T
+}
+
+impl<T> GetFederatedTimelineInteractor<T> {
+    pub fn new(timeline_repo: T) -> Self {
+        Self { timeline_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> GetFederatedTimelineAdaptor for GetFederatedTimelineInteractor<T>
+  where T: TimelineRepository
+{
+    async fn federated(&self, page: PaginationDto) -> Result<Vec<TimelineEntryDto>, ApplicationError> {
+        let entries = self.timeline_repo.find_federated(&Pagination::from(page)).await?;
+
+        Ok(entries.into_iter()
+            .filter_map(|entry| TimelineEntryDto::new(entry, &Default::default()))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{
+        repository::{MockAccountRepository, MockFollowRepository, MockTimelineCacheRepository, MockTimelineRepository},
+        entities::{
+            Account, AccountId, AccountTypes, Follow, FollowState, Note, NoteId, RemoteNote, TimelineCursor,
+            TimelineEntry, Visibility
+        },
+        KernelError
+    };
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use crate::{
+        adaptor::{GetAccountNotesAdaptor, GetFederatedTimelineAdaptor, GetHomeTimelineAdaptor},
+        transfer::{AnyNoteDto, PaginationDto, TimelineEntryDto}
+    };
+
+    use super::{GetAccountNotesInteractor, GetFederatedTimelineInteractor, GetHomeTimelineInteractor};
+
+    fn note(author: AccountId, visibility: Visibility) -> Note {
+        Note::new(
+            NoteId::default(), author, "note", None::<String>, visibility, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), OffsetDateTime::now_utc()
+        )
+    }
+
+    fn follow(source: AccountId, destination: impl Into<AccountTypes>) -> Follow {
+        Follow::new(Uuid::new_v4(), source, destination, FollowState::Accepted, OffsetDateTime::now_utc())
+    }
+
+    fn ids(entries: &[TimelineEntryDto]) -> Vec<Uuid> {
+        entries.iter().map(|entry| entry.id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_home_cached() {
+        let account = AccountId::default();
+        let cached = note(AccountId::default(), Visibility::Public);
+        let cached_id = *cached.id().as_ref();
+
+        let mut timeline_cache = MockTimelineCacheRepository::new();
+        timeline_cache.expect_find()
+            .returning(move |_, _| Ok(Some(vec![cached_id])));
+        timeline_cache.expect_rebuild().never();
+
+        let mut timeline_repo = MockTimelineRepository::new();
+        timeline_repo.expect_find_by_ids()
+            .withf(move |ids| ids == [cached_id])
+            .returning(move |_| Ok(vec![TimelineEntry::Note(cached.clone())]));
+        timeline_repo.expect_find_home().never();
+
+        let interactor = GetHomeTimelineInteractor::new(
+            MockAccountRepository::new(), MockFollowRepository::new(), timeline_repo, timeline_cache
+        );
+        let found = interactor.home(account.into(), PaginationDto::default()).await.unwrap();
+        assert_eq!(ids(&found), vec![cached_id]);
+    }
+
+    #[tokio::test]
+    async fn test_home_rebuild() {
+        let account = AccountId::default();
+        let followed = AccountId::default();
+        let entry = note(followed, Visibility::FollowersOnly);
+        let entry_id = *entry.id().as_ref();
+
+        // An unavailable cache is read past, and a failed rebuild does not fail the read.
+        let mut timeline_cache = MockTimelineCacheRepository::new();
+        timeline_cache.expect_find()
+            .returning(|_, _| Err(KernelError::Driver(anyhow::anyhow!("connection refused"))));
+        timeline_cache.expect_rebuild()
+            .times(1)
+            .returning(|_, _| Err(KernelError::Driver(anyhow::anyhow!("connection refused"))));
+
+        // Remote follows have no local notes to list.
+        let mut follow_repo = MockFollowRepository::new();
+        follow_repo.expect_find_followings()
+            .returning(move |_| Ok(vec![follow(account, followed), follow(account, AccountTypes::Federate("https://remote.example/users/a".to_string()))]));
+
+        let mut timeline_repo = MockTimelineRepository::new();
+        let cursor = TimelineCursor::new(entry_id, *entry.created_at().as_ref());
+        timeline_repo.expect_find_home_cursors()
+            .withf(move |_, following, limit| following == [followed] && *limit == TimelineCursor::HOME_CAPACITY)
+            .returning(move |_, _, _| Ok(vec![cursor.clone()]));
+        timeline_repo.expect_find_home()
+            .withf(move |_, following, _| following == [followed])
+            .returning(move |_, _, _| Ok(vec![TimelineEntry::Note(entry.clone())]));
+
+        let interactor = GetHomeTimelineInteractor::new(MockAccountRepository::new(), follow_repo, timeline_repo, timeline_cache);
+        let found = interactor.home(account.into(), PaginationDto::default()).await.unwrap();
+        assert_eq!(ids(&found), vec![entry_id]);
+
+        // Older pages are not what the cache keeps, so they do not rebuild it.
+        let mut timeline_cache = MockTimelineCacheRepository::new();
+        timeline_cache.expect_find().returning(|_, _| Ok(None));
+        timeline_cache.expect_rebuild().never();
+        let mut follow_repo = MockFollowRepository::new();
+        follow_repo.expect_find_followings().returning(|_| Ok(Vec::new()));
+        let mut timeline_repo = MockTimelineRepository::new();
+        timeline_repo.expect_find_home().returning(|_, _, _| Ok(Vec::new()));
+
+        let interactor = GetHomeTimelineInteractor::new(MockAccountRepository::new(), follow_repo, timeline_repo, timeline_cache);
+        let page = PaginationDto { max_id: Some(entry_id), ..Default::default() };
+        assert!(interactor.home(account.into(), page).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_account_notes_visibilities() {
+        let owner = Account::new(AccountId::default(), "owner", false, false, OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
+        let owner_id = *owner.id();
+        let follower = AccountId::default();
+        let stranger = AccountId::default();
+
+        let cases = [
+            (Some(owner_id), vec![Visibility::Public, Visibility::Unlisted, Visibility::FollowersOnly, Visibility::Direct]),
+            (Some(follower), vec![Visibility::Public, Visibility::Unlisted, Visibility::FollowersOnly]),
+            (Some(stranger), vec![Visibility::Public, Visibility::Unlisted]),
+            (None, vec![Visibility::Public, Visibility::Unlisted])
+        ];
+        for (viewer, expected) in cases {
+            let mut account_repo = MockAccountRepository::new();
+            let found = owner.clone();
+            account_repo.expect_find_by_name().returning(move |_| Ok(Some(found.clone())));
+
+            let mut follow_repo = MockFollowRepository::new();
+            follow_repo.expect_find()
+                .returning(move |source, destination| Ok(
+                    (*source == AccountTypes::Local(follower) && *destination == AccountTypes::Local(owner_id))
+                        .then(|| follow(follower, owner_id))
+                ));
+
+            let mut timeline_repo = MockTimelineRepository::new();
+            timeline_repo.expect_find_by_account()
+                .withf(move |account, visibilities, found_viewer, _| {
+                    *account == owner_id && visibilities == expected.as_slice() && *found_viewer == viewer
+                })
+                .times(1)
+                .returning(|_, _, _, _| Ok(Vec::new()));
+
+            let interactor = GetAccountNotesInteractor::new(account_repo, follow_repo, timeline_repo);
+            interactor.notes(viewer.map(Into::into), "owner".to_string(), PaginationDto::default()).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_federated() {
+        let local = note(AccountId::default(), Visibility::Public);
+        let remote = RemoteNote::new(
+            "https://remote.example/notes/1", "https://remote.example/users/a", "<p>remote</p>", None::<String>,
+            Visibility::Public, None, OffsetDateTime::now_utc()
+        );
+        let cursor = Uuid::new_v4();
+
+        let mut timeline_repo = MockTimelineRepository::new();
+        let entries = vec![TimelineEntry::Remote(cursor, remote.clone()), TimelineEntry::Note(local.clone())];
+        timeline_repo.expect_find_federated()
+            .returning(move |_| Ok(entries.clone()));
+
+        let interactor = GetFederatedTimelineInteractor::new(timeline_repo);
+        let found = interactor.federated(PaginationDto::default()).await.unwrap();
+
+        // Remote notes are paged by their cursor and keep their url as the note id.
+        assert_eq!(ids(&found), vec![cursor, *local.id().as_ref()]);
+        assert!(matches!(&found[0].note, AnyNoteDto::Remote(note) if note.id == remote.id().as_ref()));
+        assert!(matches!(&found[1].note, AnyNoteDto::Local(note) if note.id == *local.id().as_ref()));
+    }
+}
\ No newline at end of file
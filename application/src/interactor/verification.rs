@@ -0,0 +1,262 @@
+use kernel::{
+    repository::{ConfidentialRepository, VerificationRepository, PasswordResetRepository, SessionRepository, OAuthTokenRepository},
+    entities::{
+        AccountId, Address, Confidential, DestructUpdateTime, Mail, Password, PasswordReset,
+        PasswordResetToken, UpdateTime, Verification, VerificationToken
+    },
+    service::Mailer
+};
+use time::OffsetDateTime;
+
+use crate::{
+    adaptor::{VerifyAccountAdaptor, ResendVerificationAdaptor, RequestPasswordResetAdaptor, ResetPasswordAdaptor},
+    transfer::{VerifyAccountDto, RequestPasswordResetDto, ResetPasswordDto},
+    service::hash_password,
+    ApplicationError
+};
+
+/// Creates a new verification token for `confidential` and mails it to the registered address.
+pub(crate) async fn issue_verification(
+    repo: &impl VerificationRepository,
+    mailer: &impl Mailer,
+    confidential: &Confidential
+) -> Result<(), ApplicationError> {
+    let created_at = OffsetDateTime::now_utc();
+    let verification = Verification::new(
+        VerificationToken::default(),
+        *confidential.account(),
+        created_at,
+        created_at + Verification::LIFETIME
+    );
+
+    repo.create(&verification).await?;
+
+    let mail = Mail::new(
+        confidential.address().as_ref(),
+        "Verify your email address",
+        format!(
+            "Welcome to ShuttlePub!\n\n\
+             Use the following token to verify your email address.\n\
+             It expires in {} hours.\n\n\
+             {}\n",
+            Verification::LIFETIME.whole_hours(),
+            verification.token().as_ref()
+        )
+    );
+
+    mailer.send(&mail).await?;
+
+    Ok(())
+}
+
+fn invalid_token() -> ApplicationError {
+    ApplicationError::Convert("token is invalid or expired.".to_string())
+}
+
+/// Marks `confidential` as verified, optionally replacing the password.
+fn verified(confidential: Confidential, pass: Option<Password>) -> Confidential {
+    let mut destructed = confidential.into_destruct();
+    let DestructUpdateTime { created_at, .. } = destructed.date.into_destruct();
+
+    destructed.date     = UpdateTime::new(created_at, OffsetDateTime::now_utc());
+    destructed.pass     = pass.unwrap_or(destructed.pass);
+    destructed.verified = true;
+
+    destructed.freeze()
+}
+
+pub struct VerifyAccountInteractor<C, V> {
+    confidential_repo: C,
+    verification_repo: V
+}
+
+impl<C, V> VerifyAccountInteractor<C, V> {
+    pub fn new(confidential_repo: C, verification_repo: V) -> Self {
+        Self { confidential_repo, verification_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C, V> VerifyAccountAdaptor for VerifyAccountInteractor<C, V>
+  where C: ConfidentialRepository,
+        V: VerificationRepository
+{
+    async fn verify(&self, verify: VerifyAccountDto) -> Result<(), ApplicationError> {
+        let token = VerificationToken::new(verify.token);
+
+        let Some(verification) = self.verification_repo.take(&token).await? else {
+            return Err(invalid_token());
+        };
+
+        if verification.expires_at().is_expired() {
+            return Err(invalid_token());
+        }
+
+        let Some(confidential) = self.confidential_repo.find_by_account_id(verification.account()).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "verify",
+                entity: "confidential",
+                id: format!("{:?}", verification.account())
+            });
+        };
+
+        self.confidential_repo.update(&verified(confidential, None)).await?;
+        self.verification_repo.delete_all_by_account(verification.account()).await?;
+
+        Ok(())
+    }
+}
+
+pub struct ResendVerificationInteractor<C, V, M> {
+    confidential_repo: C,
+    verification_repo: V,
+    mailer: M
+}
+
+impl<C, V, M> ResendVerificationInteractor<C, V, M> {
+    pub fn new(confidential_repo: C, verification_repo: V, mailer: M) -> Self {
+        Self { confidential_repo, verification_repo, mailer }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C, V, M> ResendVerificationAdaptor for ResendVerificationInteractor<C, V, M>
+  where C: ConfidentialRepository,
+        V: VerificationRepository,
+        M: Mailer
+{
+    async fn resend(&self, account: i64) -> Result<(), ApplicationError> {
+        let account = AccountId::new(account);
+
+        let Some(confidential) = self.confidential_repo.find_by_account_id(&account).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "resend",
+                entity: "confidential",
+                id: format!("{:?}", account)
+            });
+        };
+
+        if confidential.is_verified() {
+            return Ok(());
+        }
+
+        // Only the latest token stays valid.
+        self.verification_repo.delete_all_by_account(&account).await?;
+        issue_verification(&self.verification_repo, &self.mailer, &confidential).await?;
+
+        Ok(())
+    }
+}
+
+pub struct RequestPasswordResetInteractor<C, P, M> {
+    confidential_repo: C,
+    reset_repo: P,
+    mailer: M
+}
+
+impl<C, P, M> RequestPasswordResetInteractor<C, P, M> {
+    pub fn new(confidential_repo: C, reset_repo: P, mailer: M) -> Self {
+        Self { confidential_repo, reset_repo, mailer }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C, P, M> RequestPasswordResetAdaptor for RequestPasswordResetInteractor<C, P, M>
+  where C: ConfidentialRepository,
+        P: PasswordResetRepository,
+        M: Mailer
+{
+    async fn request(&self, request: RequestPasswordResetDto) -> Result<(), ApplicationError> {
+        // Always succeeds so that registered addresses cannot be enumerated.
+        let Ok(address) = Address::try_from(request.address) else {
+            return Ok(());
+        };
+
+        let Some(confidential) = self.confidential_repo.find_by_address(&address).await? else {
+            return Ok(());
+        };
+
+        self.reset_repo.delete_all_by_account(confidential.account()).await?;
+
+        let created_at = OffsetDateTime::now_utc();
+        let reset = PasswordReset::new(
+            PasswordResetToken::default(),
+            *confidential.account(),
+            created_at,
+            created_at + PasswordReset::LIFETIME
+        );
+
+        self.reset_repo.create(&reset).await?;
+
+        let mail = Mail::new(
+            confidential.address().as_ref(),
+            "Reset your password",
+            format!(
+                "A password reset was requested for your ShuttlePub account.\n\
+                 Use the following token to set a new password.\n\
+                 It expires in {} minutes. If you did not request this, ignore this mail.\n\n\
+                 {}\n",
+                PasswordReset::LIFETIME.whole_minutes(),
+                reset.token().as_ref()
+            )
+        );
+
+        self.mailer.send(&mail).await?;
+
+        Ok(())
+    }
+}
+
+pub struct ResetPasswordInteractor<C, P, S, T> {
+    confidential_repo: C,
+    reset_repo: P,
+    session_repo: S,
+    token_repo: T
+}
+
+impl<C, P, S, T> ResetPasswordInteractor<C, P, S, T> {
+    pub fn new(confidential_repo: C, reset_repo: P, session_repo: S, token_repo: T) -> Self {
+        Self { confidential_repo, reset_repo, session_repo, token_repo }
+    }
+}
+
+#[async_trait::async_trait]
+impl<C, P, S, T> ResetPasswordAdaptor for ResetPasswordInteractor<C, P, S, T>
+  where C: ConfidentialRepository,
+        P: PasswordResetRepository,
+        S: SessionRepository,
+        T: OAuthTokenRepository
+{
+    async fn reset(&self, reset: ResetPasswordDto) -> Result<(), ApplicationError> {
+        let ResetPasswordDto { token, pass } = reset;
+        let token = PasswordResetToken::new(token);
+
+        // Validate before the token is consumed so that a rejected password can be retried.
+        let pass = hash_password(pass).await?;
+
+        let Some(reset) = self.reset_repo.take(&token).await? else {
+            return Err(invalid_token());
+        };
+
+        if reset.expires_at().is_expired() {
+            return Err(invalid_token());
+        }
+
+        let Some(confidential) = self.confidential_repo.find_by_account_id(reset.account()).await? else {
+            return Err(ApplicationError::NotFound {
+                method: "reset",
+                entity: "confidential",
+                id: format!("{:?}", reset.account())
+            });
+        };
+
+        // Receiving the token proves control of the address as well.
+        self.confidential_repo.update(&verified(confidential, Some(pass))).await?;
+
+        // Anyone holding the old password may have signed in already.
+        self.session_repo.delete_all_by_account(reset.account()).await?;
+        self.token_repo.delete_all_by_account(reset.account()).await?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 pub mod adaptor;
 pub mod transfer;
 pub mod interactor;
+pub mod service;
 
 mod error;
 
@@ -0,0 +1,21 @@
+mod account;
+mod audience;
+mod delivery;
+mod federation;
+mod hashtag;
+mod key;
+mod mention;
+mod password;
+mod timeline;
+
+pub use self::{
+    account::*,
+    audience::*,
+    delivery::*,
+    federation::*,
+    hashtag::*,
+    key::*,
+    mention::*,
+    password::*,
+    timeline::*,
+};
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use kernel::{
+    repository::AccountRepository,
+    entities::{Account, AccountId, AccountTypes}
+};
+
+use crate::ApplicationError;
+
+/// Looks up the local accounts among `accounts` at once, for building listings.
+/// Accounts that no longer exist are missing from the result.
+pub async fn find_accounts<'a>(
+    account_repo: &impl AccountRepository,
+    accounts: impl IntoIterator<Item = &'a AccountTypes>
+) -> Result<HashMap<AccountId, Account>, ApplicationError> {
+    let ids = accounts.into_iter()
+        .filter_map(|account| match account {
+            AccountTypes::Local(id) => Some(*id),
+            AccountTypes::Federate(_) => None
+        })
+        .collect::<Vec<AccountId>>();
+
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    Ok(account_repo.find_by_ids(&ids).await?
+        .into_iter()
+        .map(|account| (*account.id(), account))
+        .collect())
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use kernel::{
+    repository::{FollowRepository, NoteRepository},
+    entities::{AccountId, AccountTypes, Note, NoteId, RemoteNote, Visibility}
+};
+
+use crate::ApplicationError;
+
+/// Whether `viewer` may read `note`. `None` is an anonymous viewer.
+///
+/// Callers report a hidden note as missing so that its existence does not leak.
+pub async fn is_visible(
+    follow_repo: &impl FollowRepository,
+    viewer: Option<&AccountId>,
+    note: &Note
+) -> Result<bool, ApplicationError> {
+    if viewer == Some(note.author()) {
+        return Ok(true);
+    }
+
+    // Mentioned accounts are addressed directly, whatever the visibility.
+    if let Some(viewer) = viewer {
+        if note.mentions().contains(&AccountTypes::Local(*viewer)) {
+            return Ok(true);
+        }
+    }
+
+    match note.visibility() {
+        Visibility::Public | Visibility::Unlisted => Ok(true),
+        Visibility::FollowersOnly => {
+            let Some(viewer) = viewer else {
+                return Ok(false);
+            };
+
+            let follow = follow_repo.find(&AccountTypes::Local(*viewer), &AccountTypes::Local(*note.author())).await?;
+
+            // A pending request to a locked account does not count.
+            Ok(follow.is_some_and(|follow| follow.state().is_accepted()))
+        },
+        Visibility::Direct => Ok(false)
+    }
+}
+
+/// Whether the remote `actor` may read `note`, by the same rules as [`is_visible`].
+pub async fn is_visible_to_remote(
+    follow_repo: &impl FollowRepository,
+    actor: &str,
+    note: &Note
+) -> Result<bool, ApplicationError> {
+    let actor = AccountTypes::Federate(actor.to_string());
+    if note.mentions().contains(&actor) {
+        return Ok(true);
+    }
+
+    match note.visibility() {
+        Visibility::Public | Visibility::Unlisted => Ok(true),
+        Visibility::FollowersOnly => {
+            let follow = follow_repo.find(&actor, &AccountTypes::Local(*note.author())).await?;
+            Ok(follow.is_some_and(|follow| follow.state().is_accepted()))
+        },
+        Visibility::Direct => Ok(false)
+    }
+}
+
+/// Whether `viewer` may read the remote `note`.
+/// Recipients of remote notes are not stored, so direct ones are never shown.
+pub async fn is_remote_visible(
+    follow_repo: &impl FollowRepository,
+    viewer: Option<&AccountId>,
+    note: &RemoteNote
+) -> Result<bool, ApplicationError> {
+    match note.visibility() {
+        Visibility::Public | Visibility::Unlisted => Ok(true),
+        Visibility::FollowersOnly => {
+            let Some(viewer) = viewer else {
+                return Ok(false);
+            };
+
+            let author = AccountTypes::Federate(note.author().to_string());
+            let follow = follow_repo.find(&AccountTypes::Local(*viewer), &author).await?;
+            Ok(follow.is_some_and(|follow| follow.state().is_accepted()))
+        },
+        Visibility::Direct => Ok(false)
+    }
+}
+
+/// Finds `id`, reporting it as missing if `viewer` cannot see it.
+pub async fn visible_note(
+    follow_repo: &impl FollowRepository,
+    note_repo: &impl NoteRepository,
+    viewer: Option<&AccountId>,
+    id: &NoteId,
+    method: &'static str
+) -> Result<Note, ApplicationError> {
+    let note = match note_repo.find_by_id(id).await? {
+        Some(note) if is_visible(follow_repo, viewer, &note).await? => note,
+        _ => return Err(ApplicationError::NotFound {
+            method,
+            entity: "note",
+            id: id.as_ref().to_string()
+        })
+    };
+    Ok(note)
+}
+
+/// Whether `actor` may reshare `note` to `visibility`, by boosting or quoting it.
+///
+/// A reshare never reaches further than the note itself,
+/// and only the author can pass a followers-only note on to their own followers.
+pub fn check_reshare(actor: &AccountId, note: &Note, visibility: &Visibility) -> Result<(), ApplicationError> {
+    match note.visibility() {
+        Visibility::Direct => {
+            return Err(ApplicationError::Forbidden("direct notes cannot be reshared.".to_string()));
+        },
+        Visibility::FollowersOnly if actor != note.author() => {
+            return Err(ApplicationError::Forbidden("only the author can reshare a followers-only note.".to_string()));
+        },
+        _ => ()
+    }
+
+    if visibility.is_wider_than(note.visibility()) {
+        return Err(ApplicationError::InvalidField {
+            field: "visibility",
+            reason: format!("cannot be wider than `{}` of the reshared note.", note.visibility().as_str())
+        });
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,60 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use kernel::{
+    repository::{DeliveryRepository, FollowRepository},
+    entities::{Account, AccountId, AccountTypes, Delivery, DeliveryId}
+};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{service::{url_host, ActorUrls}, ApplicationError};
+
+/// Queues `activity` from `sender` for the inboxes of the actors in `recipients`.
+///
+/// Recipients are grouped into one delivery per host, so that each server gets the activity once.
+/// Local and malformed actor ids are skipped.
+pub async fn enqueue(
+    delivery_repo: &impl DeliveryRepository,
+    host: &str,
+    sender: &Account,
+    activity: &impl Serialize,
+    recipients: impl IntoIterator<Item = String>
+) -> Result<(), ApplicationError> {
+    let mut hosts = BTreeMap::<String, BTreeSet<String>>::new();
+    for recipient in recipients {
+        let Some(remote) = url_host(&recipient).filter(|remote| !remote.eq_ignore_ascii_case(host)) else {
+            continue;
+        };
+        hosts.entry(remote).or_default().insert(recipient);
+    }
+    if hosts.is_empty() {
+        return Ok(());
+    }
+
+    let activity = serde_json::to_string(activity)
+        .map_err(|e| ApplicationError::External(anyhow::Error::new(e)))?;
+    let key_id = ActorUrls::new(host, sender.name()).key_id;
+    let now = OffsetDateTime::now_utc();
+
+    for (remote, recipients) in hosts {
+        let delivery = Delivery::new(DeliveryId::default(), *sender.id(), &key_id, remote, recipients, &activity, 0, now, now);
+        delivery_repo.create(&delivery).await?;
+    }
+
+    Ok(())
+}
+
+/// Actor ids of the remote accounts following `account`.
+pub async fn remote_followers(
+    follow_repo: &impl FollowRepository,
+    account: &AccountId
+) -> Result<Vec<String>, ApplicationError> {
+    let followers = follow_repo.find_followers(&AccountTypes::Local(*account)).await?
+        .into_iter()
+        .filter_map(|follow| match follow.source() {
+            AccountTypes::Federate(actor) => Some(actor.clone()),
+            AccountTypes::Local(_) => None
+        })
+        .collect();
+    Ok(followers)
+}
\ No newline at end of file
@@ -0,0 +1,116 @@
+use kernel::entities::{AccountName, NoteId, Visibility};
+use uuid::Uuid;
+
+/// The urls under which a local account is published to other servers.
+#[derive(Debug, Clone)]
+pub struct ActorUrls {
+    pub id: String,
+    pub inbox: String,
+    pub outbox: String,
+    pub followers: String,
+    pub following: String,
+    pub shared_inbox: String,
+    pub key_id: String
+}
+
+impl ActorUrls {
+    pub fn new(host: &str, name: &AccountName) -> Self {
+        let id = format!("https://{}/users/{}", host, name.as_ref());
+        Self {
+            inbox: format!("{}/inbox", id),
+            outbox: format!("{}/outbox", id),
+            followers: format!("{}/followers", id),
+            following: format!("{}/following", id),
+            shared_inbox: format!("https://{}/inbox", host),
+            key_id: format!("{}#main-key", id),
+            id
+        }
+    }
+}
+
+/// The account name in `url` if it is the id of a local actor.
+pub fn local_actor_name<'a>(host: &str, url: &'a str) -> Option<&'a str> {
+    local_path(host, url, "users/")
+}
+
+/// The ActivityPub id of a local note.
+pub fn note_url(host: &str, id: &NoteId) -> String {
+    format!("https://{}/notes/{}", host, id.as_ref())
+}
+
+/// The note `url` refers to if it is the id of a local note.
+pub fn local_note_id(host: &str, url: &str) -> Option<NoteId> {
+    local_path(host, url, "notes/")
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .map(NoteId::new)
+}
+
+/// The rest of the path of `url` after `prefix`, if `url` points to a single resource on `host`.
+fn local_path<'a>(host: &str, url: &'a str, prefix: &str) -> Option<&'a str> {
+    let rest = url.strip_prefix("https://")?;
+    let (authority, path) = rest.split_once('/')?;
+    if !authority.eq_ignore_ascii_case(host) {
+        return None;
+    }
+    path.strip_prefix(prefix)
+        .filter(|name| !name.is_empty() && !name.contains(['/', '?', '#']))
+}
+
+/// The host part of `url`, lowercased, for comparing the origin of ids.
+pub fn url_host(url: &str) -> Option<String> {
+    let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"))?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host = authority.rsplit_once('@').map_or(authority, |(_, host)| host);
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+/// Addresses meaning "everyone" in `to` and `cc`.
+pub const PUBLIC_ADDRESSES: [&str; 3] = ["https://www.w3.org/ns/activitystreams#Public", "as:Public", "Public"];
+
+/// Derives the visibility of an object from its addressing, the way Mastodon does.
+///
+/// Public in `to` is public, public in `cc` is unlisted,
+/// a followers collection is followers-only, and anything else is direct.
+pub fn visibility_of(to: &[String], cc: &[String]) -> Visibility {
+    let is_public = |address: &String| PUBLIC_ADDRESSES.contains(&address.as_str());
+    if to.iter().any(is_public) {
+        Visibility::Public
+    } else if cc.iter().any(is_public) {
+        Visibility::Unlisted
+    } else if to.iter().chain(cc).any(|address| address.ends_with("/followers")) {
+        Visibility::FollowersOnly
+    } else {
+        Visibility::Direct
+    }
+}
+
+/// `to` and `cc` of an object with `visibility`, the inverse of [`visibility_of`].
+/// `followers` is the followers collection of the author and `mentions` are actor ids.
+pub fn addressing(visibility: &Visibility, followers: &str, mentions: &[String]) -> (Vec<String>, Vec<String>) {
+    let public = PUBLIC_ADDRESSES[0].to_string();
+    let followers = followers.to_string();
+    let mentions = mentions.to_vec();
+    match visibility {
+        Visibility::Public => (vec![public], [vec![followers], mentions].concat()),
+        Visibility::Unlisted => (vec![followers], [vec![public], mentions].concat()),
+        Visibility::FollowersOnly => (vec![followers], mentions),
+        Visibility::Direct => (mentions, Vec::new())
+    }
+}
+
+/// Profile text is stored as plain text, while ActivityStreams expects HTML.
+pub fn plain_to_html(text: &str) -> String {
+    let mut html = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            '\n' => html.push_str("<br>"),
+            _ => html.push(c)
+        }
+    }
+    html
+}
\ No newline at end of file
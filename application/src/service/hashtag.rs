@@ -0,0 +1,71 @@
+use kernel::entities::HashtagName;
+
+/// Hashtags in `content`, normalized, in order of first appearance and without duplicates.
+///
+/// A `#` directly after a word or in a url, like `C#` or `/page#section`, does not start a tag.
+pub fn parse_hashtags(content: &str) -> Vec<HashtagName> {
+    let mut found: Vec<HashtagName> = Vec::new();
+
+    let mut previous = None;
+    let mut chars = content.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let starts = c == '#'
+            && !previous.is_some_and(|p: char| HashtagName::is_tag_char(p) || matches!(p, '#' | '&' | '/'));
+        previous = Some(c);
+        if !starts {
+            continue;
+        }
+
+        let start = i + c.len_utf8();
+        let mut end = start;
+        while let Some((j, t)) = chars.next_if(|(_, t)| HashtagName::is_tag_char(*t)) {
+            end = j + t.len_utf8();
+            previous = Some(t);
+        }
+
+        let Ok(name) = HashtagName::try_from(&content[start..end]) else {
+            continue;
+        };
+        if !found.contains(&name) {
+            found.push(name);
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::entities::HashtagName;
+
+    use super::parse_hashtags;
+
+    fn names(content: &str) -> Vec<String> {
+        parse_hashtags(content).into_iter().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_hashtags() {
+        assert_eq!(names("#rust and #async_await"), vec!["rust", "async_await"]);
+
+        // Tags are case folded and NFKC normalized.
+        assert_eq!(names("#Rust #ＲＵＳＴ #RUST"), vec!["rust"]);
+        assert_eq!(names("#Straße"), vec!["strasse"]);
+
+        // Letters of any script make up a tag.
+        assert_eq!(names("#日本語 #café"), vec!["日本語", "café"]);
+
+        // A `#` inside a word, a url or an entity does not start a tag.
+        assert!(names("C# https://example.com/page#section &#123;").is_empty());
+
+        // Punctuation ending the sentence is not part of the tag.
+        assert_eq!(names("love #rust, (#tokio). #serde!"), vec!["rust", "tokio", "serde"]);
+
+        // Duplicates are dropped, keeping the first appearance.
+        assert_eq!(names("#b #a #B #a"), vec!["b", "a"]);
+
+        // Numbers and bare `#` are not tags.
+        assert!(names("#1 # ## #").is_empty());
+        assert!(names(&format!("#{}", "a".repeat(HashtagName::MAX_LENGTH + 1))).is_empty());
+    }
+}
\ No newline at end of file
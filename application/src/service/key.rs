@@ -0,0 +1,35 @@
+use kernel::{
+    repository::AccountKeyRepository,
+    entities::{AccountId, AccountKey}
+};
+
+use crate::ApplicationError;
+
+/// The signing key of a local account.
+/// Accounts created before keys existed get one on first use.
+pub async fn account_key(
+    key_repo: &impl AccountKeyRepository,
+    account: &AccountId
+) -> Result<AccountKey, ApplicationError> {
+    if let Some(key) = key_repo.find_by_account_id(account).await? {
+        return Ok(key);
+    }
+
+    key_repo.create(&generate_key(account).await?).await?;
+
+    // Another request may have stored its key first, and that one is kept.
+    key_repo.find_by_account_id(account).await?
+        .ok_or_else(|| ApplicationError::NotFound {
+            method: "account_key",
+            entity: "account_key",
+            id: format!("{:?}", account)
+        })
+}
+
+/// Generates a key for `account` on the blocking pool, as RSA key generation would stall the executor.
+pub async fn generate_key(account: &AccountId) -> Result<AccountKey, ApplicationError> {
+    let account = *account.as_ref();
+    tokio::task::spawn_blocking(move || AccountKey::generate(account)).await
+        .map_err(|e| ApplicationError::External(anyhow::Error::new(e)))?
+        .map_err(Into::into)
+}
\ No newline at end of file
@@ -0,0 +1,187 @@
+use kernel::{
+    repository::AccountRepository,
+    entities::{AccountName, AccountTypes},
+    service::ActorResolver
+};
+
+use crate::ApplicationError;
+
+/// `@name` or `@name@host` found in note content.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mention {
+    pub name: String,
+    pub host: Option<String>
+}
+
+fn is_name_byte(b: &u8) -> bool {
+    b.is_ascii_alphanumeric() || *b == b'_'
+}
+
+fn is_host_byte(b: &u8) -> bool {
+    b.is_ascii_alphanumeric() || *b == b'.' || *b == b'-'
+}
+
+/// Mentions in `content`, in order of first appearance and without duplicates.
+///
+/// An `@` directly after a word, like in an email address or a url path, does not start a mention.
+pub fn parse_mentions(content: &str) -> Vec<Mention> {
+    let bytes = content.as_bytes();
+    let mut found: Vec<Mention> = Vec::new();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts = bytes[i] == b'@'
+            && (i == 0 || !(is_host_byte(&bytes[i - 1]) || matches!(bytes[i - 1], b'_' | b'@' | b'/')));
+        if !starts {
+            i += 1;
+            continue;
+        }
+
+        let name_start = i + 1;
+        let name_end = name_start + bytes[name_start..].iter().take_while(|b| is_name_byte(b)).count();
+        if name_end == name_start {
+            i += 1;
+            continue;
+        }
+
+        let mut end = name_end;
+        let mut host = None;
+        if bytes.get(name_end) == Some(&b'@') {
+            let host_start = name_end + 1;
+            let len = bytes[host_start..].iter().take_while(|b| is_host_byte(b)).count();
+            // Trailing punctuation belongs to the sentence, not the host.
+            let raw = content[host_start..host_start + len].trim_end_matches(['.', '-']);
+            if !raw.is_empty() {
+                end = host_start + raw.len();
+                host = Some(raw.to_ascii_lowercase());
+            }
+        }
+
+        let name = &content[name_start..name_end];
+        let duplicated = found.iter()
+            .any(|mention| mention.name.eq_ignore_ascii_case(name) && mention.host == host);
+        if name.len() <= AccountName::MAX_LENGTH && !duplicated {
+            found.push(Mention { name: name.to_string(), host });
+        }
+
+        i = end;
+    }
+
+    found
+}
+
+/// Resolves `mentions` to accounts, keeping their order.
+///
+/// Handles without a host or with `host` refer to local accounts.
+/// Handles on other hosts are looked up with `resolver` and refer to the remote actor.
+/// Handles that resolve to nothing stay plain text in the content.
+pub async fn resolve_mentions(
+    account_repo: &impl AccountRepository,
+    resolver: &impl ActorResolver,
+    host: &str,
+    mentions: &[Mention]
+) -> Result<Vec<AccountTypes>, ApplicationError> {
+    let mut resolved = Vec::with_capacity(mentions.len());
+    for mention in mentions {
+        let account = match mention.host.as_deref() {
+            Some(remote) if !remote.eq_ignore_ascii_case(host) => {
+                // A server that is down must not keep the note from being posted.
+                match resolver.resolve_handle(&mention.name, remote).await {
+                    Ok(Some(actor)) => AccountTypes::Federate(actor),
+                    Ok(None) => continue,
+                    Err(e) => {
+                        tracing::debug!("failed to resolve `@{}@{}`. {:?}", mention.name, remote, e);
+                        continue;
+                    }
+                }
+            },
+            _ => {
+                let Some(account) = account_repo.find_by_name(&AccountName::new(mention.name.as_str())).await? else {
+                    continue;
+                };
+                AccountTypes::Local(*account.id())
+            }
+        };
+
+        // `@name` and `@name@host` are the same account.
+        if !resolved.contains(&account) {
+            resolved.push(account);
+        }
+    }
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{
+        repository::MockAccountRepository,
+        entities::{Account, AccountId, AccountName, AccountTypes},
+        service::MockActorResolver,
+        KernelError
+    };
+    use time::OffsetDateTime;
+
+    use super::{parse_mentions, resolve_mentions, Mention};
+
+    fn mention(name: &str, host: Option<&str>) -> Mention {
+        Mention { name: name.to_string(), host: host.map(str::to_string) }
+    }
+
+    #[test]
+    fn test_parse_mentions() {
+        assert_eq!(parse_mentions("hi @alice"), vec![mention("alice", None)]);
+        assert_eq!(parse_mentions("@bob@Remote.Example hi"), vec![mention("bob", Some("remote.example"))]);
+
+        // Email addresses and url paths are not mentions.
+        assert!(parse_mentions("mail alice@example.com").is_empty());
+        assert!(parse_mentions("https://example.com/@alice").is_empty());
+
+        // Punctuation ending the sentence is not part of the handle.
+        assert_eq!(
+            parse_mentions("thanks @alice, @bob@remote.example. (@carol)"),
+            vec![mention("alice", None), mention("bob", Some("remote.example")), mention("carol", None)]
+        );
+
+        // The first of the same handle is kept, regardless of case. Another host is another account.
+        assert_eq!(
+            parse_mentions("@alice @Alice @alice@remote.example @ALICE@REMOTE.EXAMPLE"),
+            vec![mention("alice", None), mention("alice", Some("remote.example"))]
+        );
+
+        assert!(parse_mentions("@ alone @").is_empty());
+        assert!(parse_mentions(&format!("@{}", "a".repeat(AccountName::MAX_LENGTH + 1))).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_mentions() {
+        let alice = Account::new(AccountId::default(), "alice", false, false, OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
+        let alice_id = *alice.id();
+
+        let mut account_repo = MockAccountRepository::new();
+        account_repo.expect_find_by_name()
+            .returning(move |name| Ok((name.as_ref() == "alice").then(|| alice.clone())));
+
+        let mut resolver = MockActorResolver::new();
+        resolver.expect_resolve_handle()
+            .returning(|name, host| match (name, host) {
+                ("bob", "remote.example") => Ok(Some("https://remote.example/users/bob".to_string())),
+                (_, "down.example") => Err(KernelError::Driver(anyhow::anyhow!("connection refused"))),
+                _ => Ok(None)
+            });
+
+        let mentions = [
+            mention("alice", None),
+            mention("alice", Some("local.example")),
+            mention("nobody", None),
+            mention("bob", Some("remote.example")),
+            mention("nobody", Some("remote.example")),
+            mention("carol", Some("down.example"))
+        ];
+        let resolved = resolve_mentions(&account_repo, &resolver, "local.example", &mentions).await.unwrap();
+
+        assert_eq!(resolved, vec![
+            AccountTypes::Local(alice_id),
+            AccountTypes::Federate("https://remote.example/users/bob".to_string())
+        ]);
+    }
+}
\ No newline at end of file
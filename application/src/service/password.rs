@@ -0,0 +1,15 @@
+use kernel::{entities::Password, KernelError};
+
+// Argon2 is made to take a while, which would stall the executor if run on it.
+
+/// [`Password::hash`] on the blocking pool.
+pub async fn hash_password(raw: String) -> Result<Password, KernelError> {
+    tokio::task::spawn_blocking(move || Password::hash(raw)).await
+        .map_err(|e| KernelError::External(anyhow::Error::new(e)))?
+}
+
+/// [`Password::verify`] on the blocking pool.
+pub async fn verify_password(hashed: Password, raw: String) -> Result<bool, KernelError> {
+    tokio::task::spawn_blocking(move || hashed.verify(raw)).await
+        .map_err(|e| KernelError::External(anyhow::Error::new(e)))?
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use kernel::{
+    repository::{FollowRepository, TimelineCacheRepository},
+    entities::{AccountId, AccountTypes, TimelineCursor, Visibility}
+};
+
+use crate::ApplicationError;
+
+/// Pushes a new entry of `author` into the cached home timelines that list it,
+/// which are those of the author and of their local followers.
+///
+/// Direct entries only reach the followers they mention, the same as in `TimelineRepository::find_home`.
+pub async fn fan_out(
+    follow_repo: &impl FollowRepository,
+    cache: &impl TimelineCacheRepository,
+    author: &AccountId,
+    entry: &TimelineCursor,
+    visibility: &Visibility,
+    mentions: &[AccountTypes]
+) -> Result<(), ApplicationError> {
+    let mut accounts = follow_repo.find_followers(&AccountTypes::Local(*author)).await?
+        .into_iter()
+        .filter(|follow| *visibility != Visibility::Direct || mentions.contains(follow.source()))
+        .filter_map(|follow| match follow.source() {
+            AccountTypes::Local(id) => Some(*id),
+            AccountTypes::Federate(_) => None
+        })
+        .collect::<Vec<AccountId>>();
+    accounts.push(*author);
+
+    cache.push(&accounts, entry).await?;
+
+    Ok(())
+}
+
+/// Drops the cached home timeline of `follower`, as whom it follows has changed.
+pub async fn invalidate_home(
+    cache: &impl TimelineCacheRepository,
+    follower: &AccountTypes
+) -> Result<(), ApplicationError> {
+    if let AccountTypes::Local(id) = follower {
+        cache.delete(id).await?;
+    }
+    Ok(())
+}
\ No newline at end of file
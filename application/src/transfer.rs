@@ -1,7 +1,33 @@
 mod account;
 mod profile;
+mod session;
+mod oauth;
+mod verification;
+mod follow;
+mod note;
+mod reaction;
+mod boost;
+mod timeline;
+mod activitypub;
+mod discovery;
+mod signature;
+mod inbox;
+mod notification;
 
 pub use self::{
     account::*,
     profile::*,
+    session::*,
+    oauth::*,
+    verification::*,
+    follow::*,
+    note::*,
+    reaction::*,
+    boost::*,
+    timeline::*,
+    activitypub::*,
+    discovery::*,
+    signature::*,
+    inbox::*,
+    notification::*,
 };
\ No newline at end of file
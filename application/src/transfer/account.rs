@@ -1,12 +1,48 @@
-use kernel::entities::{Account, DestructAccount, DestructUpdateTime};
+use std::collections::HashMap;
+
+use kernel::entities::{Account, AccountId, AccountTypes, DestructAccount, DestructUpdateTime};
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
-#[derive(Debug)]
+/// Local account by name, or remote actor by url.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AccountRefDto {
+    Local {
+        id: i64,
+        name: String
+    },
+    Remote {
+        url: String
+    }
+}
+
+impl AccountRefDto {
+    /// Returns `None` if `account` is local and missing from `accounts`.
+    pub(crate) fn new(account: &AccountTypes, accounts: &HashMap<AccountId, Account>) -> Option<Self> {
+        let account = match account {
+            AccountTypes::Local(id) => {
+                let found = accounts.get(id)?;
+                Self::Local {
+                    id: *found.id().as_ref(),
+                    name: found.name().as_ref().to_string()
+                }
+            },
+            AccountTypes::Federate(url) => Self::Remote { url: url.clone() }
+        };
+        Some(account)
+    }
+}
+
+#[derive(Debug, Serialize)]
 pub struct AccountDto {
     pub id: i64,
     pub name: String,
     pub bot: bool,
+    pub locked: bool,
+    #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
     pub updated_at: OffsetDateTime
 }
 
@@ -16,6 +52,7 @@ impl From<Account> for AccountDto {
             id, 
             name, 
             bot ,
+            locked,
             date,
         } = internal.into_destruct();
         let DestructUpdateTime {
@@ -26,25 +63,58 @@ impl From<Account> for AccountDto {
             id: id.into(), 
             name: name.into(), 
             bot: bot.into(),
+            locked: locked.into(),
             created_at: created_at.into(),
             updated_at: updated_at.into()
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
 pub struct CreateAccountDto {
     pub name: String,
-    pub bot: bool
+    pub bot: bool,
+    pub address: String,
+    pub pass: String
 }
 
 impl CreateAccountDto {
     pub fn new(
         name: impl Into<String>, 
-        bot: impl Into<bool>
+        bot: impl Into<bool>,
+        address: impl Into<String>,
+        pass: impl Into<String>
     ) -> Self {
         Self { 
             name: name.into(), 
-            bot: bot.into() 
+            bot: bot.into(),
+            address: address.into(),
+            pass: pass.into()
+        }
+    }
+}
+
+/// Omitted fields are left as they are.
+#[derive(Debug, Deserialize)]
+pub struct UpdateAccountDto {
+    pub bot: Option<bool>,
+    pub locked: Option<bool>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LoginDto {
+    pub address: String,
+    pub pass: String
+}
+
+impl LoginDto {
+    pub fn new(
+        address: impl Into<String>,
+        pass: impl Into<String>
+    ) -> Self {
+        Self {
+            address: address.into(),
+            pass: pass.into()
         }
     }
 }
\ No newline at end of file
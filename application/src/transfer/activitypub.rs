@@ -0,0 +1,206 @@
+use kernel::entities::{Account, AccountKey, Note, NoteTypes, Profile};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::service::{note_url, plain_to_html, ActorUrls};
+
+pub const ACTIVITY_STREAMS: &str = "https://www.w3.org/ns/activitystreams";
+pub const SECURITY_V1: &str = "https://w3id.org/security/v1";
+
+/// ActivityStreams actor of a local account, as served at `/users/{name}`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorDto {
+    #[serde(rename = "@context")]
+    pub context: [&'static str; 2],
+    pub id: String,
+    /// `Service` for bot accounts, otherwise `Person`.
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub preferred_username: String,
+    pub name: String,
+    pub summary: String,
+    pub url: String,
+    pub inbox: String,
+    pub outbox: String,
+    pub followers: String,
+    pub following: String,
+    pub endpoints: EndpointsDto,
+    pub manually_approves_followers: bool,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published: OffsetDateTime,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<ImageDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ImageDto>,
+    pub public_key: PublicKeyDto
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointsDto {
+    pub shared_inbox: String
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImageDto {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub url: String
+}
+
+impl ImageDto {
+    fn new(url: impl Into<String>) -> Self {
+        Self { kind: "Image", url: url.into() }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyDto {
+    pub id: String,
+    pub owner: String,
+    pub public_key_pem: String
+}
+
+impl ActorDto {
+    /// Accounts without a profile fall back to their name and an empty summary.
+    pub(crate) fn new(account: &Account, profile: Option<&Profile>, key: &AccountKey, urls: ActorUrls) -> Self {
+        let name = account.name().as_ref().to_string();
+        let ActorUrls { id, inbox, outbox, followers, following, shared_inbox, key_id } = urls;
+        Self {
+            context: [ACTIVITY_STREAMS, SECURITY_V1],
+            kind: if *account.bot().as_ref() { "Service" } else { "Person" },
+            name: profile.map(|profile| profile.name().as_ref())
+                .filter(|display_name| !display_name.is_empty())
+                .unwrap_or(&name)
+                .to_string(),
+            summary: profile.map(|profile| plain_to_html(profile.summary().as_ref()))
+                .unwrap_or_default(),
+            icon: profile.and_then(Profile::icon)
+                .map(|icon| ImageDto::new(icon.as_ref())),
+            image: profile.and_then(Profile::banner)
+                .map(|banner| ImageDto::new(banner.as_ref())),
+            preferred_username: name,
+            public_key: PublicKeyDto {
+                id: key_id,
+                owner: id.clone(),
+                public_key_pem: key.public_key().as_ref().to_string()
+            },
+            url: id.clone(),
+            id,
+            inbox,
+            outbox,
+            followers,
+            following,
+            endpoints: EndpointsDto { shared_inbox },
+            manually_approves_followers: *account.locked().as_ref(),
+            published: *account.date().created_at().as_ref()
+        }
+    }
+}
+
+/// Activity posted to the inboxes of other servers.
+#[derive(Debug, Serialize)]
+pub struct OutgoingActivityDto<T> {
+    #[serde(rename = "@context")]
+    pub context: &'static str,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub actor: String,
+    pub object: T,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub to: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<String>
+}
+
+impl<T> OutgoingActivityDto<T> {
+    pub(crate) fn new(id: impl Into<String>, kind: &'static str, actor: impl Into<String>, object: T) -> Self {
+        Self {
+            context: ACTIVITY_STREAMS,
+            id: id.into(),
+            kind,
+            actor: actor.into(),
+            object,
+            to: Vec::new(),
+            cc: Vec::new()
+        }
+    }
+}
+
+/// The `Follow` an `Accept` or `Reject` answers.
+/// `id` is unknown for follows that were not received through the inbox.
+#[derive(Debug, Serialize)]
+pub struct FollowObjectDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub actor: String,
+    pub object: String
+}
+
+impl FollowObjectDto {
+    pub(crate) fn new(id: Option<String>, actor: impl Into<String>, object: impl Into<String>) -> Self {
+        Self { id, kind: "Follow", actor: actor.into(), object: object.into() }
+    }
+}
+
+/// ActivityStreams `Note` of a local note.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteObjectDto {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub attributed_to: String,
+    pub content: String,
+    /// The content warning, as Mastodon reads it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    pub sensitive: bool,
+    pub in_reply_to: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub published: OffsetDateTime,
+    pub url: String,
+    pub to: Vec<String>,
+    pub cc: Vec<String>
+}
+
+impl NoteObjectDto {
+    /// `attributed_to` is the actor id of the author.
+    pub(crate) fn new(note: &Note, host: &str, attributed_to: impl Into<String>, to: Vec<String>, cc: Vec<String>) -> Self {
+        let id = note_url(host, note.id());
+        Self {
+            kind: "Note",
+            attributed_to: attributed_to.into(),
+            content: plain_to_html(note.content().as_ref()),
+            summary: note.cw().map(|cw| cw.as_ref().to_string()),
+            sensitive: note.cw().is_some(),
+            in_reply_to: note.in_reply_to().map(|reply| match reply {
+                NoteTypes::Local(id) => note_url(host, id),
+                NoteTypes::Federate(url) => url.clone()
+            }),
+            published: *note.created_at().as_ref(),
+            url: id.clone(),
+            to,
+            cc,
+            id
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TombstoneDto {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: &'static str
+}
+
+impl TombstoneDto {
+    pub(crate) fn new(id: impl Into<String>) -> Self {
+        Self { id: id.into(), kind: "Tombstone" }
+    }
+}
\ No newline at end of file
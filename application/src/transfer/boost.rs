@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use kernel::entities::{Account, AccountId, Boost};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{AccountRefDto, NoteRefDto};
+
+#[derive(Debug, Serialize)]
+pub struct BoostDto {
+    pub id: Uuid,
+    /// Account that boosted the note.
+    pub account: AccountRefDto,
+    pub note: NoteRefDto,
+    pub visibility: &'static str,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl BoostDto {
+    /// Returns `None` if the booster is local and missing from `accounts`.
+    pub(crate) fn new(boost: &Boost, accounts: &HashMap<AccountId, Account>) -> Option<Self> {
+        Some(Self {
+            id: *boost.id().as_ref(),
+            account: AccountRefDto::new(boost.account(), accounts)?,
+            note: boost.note().clone().into(),
+            visibility: boost.visibility().as_str(),
+            created_at: *boost.created_at().as_ref()
+        })
+    }
+}
+
+/// `visibility` defaults to that of the boosted note and cannot be wider than it.
+#[derive(Debug, Default, Deserialize)]
+pub struct BoostNoteDto {
+    pub visibility: Option<String>
+}
\ No newline at end of file
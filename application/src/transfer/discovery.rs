@@ -0,0 +1,74 @@
+use serde::Serialize;
+
+pub const NODEINFO_SCHEMA: &str = "http://nodeinfo.diaspora.software/ns/schema/2.1";
+
+/// JSON Resource Descriptor returned by WebFinger (RFC 7033).
+#[derive(Debug, Serialize)]
+pub struct WebFingerDto {
+    pub subject: String,
+    pub aliases: Vec<String>,
+    pub links: Vec<LinkDto>
+}
+
+#[derive(Debug, Serialize)]
+pub struct LinkDto {
+    pub rel: &'static str,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<&'static str>,
+    pub href: String
+}
+
+/// `lrdd` is the WebFinger url template that host-meta points clients to.
+#[derive(Debug, Serialize)]
+pub struct HostMetaDto {
+    pub lrdd: String
+}
+
+/// Served at `/.well-known/nodeinfo`, pointing to the NodeInfo documents.
+#[derive(Debug, Serialize)]
+pub struct NodeInfoLinksDto {
+    pub links: Vec<LinkDto>
+}
+
+/// NodeInfo 2.1 document.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeInfoDto {
+    pub version: &'static str,
+    pub software: SoftwareDto,
+    pub protocols: Vec<&'static str>,
+    pub services: ServicesDto,
+    pub open_registrations: bool,
+    pub usage: UsageDto,
+    pub metadata: MetadataDto
+}
+
+#[derive(Debug, Serialize)]
+pub struct SoftwareDto {
+    pub name: &'static str,
+    pub version: &'static str
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServicesDto {
+    pub inbound: Vec<&'static str>,
+    pub outbound: Vec<&'static str>
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageDto {
+    pub users: UsersDto,
+    pub local_posts: i64
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsersDto {
+    pub total: i64,
+    pub active_month: i64,
+    pub active_halfyear: i64
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetadataDto {}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use kernel::entities::{Account, AccountId, AccountTypes, Follow, FollowState};
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::AccountRefDto;
+
+#[derive(Debug, Serialize)]
+pub struct FollowDto {
+    pub id: Uuid,
+    /// Other end of the follow as seen from the listed account.
+    pub account: AccountRefDto,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl FollowDto {
+    /// Describes `account`, one end of `follow`.
+    /// Returns `None` if `account` is local and missing from `accounts`.
+    pub(crate) fn new(
+        follow: &Follow,
+        account: &AccountTypes,
+        accounts: &HashMap<AccountId, Account>
+    ) -> Option<Self> {
+        let account = AccountRefDto::new(account, accounts)?;
+        Some(Self {
+            id: *follow.id().as_ref(),
+            account,
+            created_at: *follow.created_at().as_ref()
+        })
+    }
+}
+
+/// Relationship from the requesting account to another account.
+#[derive(Debug, Serialize)]
+pub struct RelationshipDto {
+    pub following: bool,
+    /// The follow waits for the approval of a locked account.
+    pub requested: bool
+}
+
+impl From<Option<&Follow>> for RelationshipDto {
+    fn from(follow: Option<&Follow>) -> Self {
+        let state = follow.map(|follow| *follow.state());
+        Self {
+            following: state == Some(FollowState::Accepted),
+            requested: state == Some(FollowState::Pending)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+/// An ActivityStreams object posted to an inbox: an activity, or an object nested in one.
+/// Only the properties the inbox acts on are read. The rest are ignored.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityDto {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    #[serde(default)]
+    pub actor: Option<ObjectDto>,
+    #[serde(default)]
+    pub object: Option<ObjectDto>,
+    #[serde(default)]
+    pub attributed_to: Option<ObjectDto>,
+    /// HTML of a note, or the emoji of a `Like` sent as a reaction.
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Content warning of a note.
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub in_reply_to: Option<ObjectDto>,
+    /// RFC 3339 timestamp.
+    #[serde(default)]
+    pub published: Option<String>,
+    #[serde(default)]
+    pub to: AddressesDto,
+    #[serde(default)]
+    pub cc: AddressesDto,
+    /// Reaction of a `Like` sent by Misskey.
+    #[serde(rename = "_misskey_reaction", default)]
+    pub misskey_reaction: Option<String>
+}
+
+/// A reference to an object, either by its id or embedded.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ObjectDto {
+    Id(String),
+    Object(Box<ActivityDto>)
+}
+
+impl ObjectDto {
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::Id(id) => Some(id),
+            Self::Object(object) => object.id.as_deref()
+        }
+    }
+}
+
+/// `to` and `cc` hold either a single address or an array of them.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(untagged)]
+pub enum AddressesDto {
+    #[default]
+    None,
+    One(String),
+    Many(Vec<String>)
+}
+
+impl AddressesDto {
+    pub fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::None => Vec::new(),
+            Self::One(address) => vec![address],
+            Self::Many(addresses) => addresses
+        }
+    }
+}
\ No newline at end of file
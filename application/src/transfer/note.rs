@@ -0,0 +1,182 @@
+use kernel::entities::{AccountTypes, DestructNote, DestructRemoteNote, Note, NoteTypes, RemoteNote};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NoteRefDto {
+    Local {
+        id: Uuid
+    },
+    Remote {
+        url: String
+    }
+}
+
+impl From<NoteTypes> for NoteRefDto {
+    fn from(note: NoteTypes) -> Self {
+        match note {
+            NoteTypes::Local(id) => Self::Local { id: id.into() },
+            NoteTypes::Federate(url) => Self::Remote { url }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum MentionDto {
+    Local {
+        id: i64
+    },
+    Remote {
+        url: String
+    }
+}
+
+impl From<AccountTypes> for MentionDto {
+    fn from(account: AccountTypes) -> Self {
+        match account {
+            AccountTypes::Local(id) => Self::Local { id: id.into() },
+            AccountTypes::Federate(url) => Self::Remote { url }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct NoteDto {
+    pub id: Uuid,
+    pub author: i64,
+    pub content: String,
+    pub cw: Option<String>,
+    pub visibility: &'static str,
+    pub in_reply_to: Option<NoteRefDto>,
+    pub quote_of: Option<NoteRefDto>,
+    pub mentions: Vec<MentionDto>,
+    pub media: Vec<Uuid>,
+    pub hashtags: Vec<Uuid>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl From<Note> for NoteDto {
+    fn from(internal: Note) -> Self {
+        let DestructNote {
+            id,
+            author,
+            content,
+            cw,
+            visibility,
+            in_reply_to,
+            quote_of,
+            mentions,
+            media,
+            hashtags,
+            created_at
+        } = internal.into_destruct();
+        Self {
+            id: id.into(),
+            author: author.into(),
+            content: content.into(),
+            cw: cw.map(Into::into),
+            visibility: visibility.as_str(),
+            in_reply_to: in_reply_to.map(Into::into),
+            quote_of: quote_of.map(Into::into),
+            mentions: mentions.into_iter().map(Into::into).collect(),
+            media: media.into_iter().map(Into::into).collect(),
+            hashtags: hashtags.into_iter().map(Into::into).collect(),
+            created_at: created_at.into()
+        }
+    }
+}
+
+/// Note received from another server. `id` and `author` are ActivityPub ids and `content` is HTML.
+#[derive(Debug, Serialize)]
+pub struct RemoteNoteDto {
+    pub id: String,
+    pub author: String,
+    pub content: String,
+    pub cw: Option<String>,
+    pub visibility: &'static str,
+    pub in_reply_to: Option<NoteRefDto>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl From<RemoteNote> for RemoteNoteDto {
+    fn from(internal: RemoteNote) -> Self {
+        let DestructRemoteNote {
+            id,
+            author,
+            content,
+            cw,
+            visibility,
+            in_reply_to,
+            created_at
+        } = internal.into_destruct();
+        Self {
+            id: id.into(),
+            author,
+            content,
+            cw,
+            visibility: visibility.as_str(),
+            in_reply_to: in_reply_to.map(Into::into),
+            created_at: created_at.into()
+        }
+    }
+}
+
+/// A local or a remote note in a listing. Local notes keep the shape of [`NoteDto`].
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum AnyNoteDto {
+    Local(NoteDto),
+    Remote(RemoteNoteDto)
+}
+
+impl From<Note> for AnyNoteDto {
+    fn from(note: Note) -> Self {
+        Self::Local(note.into())
+    }
+}
+
+impl From<RemoteNote> for AnyNoteDto {
+    fn from(note: RemoteNote) -> Self {
+        Self::Remote(note.into())
+    }
+}
+
+/// `visibility` is one of `public` (default), `unlisted`, `followers_only` and `direct`.
+#[derive(Debug, Deserialize)]
+pub struct CreateNoteDto {
+    pub content: String,
+    pub cw: Option<String>,
+    pub visibility: Option<String>,
+    pub in_reply_to: Option<Uuid>,
+    pub quote_of: Option<Uuid>
+}
+
+impl CreateNoteDto {
+    pub fn new(
+        content: impl Into<String>,
+        cw: Option<String>,
+        visibility: Option<String>,
+        in_reply_to: Option<Uuid>,
+        quote_of: Option<Uuid>
+    ) -> Self {
+        Self {
+            content: content.into(),
+            cw,
+            visibility,
+            in_reply_to,
+            quote_of
+        }
+    }
+}
+
+/// Visible notes around a note, in thread order.
+#[derive(Debug, Serialize)]
+pub struct NoteContextDto {
+    pub ancestors: Vec<AnyNoteDto>,
+    pub descendants: Vec<AnyNoteDto>
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use kernel::entities::TimelineEntry;
+use serde::Serialize;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::AnyNoteDto;
+
+/// Something that happened to an account. `kind` is `mention` for notes that mention it.
+#[derive(Debug, Serialize)]
+pub struct NotificationDto {
+    /// Cursor for `max_id`.
+    pub id: Uuid,
+    pub kind: &'static str,
+    pub note: AnyNoteDto,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl NotificationDto {
+    /// Returns `None` for boosts, which mention nobody of their own.
+    pub(crate) fn mention(entry: TimelineEntry) -> Option<Self> {
+        let id = *entry.id();
+        let created_at = *entry.created_at().as_ref();
+        let note = match entry {
+            TimelineEntry::Note(note) => note.into(),
+            TimelineEntry::Remote(_, note) => note.into(),
+            TimelineEntry::Boost(..) => return None
+        };
+        Some(Self {
+            id,
+            kind: "mention",
+            note,
+            created_at
+        })
+    }
+}
\ No newline at end of file
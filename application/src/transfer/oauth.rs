@@ -0,0 +1,115 @@
+use kernel::entities::{Application, DestructApplication};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+pub struct ApplicationDto {
+    pub id: Uuid,
+    pub name: String,
+    pub website: Option<String>,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uris: Vec<String>,
+    pub scopes: Vec<String>
+}
+
+impl From<Application> for ApplicationDto {
+    fn from(internal: Application) -> Self {
+        let DestructApplication {
+            id,
+            name,
+            website,
+            client_id,
+            client_secret,
+            redirect_uris,
+            scopes,
+            ..
+        } = internal.into_destruct();
+        Self {
+            id: id.into(),
+            name: name.into(),
+            website: website.map(Into::into),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            redirect_uris: redirect_uris.into_iter().map(Into::into).collect(),
+            scopes: scopes.into()
+        }
+    }
+}
+
+/// Mastodon compatible app registration. `redirect_uris` and `scopes` are space separated.
+#[derive(Debug, Deserialize)]
+pub struct RegisterApplicationDto {
+    pub client_name: String,
+    pub redirect_uris: String,
+    pub scopes: Option<String>,
+    pub website: Option<String>
+}
+
+impl RegisterApplicationDto {
+    pub fn new(
+        client_name: impl Into<String>,
+        redirect_uris: impl Into<String>,
+        scopes: Option<String>,
+        website: Option<String>
+    ) -> Self {
+        Self {
+            client_name: client_name.into(),
+            redirect_uris: redirect_uris.into(),
+            scopes,
+            website
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuthorizeDto {
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>
+}
+
+/// What the user is asked to consent to.
+#[derive(Debug, Serialize)]
+pub struct AuthorizationRequestDto {
+    pub client_name: String,
+    pub website: Option<String>,
+    pub redirect_uri: String,
+    pub scopes: Vec<String>
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthorizationCodeDto {
+    pub code: String,
+    pub redirect_uri: String,
+    pub state: Option<String>
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenRequestDto {
+    pub grant_type: String,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub code_verifier: Option<String>
+}
+
+#[derive(Debug, Serialize)]
+pub struct TokenDto {
+    pub access_token: String,
+    pub token_type: &'static str,
+    pub scope: String,
+    pub created_at: i64
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeTokenDto {
+    pub token: String,
+    pub client_id: String,
+    pub client_secret: String
+}
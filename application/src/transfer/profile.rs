@@ -1,17 +1,20 @@
 use kernel::entities::{Profile, DestructProfile, DestructUpdateTime};
+use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ProfileDto {
     pub id: Uuid,
     pub account: i64,
+    #[serde(with = "time::serde::rfc3339")]
     pub create_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
     pub update_at: OffsetDateTime,
     pub display_name: String,
     pub summary: String,
-    pub icon_url: String,
-    pub banner_url: String
+    pub icon_url: Option<String>,
+    pub banner_url: Option<String>
 }
 
 impl From<Profile> for ProfileDto {
@@ -36,57 +39,56 @@ impl From<Profile> for ProfileDto {
             update_at: updated_at.into(), 
             display_name: name.into(), 
             summary: summary.into(), 
-            icon_url: icon.into(), 
-            banner_url: banner.into()
+            icon_url: icon.map(Into::into), 
+            banner_url: banner.map(Into::into)
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
 pub struct CreateProfileDto {
     pub display_name: String,
     pub summary: String,
-    pub icon: String,
-    pub banner: String,
+    pub icon: Option<String>,
+    pub banner: Option<String>,
 }
 
 impl CreateProfileDto {
     pub fn new(
         display_name: impl Into<String>,
         summary: impl Into<String>,
-        icon: impl Into<String>,
-        banner: impl Into<String>
+        icon: Option<String>,
+        banner: Option<String>
     ) -> Self {
         Self { 
             display_name: display_name.into(), 
             summary: summary.into(), 
-            icon: icon.into(), 
-            banner: banner.into() 
+            icon, 
+            banner 
         }
     }
 }
 
+#[derive(Debug, Deserialize)]
 pub struct UpdateProfileDto {
-    pub id: Uuid,
     pub display_name: String,
     pub summary: String,
-    pub icon: String,
-    pub banner: String,
+    pub icon: Option<String>,
+    pub banner: Option<String>,
 }
 
 impl UpdateProfileDto {
     pub fn new(
-        id: impl Into<Uuid>,
         display_name: impl Into<String>,
         summary: impl Into<String>,
-        icon: impl Into<String>,
-        banner: impl Into<String>,
+        icon: Option<String>,
+        banner: Option<String>,
     ) -> Self {
         Self { 
-            id: id.into(),
             display_name: display_name.into(), 
             summary: summary.into(), 
-            icon: icon.into(), 
-            banner: banner.into() 
+            icon, 
+            banner 
         }
     }
 }
\ No newline at end of file
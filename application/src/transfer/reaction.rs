@@ -0,0 +1,60 @@
+use kernel::entities::{DestructReactionAsset, ReactionAsset};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// `reaction` is a Unicode emoji or the `:alias:` of a registered asset.
+#[derive(Debug, Deserialize)]
+pub struct CreateReactionDto {
+    pub reaction: String
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReactionAssetDto {
+    pub id: Uuid,
+    pub alias: String,
+    pub url: String,
+    pub license_url: Option<String>,
+    pub license_spdx: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl From<ReactionAsset> for ReactionAssetDto {
+    fn from(internal: ReactionAsset) -> Self {
+        let DestructReactionAsset {
+            id,
+            alias,
+            asset,
+            license,
+            created_at
+        } = internal.into_destruct();
+        Self {
+            id: id.into(),
+            alias: alias.into(),
+            url: asset.into(),
+            license_url: license.url().map(|url| url.as_ref().to_string()),
+            license_spdx: license.spdx().map(ToString::to_string),
+            created_at: created_at.into()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateReactionAssetDto {
+    pub alias: String,
+    pub url: String,
+    pub license_url: Option<String>,
+    pub license_spdx: Option<String>
+}
+
+/// Reactions with the same content on a note.
+#[derive(Debug, Serialize)]
+pub struct ReactionCountDto {
+    /// The emoji itself, or `:alias:` for a custom emoji.
+    pub reaction: String,
+    pub asset: Option<ReactionAssetDto>,
+    pub count: i64,
+    /// Whether the requesting account is one of them.
+    pub me: bool
+}
\ No newline at end of file
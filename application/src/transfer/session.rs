@@ -0,0 +1,40 @@
+use kernel::entities::{Session, DestructSession};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+#[derive(Debug, Serialize)]
+pub struct SessionDto {
+    pub token: String,
+    pub account: i64,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub expires_at: OffsetDateTime
+}
+
+impl From<Session> for SessionDto {
+    fn from(internal: Session) -> Self {
+        let DestructSession {
+            token,
+            account,
+            created_at,
+            expires_at
+        } = internal.into_destruct();
+        Self {
+            token: token.into(),
+            account: account.into(),
+            created_at: created_at.into(),
+            expires_at: expires_at.into()
+        }
+    }
+}
+
+/// Who is making a request and what they may do.
+/// `session` is `false` when authenticated with an OAuth access token.
+#[derive(Debug)]
+pub struct CredentialDto {
+    pub account: i64,
+    pub token: String,
+    pub scopes: Vec<String>,
+    pub session: bool
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+/// An incoming request, reduced to what signature verification looks at.
+#[derive(Debug)]
+pub struct SignedRequestDto {
+    pub method: String,
+    /// Path and query, as in `(request-target)`.
+    pub path: String,
+    /// Keyed by lowercase name. Repeated headers are joined with `, `.
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>
+}
+
+/// The key a request was verified with.
+#[derive(Debug, Clone)]
+pub struct SignerDto {
+    pub key_id: String,
+    /// Actor url of the key owner.
+    pub owner: String
+}
\ No newline at end of file
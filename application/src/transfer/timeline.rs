@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use kernel::entities::{Account, AccountId, Pagination, TimelineEntry};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{AnyNoteDto, BoostDto};
+
+/// Query of a listing ordered newest first, with ids of listed entries as cursors.
+///
+/// `max_id` pages back to older entries. `since_id` lists the newest entries after it,
+/// while `min_id` lists those right after it, so that a client can catch up without gaps.
+#[derive(Debug, Default, Deserialize)]
+pub struct PaginationDto {
+    pub max_id: Option<Uuid>,
+    pub since_id: Option<Uuid>,
+    pub min_id: Option<Uuid>,
+    pub limit: Option<i64>
+}
+
+impl From<PaginationDto> for Pagination {
+    fn from(page: PaginationDto) -> Self {
+        let PaginationDto { max_id, since_id, min_id, limit } = page;
+        Pagination::new(max_id, since_id, min_id, limit)
+    }
+}
+
+/// Entry of a note listing. `boost` attributes a reshared note to whoever boosted it.
+#[derive(Debug, Serialize)]
+pub struct TimelineEntryDto {
+    /// Cursor for `max_id`. The id of the boost for boosts.
+    pub id: Uuid,
+    pub note: AnyNoteDto,
+    pub boost: Option<BoostDto>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime
+}
+
+impl TimelineEntryDto {
+    /// Returns `None` if the booster is local and missing from `accounts`.
+    pub(crate) fn new(entry: TimelineEntry, accounts: &HashMap<AccountId, Account>) -> Option<Self> {
+        let id = *entry.id();
+        let created_at = *entry.created_at().as_ref();
+        let (note, boost) = match entry {
+            TimelineEntry::Note(note) => (note.into(), None),
+            TimelineEntry::Boost(boost, note) => (note.into(), Some(BoostDto::new(&boost, accounts)?)),
+            TimelineEntry::Remote(_, note) => (note.into(), None)
+        };
+        Some(Self {
+            id,
+            note,
+            boost,
+            created_at
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use kernel::entities::{Account, AccountId, Boost, BoostId, Note, NoteId, Pagination, TimelineEntry, Visibility};
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use super::{PaginationDto, TimelineEntryDto};
+
+    #[test]
+    fn test_pagination() {
+        let (max_id, since_id, min_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let page = Pagination::from(PaginationDto { max_id: Some(max_id), since_id: Some(since_id), min_id: None, limit: None });
+        assert_eq!(page.max_id(), Some(&max_id));
+        assert_eq!(page.newer_than(), Some(&since_id));
+        assert!(!page.is_ascending());
+        assert_eq!(page.limit(), Pagination::DEFAULT_LIMIT);
+
+        // `min_id` wins over `since_id` and pages oldest first.
+        let page = Pagination::from(PaginationDto { max_id: None, since_id: Some(since_id), min_id: Some(min_id), limit: Some(0) });
+        assert_eq!(page.newer_than(), Some(&min_id));
+        assert!(page.is_ascending());
+        assert_eq!(page.limit(), 1);
+
+        let page = Pagination::from(PaginationDto { limit: Some(1000), ..Default::default() });
+        assert_eq!(page.limit(), Pagination::MAX_LIMIT);
+    }
+
+    #[test]
+    fn test_entry_cursor() {
+        let author = AccountId::default();
+        let note = Note::new(
+            NoteId::default(), author, "note", None::<String>, Visibility::Public, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), OffsetDateTime::UNIX_EPOCH
+        );
+        let booster = Account::new(AccountId::default(), "booster", false, false, OffsetDateTime::now_utc(), OffsetDateTime::now_utc());
+        let boost = Boost::new(BoostId::default(), *booster.id(), *note.id(), Visibility::Public, false, OffsetDateTime::now_utc());
+
+        let entry = TimelineEntryDto::new(TimelineEntry::Note(note.clone()), &HashMap::new()).unwrap();
+        assert_eq!(entry.id, *note.id().as_ref());
+        assert!(entry.boost.is_none());
+
+        // A boost is paged by its own id and placed by when it was made.
+        let accounts = HashMap::from([(*booster.id(), booster.clone())]);
+        let entry = TimelineEntryDto::new(TimelineEntry::Boost(boost.clone(), note.clone()), &accounts).unwrap();
+        assert_eq!(entry.id, *boost.id().as_ref());
+        assert_eq!(entry.created_at, *boost.created_at().as_ref());
+
+        // Boosts by accounts that are gone are dropped.
+        assert!(TimelineEntryDto::new(TimelineEntry::Boost(boost, note), &HashMap::new()).is_none());
+    }
+}
\ No newline at end of file
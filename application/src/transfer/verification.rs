@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct VerifyAccountDto {
+    pub token: String
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RequestPasswordResetDto {
+    pub address: String
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordDto {
+    pub token: String,
+    pub pass: String
+}
\ No newline at end of file
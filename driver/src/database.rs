@@ -1,7 +1,49 @@
 mod account;
 mod profile;
+mod confidential;
+mod follow;
+mod session;
+mod application;
+mod authorization_code;
+mod oauth_token;
+mod verification;
+mod password_reset;
+mod note;
+mod hashtag;
+mod reaction;
+mod reaction_asset;
+mod boost;
+mod timeline;
+mod timeline_cache;
+mod account_key;
+mod remote_key;
+mod remote_note;
+mod received_activity;
+mod delivery;
+mod delivery_host;
 
 pub use self::{
     account::AccountDataBase,
-    profile::ProfileDataBase
+    profile::ProfileDataBase,
+    confidential::ConfidentialDataBase,
+    follow::FollowDataBase,
+    session::SessionDataBase,
+    application::ApplicationDataBase,
+    authorization_code::AuthorizationCodeDataBase,
+    oauth_token::OAuthTokenDataBase,
+    verification::VerificationDataBase,
+    password_reset::PasswordResetDataBase,
+    note::NoteDataBase,
+    hashtag::HashtagDataBase,
+    reaction::ReactionDataBase,
+    reaction_asset::ReactionAssetDataBase,
+    boost::BoostDataBase,
+    timeline::TimelineDataBase,
+    timeline_cache::{TimelineCacheDataBase, MemoryTimelineCache},
+    account_key::AccountKeyDataBase,
+    remote_key::RemoteKeyDataBase,
+    remote_note::RemoteNoteDataBase,
+    received_activity::ReceivedActivityDataBase,
+    delivery::DeliveryDataBase,
+    delivery_host::DeliveryHostDataBase
 };
\ No newline at end of file
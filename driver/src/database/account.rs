@@ -2,12 +2,19 @@ use futures_util::{StreamExt, TryStreamExt};
 use kernel::{
     KernelError,
     repository::AccountRepository,
-    entities::{Account, AccountId, AccountName}
+    entities::{Account, AccountId, AccountKey, AccountName, Confidential}
 };
 use sqlx::{Pool, Postgres, PgConnection};
 use time::OffsetDateTime;
 
-use crate::DriverError;
+use crate::{
+    database::{account_key::Internal as AccountKeyDataBaseInternal, confidential::Internal as ConfidentialDataBaseInternal},
+    DriverError
+};
+
+/// Unique indexes whose violation means the name or address is taken.
+const NAME_INDEX: &str = "accounts_name_lower_idx";
+const ADDRESS_INDEX: &str = "confidentials_address_idx";
 
 #[derive(Debug, Clone)]
 pub struct AccountDataBase {
@@ -31,6 +38,39 @@ impl AccountRepository for AccountDataBase {
         Ok(())
     }
 
+    async fn register(&self, account: &Account, confidential: &Confidential, key: &AccountKey) -> Result<(), KernelError> {
+        let conflict = |e: DriverError| -> KernelError {
+            let DriverError::SqlX(sqlx::Error::Database(db)) = &e else {
+                return e.into();
+            };
+            match db.constraint() {
+                Some(NAME_INDEX) => KernelError::Conflict { entity: "account", id: account.name().as_ref().to_string() },
+                Some(ADDRESS_INDEX) => KernelError::Conflict { entity: "address", id: confidential.address().as_ref().to_string() },
+                _ => e.into()
+            }
+        };
+
+        let mut tx = self.pool.begin().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(account, &mut tx).await
+            .map_err(conflict)?;
+        ConfidentialDataBaseInternal::create(confidential, &mut tx).await
+            .map_err(conflict)?;
+        AccountKeyDataBaseInternal::create(key, &mut tx).await?;
+        tx.commit().await
+            .map_err(DriverError::SqlX)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, update: &Account) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::update(update, &mut con).await?;
+
+        Ok(())
+    }
+
     async fn delete(&self, delete: &AccountId) -> Result<(), KernelError> {
         let mut con = self.pool.acquire().await
             .map_err(DriverError::SqlX)?;
@@ -53,12 +93,26 @@ impl AccountRepository for AccountDataBase {
         Ok(found)
     }
 
+    async fn find_by_ids(&self, ids: &[AccountId]) -> Result<Vec<Account>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_ids(ids, &mut con).await?;
+        Ok(found)
+    }
+
     async fn find_by_name(&self, name: &AccountName) -> Result<Option<Account>, KernelError> {
         let mut con = self.pool.acquire().await
             .map_err(DriverError::SqlX)?;
         let found = Internal::find_by_name(name, &mut con).await?;
         Ok(found)
     }
+
+    async fn count(&self) -> Result<i64, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let count = Internal::count(&mut con).await?;
+        Ok(count)
+    }
 }
 
 #[allow(dead_code)]
@@ -67,6 +121,7 @@ struct AccountRow {
     id: i64,
     name: String,
     bot: bool,
+    locked: bool,
     created_at: OffsetDateTime,
     updated_at: OffsetDateTime
 }
@@ -80,6 +135,7 @@ impl Internal {
                 id,
                 name,
                 bot,
+                locked,
                 created_at,
                 updated_at
             )
@@ -88,12 +144,14 @@ impl Internal {
                 $2,
                 $3,
                 $4,
-                $5
+                $5,
+                $6
             );
         "#)
         .bind(create.id().as_ref())
         .bind(create.name().as_ref())
         .bind(create.bot().as_ref())
+        .bind(create.locked().as_ref())
         .bind(create.date().created_at().as_ref())
         .bind(create.date().updated_at().as_ref())
         .execute(&mut *con)
@@ -102,6 +160,25 @@ impl Internal {
         Ok(())
     }
 
+    pub async fn update(update: &Account, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            UPDATE accounts
+            SET
+                bot = $1,
+                locked = $2,
+                updated_at = $3
+            WHERE id = $4
+        "#)
+        .bind(update.bot().as_ref())
+        .bind(update.locked().as_ref())
+        .bind(update.date().updated_at().as_ref())
+        .bind(update.id().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn delete(delete: &AccountId, con: &mut PgConnection) -> Result<(), DriverError> {
         sqlx::query(r#"
             DELETE FROM accounts WHERE id = $1
@@ -124,6 +201,7 @@ impl Internal {
                 fetched.id,
                 fetched.name,
                 fetched.bot,
+                fetched.locked,
                 fetched.created_at, 
                 fetched.updated_at
             ))
@@ -146,6 +224,7 @@ impl Internal {
                 fetched.id,
                 fetched.name,
                 fetched.bot,
+                fetched.locked,
                 fetched.created_at, 
                 fetched.updated_at
             ))
@@ -153,9 +232,34 @@ impl Internal {
         .transpose()
     }
 
+    pub async fn find_by_ids(ids: &[AccountId], con: &mut PgConnection) -> Result<Vec<Account>, DriverError> {
+        let ids = ids.iter()
+            .map(|id| *id.as_ref())
+            .collect::<Vec<i64>>();
+
+        sqlx::query_as::<_, AccountRow>(r#"
+            SELECT * from accounts WHERE id = ANY($1)
+        "#)
+        .bind(ids)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(|fetched| -> Result<Account, DriverError> {
+            Ok(Account::new(
+                fetched.id,
+                fetched.name,
+                fetched.bot,
+                fetched.locked,
+                fetched.created_at, 
+                fetched.updated_at
+            ))
+        })
+        .collect()
+    }
+
     pub async fn find_by_name(name: &AccountName, con: &mut PgConnection) -> Result<Option<Account>, DriverError> {
         sqlx::query_as::<_, AccountRow>(r#"
-            SELECT * from accounts WHERE name LIKE $1
+            SELECT * from accounts WHERE LOWER(name) = LOWER($1)
         "#)
         .bind(name.as_ref())
         .fetch_optional(&mut *con)
@@ -165,24 +269,39 @@ impl Internal {
                 fetched.id,
                 fetched.name,
                 fetched.bot,
+                fetched.locked,
                 fetched.created_at, 
                 fetched.updated_at
             ))
         })
         .transpose()
     }
+
+    pub async fn count(con: &mut PgConnection) -> Result<i64, DriverError> {
+        let (count,): (i64,) = sqlx::query_as(r#"
+            SELECT COUNT(*) FROM accounts
+        "#)
+        .fetch_one(&mut *con)
+        .await?;
+
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
 
-    use kernel::entities::{Account, AccountId, AccountName};
+    use kernel::{
+        KernelError,
+        repository::AccountRepository,
+        entities::{Account, AccountId, AccountKey, AccountName, ConfidentialId, Confidential, Password}
+    };
     use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
-    use time::PrimitiveDateTime;
+    use time::{OffsetDateTime, PrimitiveDateTime};
     use time_macros::{date, time};
 
-    use super::Internal;
+    use super::{AccountDataBase, Internal};
 
     async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
         dotenvy::dotenv().ok();
@@ -214,16 +333,20 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true,  true,  created_at, updated_at);
 
         let mut con = pool.begin().await?;
 
+        let before = Internal::count(&mut con).await?;
+
         Internal::create(&a, &mut con).await?;
         Internal::create(&b, &mut con).await?;
         Internal::create(&c, &mut con).await?;
 
+        assert_eq!(Internal::count(&mut con).await?, before + 3);
+
         let fetched = Internal::find_all(&mut con).await?;
         println!("{:?}", fetched);
 
@@ -239,6 +362,12 @@ mod tests {
 
         assert_ne!(fetched.0, b);
 
+        let mut fetched = Internal::find_by_ids(&[a_id, c_id, AccountId::default()], &mut con).await?;
+        fetched.sort_by_key(|account| *account.id().as_ref());
+        let mut expected = vec![a.clone(), c.clone()];
+        expected.sort_by_key(|account| *account.id().as_ref());
+        assert_eq!(fetched, expected);
+
         let fetched = (
             Internal::find_by_name(&a_name, &mut con).await?.unwrap(),
             Internal::find_by_name(&b_name, &mut con).await?.unwrap(),
@@ -271,9 +400,9 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true,  true,  created_at, updated_at);
 
         let mut con = pool.begin().await?;
 
@@ -301,9 +430,9 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true,  true,  created_at, updated_at);
 
         let mut con = pool.begin().await?;
 
@@ -327,6 +456,33 @@ mod tests {
         Ok(())
     }
 
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_update() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let a_id = AccountId::default();
+        let a_name = AccountName::new("test1");
+
+        let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-25), time!(0:00)).assume_utc();
+
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, created_at);
+
+        let mut con = pool.begin().await?;
+
+        Internal::create(&a, &mut con).await?;
+
+        let locked = Account::new(a_id, a_name, false, true, created_at, updated_at);
+        Internal::update(&locked, &mut con).await?;
+
+        let fetched = Internal::find_by_id(&a_id, &mut con).await?.unwrap();
+        assert_eq!(fetched, locked);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
     #[ignore = "It depends on Postgres and does not work as is."]
     #[tokio::test]
     async fn test_find_all() -> anyhow::Result<()> {
@@ -343,9 +499,9 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true,  true,  created_at, updated_at);
 
         let mut con = pool.begin().await?;
 
@@ -376,9 +532,9 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true,  true,  created_at, updated_at);
 
         let mut con = pool.begin().await?;
 
@@ -418,9 +574,9 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true,  true,  created_at, updated_at);
 
         let mut con = pool.begin().await?;
 
@@ -440,7 +596,37 @@ mod tests {
 
         assert_ne!(fetched.0, b);
 
+        // Names are compared case-insensitively, and `_` is not a wildcard.
+        let fetched = Internal::find_by_name(&AccountName::new("TEST1"), &mut con).await?;
+        assert_eq!(fetched, Some(a));
+        let fetched = Internal::find_by_name(&AccountName::new("test_"), &mut con).await?;
+        assert_eq!(fetched, None);
+
         con.rollback().await?;
         Ok(())
     }
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_register() -> anyhow::Result<()> {
+        let db = AccountDataBase::new(test_pool().await?);
+
+        let now = OffsetDateTime::now_utc();
+        let first = AccountId::default();
+        let second = AccountId::default();
+        let account = |id: AccountId, name: &str| Account::new(id, AccountName::new(name), false, false, now, now);
+        let confidential = |id: AccountId, address: &str| Confidential::new(ConfidentialId::default(), id, now, now, address, Password::new("hashed"), false);
+        let key = |id: AccountId| AccountKey::new(id, "public", "private", now);
+
+        db.register(&account(first, "register_test1"), &confidential(first, "register_test1@example.com"), &key(first)).await?;
+
+        // Nothing of a failed registration is left behind.
+        let taken = db.register(&account(second, "REGISTER_TEST1"), &confidential(second, "register_test2@example.com"), &key(second)).await;
+        assert!(matches!(taken, Err(KernelError::Conflict { entity: "account", .. })));
+        let taken = db.register(&account(second, "register_test2"), &confidential(second, "register_test1@example.com"), &key(second)).await;
+        assert!(matches!(taken, Err(KernelError::Conflict { entity: "address", .. })));
+        assert!(db.find_by_id(&second).await?.is_none());
+
+        db.delete(&first).await?;
+        Ok(())
+    }
 }
\ No newline at end of file
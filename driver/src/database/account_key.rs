@@ -0,0 +1,143 @@
+use kernel::{
+    repository::AccountKeyRepository,
+    entities::{AccountKey, AccountId},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct AccountKeyDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl AccountKeyDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountKeyRepository for AccountKeyDataBase {
+    async fn create(&self, create: &AccountKey) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_account_id(&self, id: &AccountId) -> Result<Option<AccountKey>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_account_id(id, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AccountKeyRow {
+    account: i64,
+    public_key: String,
+    private_key: String,
+    created_at: OffsetDateTime
+}
+
+impl From<AccountKeyRow> for AccountKey {
+    fn from(row: AccountKeyRow) -> Self {
+        AccountKey::new(row.account, row.public_key, row.private_key, row.created_at)
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    /// An account keeps its first key. Creating another one is a no-op.
+    pub async fn create(create: &AccountKey, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO account_keys (account, public_key, private_key, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (account) DO NOTHING
+        "#)
+        .bind(create.account().as_ref())
+        .bind(create.public_key().as_ref())
+        .bind(create.private_key().as_ref())
+        .bind(create.created_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_account_id(id: &AccountId, con: &mut PgConnection) -> Result<Option<AccountKey>, DriverError> {
+        let found = sqlx::query_as::<_, AccountKeyRow>(r#"
+            SELECT * FROM account_keys WHERE account = $1
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(AccountKey::from);
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::account::Internal as AccountDataBaseInternal;
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-5-5), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, created_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        assert!(Internal::find_by_account_id(&a_id, &mut con).await?.is_none());
+
+        let key = AccountKey::new(a_id, "public", "private", created_at);
+        Internal::create(&key, &mut con).await?;
+        assert_eq!(Internal::find_by_account_id(&a_id, &mut con).await?, Some(key.clone()));
+
+        // The first key is kept.
+        Internal::create(&AccountKey::new(a_id, "other", "other", created_at), &mut con).await?;
+        assert_eq!(Internal::find_by_account_id(&a_id, &mut con).await?, Some(key));
+
+        // Keys go away with their account.
+        AccountDataBaseInternal::delete(&a_id, &mut con).await?;
+        assert!(Internal::find_by_account_id(&a_id, &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,233 @@
+use kernel::{
+    repository::ApplicationRepository,
+    entities::{Application, ApplicationId, ClientId, Scopes},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct ApplicationDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl ApplicationDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ApplicationRepository for ApplicationDataBase {
+    async fn create(&self, create: &Application) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, delete: &ApplicationId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(delete, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &ApplicationId) -> Result<Option<Application>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_id(id, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_client_id(&self, client_id: &ClientId) -> Result<Option<Application>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_client_id(client_id, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ApplicationRow {
+    id: Uuid,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+    name: String,
+    website: Option<String>,
+    client_id: String,
+    client_secret: String,
+    redirect_uris: Vec<String>,
+    scopes: Vec<String>
+}
+
+impl From<ApplicationRow> for Application {
+    fn from(fetched: ApplicationRow) -> Self {
+        Application::new(
+            fetched.id,
+            fetched.created_at,
+            fetched.updated_at,
+            fetched.name,
+            fetched.website,
+            fetched.client_id,
+            fetched.client_secret,
+            fetched.redirect_uris,
+            Scopes::new(fetched.scopes)
+        )
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Application, con: &mut PgConnection) -> Result<(), DriverError> {
+        let redirect_uris = create.redirect_uris().iter()
+            .map(|uri| uri.as_ref())
+            .collect::<Vec<&str>>();
+        let scopes = create.scopes().iter()
+            .map(|scope| scope.as_ref())
+            .collect::<Vec<&str>>();
+
+        sqlx::query(r#"
+            INSERT INTO oauth_applications (
+                id,
+                created_at,
+                updated_at,
+                name,
+                website,
+                client_id,
+                client_secret,
+                redirect_uris,
+                scopes
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8,
+                $9
+            );
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.date().created_at().as_ref())
+        .bind(create.date().updated_at().as_ref())
+        .bind(create.name().as_ref())
+        .bind(create.website().map(|website| website.as_ref()))
+        .bind(create.client_id().as_ref())
+        .bind(create.client_secret().as_ref())
+        .bind(redirect_uris)
+        .bind(scopes)
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(delete: &ApplicationId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM oauth_applications WHERE id = $1
+        "#)
+        .bind(delete.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_id(id: &ApplicationId, con: &mut PgConnection) -> Result<Option<Application>, DriverError> {
+        let found = sqlx::query_as::<_, ApplicationRow>(r#"
+            SELECT * FROM oauth_applications WHERE id = $1
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Application::from);
+
+        Ok(found)
+    }
+
+    pub async fn find_by_client_id(client_id: &ClientId, con: &mut PgConnection) -> Result<Option<Application>, DriverError> {
+        let found = sqlx::query_as::<_, ApplicationRow>(r#"
+            SELECT * FROM oauth_applications WHERE client_id = $1
+        "#)
+        .bind(client_id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Application::from);
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-5), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-5), time!(0:00)).assume_utc();
+
+        let app_id = ApplicationId::default();
+        let client_id = ClientId::default();
+        let app = Application::new(
+            app_id,
+            created_at, updated_at,
+            "Test Client",
+            Some("https://example.com"),
+            client_id.clone(),
+            ClientSecret::default(),
+            ["https://example.com/callback", RedirectUri::OUT_OF_BAND],
+            Scopes::try_from("read write:accounts")?
+        );
+
+        Internal::create(&app, &mut con).await?;
+
+        let by_id = Internal::find_by_id(&app_id, &mut con).await?.unwrap();
+        let by_client_id = Internal::find_by_client_id(&client_id, &mut con).await?.unwrap();
+
+        assert_eq!(by_id, app);
+        assert_eq!(by_client_id, app);
+
+        Internal::delete(&app_id, &mut con).await?;
+
+        assert!(Internal::find_by_id(&app_id, &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
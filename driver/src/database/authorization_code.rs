@@ -0,0 +1,213 @@
+use kernel::{
+    repository::AuthorizationCodeRepository,
+    entities::{AuthorizationCode, ApplicationId, ChallengeMethod, Code, CodeChallenge, Scopes},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct AuthorizationCodeDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl AuthorizationCodeDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthorizationCodeRepository for AuthorizationCodeDataBase {
+    async fn create(&self, create: &AuthorizationCode) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn take(&self, code: &Code) -> Result<Option<AuthorizationCode>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let taken = Internal::take(code, &mut con).await?;
+        Ok(taken)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct AuthorizationCodeRow {
+    code: String,
+    application: Uuid,
+    account: i64,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    code_challenge: Option<String>,
+    code_challenge_method: Option<String>,
+    created_at: OffsetDateTime,
+    expires_at: OffsetDateTime
+}
+
+impl TryFrom<AuthorizationCodeRow> for AuthorizationCode {
+    type Error = DriverError;
+    fn try_from(fetched: AuthorizationCodeRow) -> Result<Self, Self::Error> {
+        let challenge = match (fetched.code_challenge, fetched.code_challenge_method) {
+            (Some(challenge), Some(method)) => {
+                let method = ChallengeMethod::try_from(method.as_str())
+                    .map_err(|e| DriverError::Convert(e.to_string()))?;
+                Some(CodeChallenge::new(challenge, method))
+            },
+            _ => None
+        };
+
+        Ok(AuthorizationCode::new(
+            fetched.code,
+            ApplicationId::new(fetched.application),
+            fetched.account,
+            fetched.redirect_uri,
+            Scopes::new(fetched.scopes),
+            challenge,
+            fetched.created_at,
+            fetched.expires_at
+        ))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &AuthorizationCode, con: &mut PgConnection) -> Result<(), DriverError> {
+        let scopes = create.scopes().iter()
+            .map(|scope| scope.as_ref())
+            .collect::<Vec<&str>>();
+
+        sqlx::query(r#"
+            INSERT INTO oauth_authorization_codes (
+                code,
+                application,
+                account,
+                redirect_uri,
+                scopes,
+                code_challenge,
+                code_challenge_method,
+                created_at,
+                expires_at
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8,
+                $9
+            );
+        "#)
+        .bind(create.code().as_ref())
+        .bind(create.application().as_ref())
+        .bind(create.account().as_ref())
+        .bind(create.redirect_uri().as_ref())
+        .bind(scopes)
+        .bind(create.challenge().map(|challenge| challenge.challenge()))
+        .bind(create.challenge().map(|challenge| challenge.method().as_ref()))
+        .bind(create.created_at().as_ref())
+        .bind(create.expires_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn take(code: &Code, con: &mut PgConnection) -> Result<Option<AuthorizationCode>, DriverError> {
+        sqlx::query_as::<_, AuthorizationCodeRow>(r#"
+            DELETE FROM oauth_authorization_codes WHERE code = $1 RETURNING *
+        "#)
+        .bind(code.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(AuthorizationCode::try_from)
+        .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::{account::Internal as AccountDataBaseInternal, application::Internal as ApplicationDataBaseInternal};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-5), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-5), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let app_id = ApplicationId::default();
+        let app = Application::new(
+            app_id,
+            created_at, updated_at,
+            "Test Client",
+            None::<String>,
+            ClientId::default(),
+            ClientSecret::default(),
+            ["https://example.com/callback"],
+            Scopes::all()
+        );
+        ApplicationDataBaseInternal::create(&app, &mut con).await?;
+
+        let code = AuthorizationCode::new(
+            Code::default(),
+            app_id,
+            a_id,
+            "https://example.com/callback",
+            Scopes::try_from("read")?,
+            Some(CodeChallenge::new("E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM", ChallengeMethod::S256)),
+            created_at,
+            created_at + AuthorizationCode::LIFETIME
+        );
+
+        Internal::create(&code, &mut con).await?;
+
+        let taken = Internal::take(code.code(), &mut con).await?.unwrap();
+        assert_eq!(taken, code);
+
+        // A code can be exchanged only once.
+        assert!(Internal::take(code.code(), &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
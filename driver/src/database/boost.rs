@@ -0,0 +1,279 @@
+use kernel::{
+    repository::BoostRepository,
+    entities::{AccountId, AccountTypes, Boost, NoteTypes, Visibility},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+use super::note::{note_columns, note_types};
+
+#[derive(Debug, Clone)]
+pub struct BoostDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl BoostDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl BoostRepository for BoostDataBase {
+    async fn create(&self, create: &Boost) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, account: &AccountTypes, note: &NoteTypes) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(account, note, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find(&self, account: &AccountTypes, note: &NoteTypes) -> Result<Option<Boost>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find(account, note, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_note(&self, note: &NoteTypes) -> Result<Vec<Boost>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_note(note, &mut con).await?;
+        Ok(found)
+    }
+}
+
+/// Splits `account` into the `(*_local, *_remote)` column pair. Exactly one is `Some`.
+fn columns(account: &AccountTypes) -> (Option<i64>, Option<&str>) {
+    match account {
+        AccountTypes::Local(id) => (Some(*id.as_ref()), None),
+        AccountTypes::Federate(url) => (None, Some(url.as_str()))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct BoostRow {
+    id: Uuid,
+    origin_local: Option<i64>,
+    origin_remote: Option<String>,
+    target_local: Option<Uuid>,
+    target_remote: Option<String>,
+    created_at: OffsetDateTime,
+    implicit: bool,
+    visibility: String
+}
+
+impl TryFrom<BoostRow> for Boost {
+    type Error = DriverError;
+    fn try_from(fetched: BoostRow) -> Result<Self, Self::Error> {
+        let account = match (fetched.origin_local, fetched.origin_remote) {
+            (Some(id), None) => AccountTypes::Local(AccountId::new(id)),
+            (None, Some(url)) => AccountTypes::Federate(url),
+            _ => return Err(DriverError::Convert("boost must have exactly one of local or remote account.".to_string()))
+        };
+        let Some(note) = note_types(fetched.target_local, fetched.target_remote, "boost")? else {
+            return Err(DriverError::Convert("boost must have exactly one of local or remote note.".to_string()));
+        };
+        let visibility = Visibility::try_from(fetched.visibility.as_str())
+            .map_err(|e| DriverError::Convert(e.to_string()))?;
+        Ok(Boost::new(
+            fetched.id,
+            account,
+            note,
+            visibility,
+            fetched.implicit,
+            fetched.created_at
+        ))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Boost, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (origin_local, origin_remote) = columns(create.account());
+        let (target_local, target_remote) = note_columns(create.note());
+
+        sqlx::query(r#"
+            INSERT INTO note_turbo (
+                id,
+                origin_local,
+                origin_remote,
+                target_local,
+                target_remote,
+                created_at,
+                implicit,
+                visibility
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8
+            )
+            ON CONFLICT DO NOTHING
+        "#)
+        .bind(create.id().as_ref())
+        .bind(origin_local)
+        .bind(origin_remote)
+        .bind(target_local)
+        .bind(target_remote)
+        .bind(create.created_at().as_ref())
+        .bind(create.is_implicit())
+        .bind(create.visibility().as_str())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(account: &AccountTypes, note: &NoteTypes, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (origin_local, origin_remote) = columns(account);
+        let (target_local, target_remote) = note_columns(note);
+
+        sqlx::query(r#"
+            DELETE FROM note_turbo
+            WHERE (origin_local = $1 OR origin_remote = $2)
+              AND (target_local = $3 OR target_remote = $4)
+        "#)
+        .bind(origin_local)
+        .bind(origin_remote)
+        .bind(target_local)
+        .bind(target_remote)
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find(account: &AccountTypes, note: &NoteTypes, con: &mut PgConnection) -> Result<Option<Boost>, DriverError> {
+        let (origin_local, origin_remote) = columns(account);
+        let (target_local, target_remote) = note_columns(note);
+
+        sqlx::query_as::<_, BoostRow>(r#"
+            SELECT * FROM note_turbo
+            WHERE (origin_local = $1 OR origin_remote = $2)
+              AND (target_local = $3 OR target_remote = $4)
+        "#)
+        .bind(origin_local)
+        .bind(origin_remote)
+        .bind(target_local)
+        .bind(target_remote)
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Boost::try_from)
+        .transpose()
+    }
+
+    pub async fn find_by_note(note: &NoteTypes, con: &mut PgConnection) -> Result<Vec<Boost>, DriverError> {
+        let (target_local, target_remote) = note_columns(note);
+
+        sqlx::query_as::<_, BoostRow>(r#"
+            SELECT * FROM note_turbo
+            WHERE target_local = $1 OR target_remote = $2
+            ORDER BY created_at ASC, id ASC
+        "#)
+        .bind(target_local)
+        .bind(target_remote)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Boost::try_from)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use uuid::Uuid;
+    use crate::database::{
+        account::Internal as AccountDataBaseInternal,
+        note::Internal as NoteDataBaseInternal
+    };
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-4-30), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-4-30), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let b_id = AccountId::default();
+        let b = Account::new(b_id, "test2", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let note = Note::new(NoteId::default(), a_id, "Hello", None::<String>, Visibility::Public, None, None, [], Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at);
+        NoteDataBaseInternal::create(&note, &mut con).await?;
+
+        let target = NoteTypes::Local(*note.id());
+        let local = AccountTypes::Local(b_id);
+        let remote = AccountTypes::Federate("https://remote.example/users/test3".to_string());
+
+        let a_boost = Boost::new(BoostId::default(), local.clone(), target.clone(), Visibility::Unlisted, false, created_at);
+        let b_boost = Boost::new(BoostId::default(), remote.clone(), target.clone(), Visibility::Public, true, created_at + time::Duration::minutes(1));
+
+        Internal::create(&a_boost, &mut con).await?;
+        Internal::create(&b_boost, &mut con).await?;
+
+        // Boosting twice keeps the first boost.
+        Internal::create(&Boost::new(BoostId::default(), local.clone(), target.clone(), Visibility::Public, false, created_at), &mut con).await?;
+
+        assert_eq!(Internal::find(&local, &target, &mut con).await?, Some(a_boost.clone()));
+        assert_eq!(Internal::find_by_note(&target, &mut con).await?, vec![a_boost, b_boost.clone()]);
+
+        Internal::delete(&local, &target, &mut con).await?;
+        assert!(Internal::find(&local, &target, &mut con).await?.is_none());
+        assert_eq!(Internal::find_by_note(&target, &mut con).await?, vec![b_boost]);
+
+        // Boosts go away with the note.
+        NoteDataBaseInternal::delete(note.id(), &mut con).await?;
+        assert!(Internal::find_by_note(&target, &mut con).await?.is_empty());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
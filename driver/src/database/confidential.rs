@@ -0,0 +1,273 @@
+use kernel::{
+    repository::ConfidentialRepository,
+    entities::{Confidential, AccountId, Address},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct ConfidentialDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl ConfidentialDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConfidentialRepository for ConfidentialDataBase {
+    async fn create(&self, create: &Confidential) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn update(&self, update: &Confidential) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::update(update, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_account_id(&self, id: &AccountId) -> Result<Option<Confidential>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let fetched = Internal::find_by_account_id(id, &mut con).await?;
+        Ok(fetched)
+    }
+
+    async fn find_by_address(&self, address: &Address) -> Result<Option<Confidential>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let fetched = Internal::find_by_address(address, &mut con).await?;
+        Ok(fetched)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ConfidentialRow {
+    id: Uuid,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+    account: i64,
+    address: String,
+    pass: String,
+    verified: bool
+}
+
+impl From<ConfidentialRow> for Confidential {
+    fn from(fetched: ConfidentialRow) -> Self {
+        Confidential::new(
+            fetched.id,
+            fetched.account,
+            fetched.created_at,
+            fetched.updated_at,
+            fetched.address,
+            fetched.pass,
+            fetched.verified
+        )
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Confidential, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO confidentials (
+                id,
+                created_at,
+                updated_at,
+                account,
+                address,
+                pass,
+                verified
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7
+            );
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.date().created_at().as_ref())
+        .bind(create.date().updated_at().as_ref())
+        .bind(create.account().as_ref())
+        .bind(create.address().as_ref())
+        .bind(create.pass().as_ref())
+        .bind(create.is_verified())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update(update: &Confidential, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            UPDATE confidentials
+            SET
+                updated_at = $1,
+                address = $2,
+                pass = $3,
+                verified = $4
+            WHERE id = $5
+        "#)
+        .bind(update.date().updated_at().as_ref())
+        .bind(update.address().as_ref())
+        .bind(update.pass().as_ref())
+        .bind(update.is_verified())
+        .bind(update.id().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_account_id(account: &AccountId, con: &mut PgConnection) -> Result<Option<Confidential>, DriverError> {
+        let fetched = sqlx::query_as::<_, ConfidentialRow>(r#"
+            SELECT * from confidentials WHERE account = $1
+        "#)
+        .bind(account.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Confidential::from);
+
+        Ok(fetched)
+    }
+
+    pub async fn find_by_address(address: &Address, con: &mut PgConnection) -> Result<Option<Confidential>, DriverError> {
+        let fetched = sqlx::query_as::<_, ConfidentialRow>(r#"
+            SELECT * from confidentials WHERE address = $1
+        "#)
+        .bind(address.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Confidential::from);
+
+        Ok(fetched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::account::Internal as AccountDataBaseInternal;
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_create() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let a_id = AccountId::default();
+
+        let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
+
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let a_address = Address::new("test1@example.com");
+        let a_conf = Confidential::new(
+            ConfidentialId::default(), a_id,
+            created_at, updated_at,
+            a_address.clone(),
+            Password::hash("test_man_a_pass")?,
+            false
+        );
+
+        Internal::create(&a_conf, &mut con).await?;
+
+        let by_account = Internal::find_by_account_id(&a_id, &mut con).await?.unwrap();
+        let by_address = Internal::find_by_address(&a_address, &mut con).await?.unwrap();
+
+        assert_eq!(by_account, a_conf);
+        assert_eq!(by_address, a_conf);
+        assert!(by_address.pass().verify("test_man_a_pass")?);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_update() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let a_id = AccountId::default();
+
+        let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
+
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let a_conf_id = ConfidentialId::default();
+        let a_conf = Confidential::new(
+            a_conf_id, a_id,
+            created_at, updated_at,
+            "test1@example.com",
+            Password::hash("test_man_a_pass")?,
+            false
+        );
+
+        Internal::create(&a_conf, &mut con).await?;
+
+        let a_conf = Confidential::new(
+            a_conf_id, a_id,
+            created_at, updated_at,
+            "test1@example.com",
+            Password::hash("test_man_a_new_pass")?,
+            true
+        );
+
+        Internal::update(&a_conf, &mut con).await?;
+
+        let a_conf_in_db = Internal::find_by_account_id(&a_id, &mut con).await?.unwrap();
+
+        assert!(a_conf_in_db.pass().verify("test_man_a_new_pass")?);
+        assert!(!a_conf_in_db.pass().verify("test_man_a_pass")?);
+        assert!(a_conf_in_db.is_verified());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
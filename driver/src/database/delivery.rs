@@ -0,0 +1,257 @@
+use kernel::{
+    repository::DeliveryRepository,
+    entities::{Delivery, DeliveryId},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct DeliveryDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl DeliveryDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeliveryRepository for DeliveryDataBase {
+    async fn create(&self, create: &Delivery) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn update(&self, update: &Delivery) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::update(update, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, delete: &DeliveryId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(delete, &mut con).await?;
+        Ok(())
+    }
+
+    async fn claim(&self, now: &OffsetDateTime, lease: &Duration, limit: i64) -> Result<Vec<Delivery>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let claimed = Internal::claim(now, lease, limit, &mut con).await?;
+        Ok(claimed)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct DeliveryRow {
+    id: Uuid,
+    sender: i64,
+    key_id: String,
+    host: String,
+    recipients: Vec<String>,
+    activity: String,
+    attempts: i32,
+    next_attempt_at: OffsetDateTime,
+    created_at: OffsetDateTime
+}
+
+impl From<DeliveryRow> for Delivery {
+    fn from(row: DeliveryRow) -> Self {
+        Delivery::new(
+            row.id,
+            row.sender,
+            row.key_id,
+            row.host,
+            row.recipients,
+            row.activity,
+            row.attempts,
+            row.next_attempt_at,
+            row.created_at
+        )
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Delivery, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO deliveries (
+                id,
+                sender,
+                key_id,
+                host,
+                recipients,
+                activity,
+                attempts,
+                next_attempt_at,
+                created_at
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8,
+                $9
+            )
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.sender().as_ref())
+        .bind(create.key_id())
+        .bind(create.host())
+        .bind(create.recipients())
+        .bind(create.activity())
+        .bind(create.attempts())
+        .bind(create.next_attempt_at())
+        .bind(create.created_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update(update: &Delivery, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            UPDATE deliveries SET attempts = $1, next_attempt_at = $2 WHERE id = $3
+        "#)
+        .bind(update.attempts())
+        .bind(update.next_attempt_at())
+        .bind(update.id().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(delete: &DeliveryId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM deliveries WHERE id = $1
+        "#)
+        .bind(delete.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Pushing `next_attempt_at` past the lease is what hides a claimed delivery,
+    /// so a worker that dies mid-delivery leaves it to be claimed again.
+    pub async fn claim(now: &OffsetDateTime, lease: &Duration, limit: i64, con: &mut PgConnection) -> Result<Vec<Delivery>, DriverError> {
+        let claimed = sqlx::query_as::<_, DeliveryRow>(r#"
+            UPDATE deliveries SET next_attempt_at = $2
+            WHERE id IN (
+                SELECT id FROM deliveries
+                WHERE next_attempt_at <= $1
+                ORDER BY next_attempt_at ASC, created_at ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+        "#)
+        .bind(now)
+        .bind(*now + *lease)
+        .bind(limit)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Delivery::from)
+        .collect();
+
+        Ok(claimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::account::Internal as AccountDataBaseInternal;
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        // Far enough in the past that no other pending delivery is due before these.
+        let created_at = PrimitiveDateTime::new(date!(2000-1-1), time!(0:00)).assume_utc();
+        let now = PrimitiveDateTime::new(date!(2000-1-1), time!(1:00)).assume_utc();
+        let lease = time::Duration::minutes(5);
+
+        let account = Account::new(AccountId::default(), "test1", false, false, created_at, created_at);
+        AccountDataBaseInternal::create(&account, &mut con).await?;
+
+        let delivery = |next_attempt_at| Delivery::new(
+            DeliveryId::default(),
+            *account.id(),
+            "https://local.example/users/test1#main-key",
+            "remote.example",
+            ["https://remote.example/users/a", "https://remote.example/users/b"],
+            r#"{"type":"Create"}"#,
+            0,
+            next_attempt_at,
+            created_at
+        );
+        let due = delivery(created_at);
+        let later = delivery(now + time::Duration::hours(1));
+        Internal::create(&due, &mut con).await?;
+        Internal::create(&later, &mut con).await?;
+
+        let claimed = Internal::claim(&now, &lease, 10, &mut con).await?;
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id(), due.id());
+        assert_eq!(claimed[0].recipients(), due.recipients());
+        assert_eq!(*claimed[0].next_attempt_at(), now + lease);
+
+        // Claimed deliveries are hidden until the lease runs out.
+        assert!(Internal::claim(&now, &lease, 10, &mut con).await?.is_empty());
+        assert_eq!(Internal::claim(&(now + lease), &lease, 10, &mut con).await?.len(), 1);
+
+        let retried = due.clone().retry(now).unwrap();
+        Internal::update(&retried, &mut con).await?;
+        let claimed = Internal::claim(retried.next_attempt_at(), &lease, 10, &mut con).await?;
+        assert_eq!(claimed.iter().map(|delivery| delivery.attempts()).collect::<Vec<_>>(), vec![1]);
+
+        Internal::delete(due.id(), &mut con).await?;
+        Internal::delete(later.id(), &mut con).await?;
+        assert!(Internal::claim(&(now + time::Duration::days(1)), &lease, 10, &mut con).await?.is_empty());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,137 @@
+use kernel::{
+    repository::DeliveryHostRepository,
+    entities::DeliveryHost,
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct DeliveryHostDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl DeliveryHostDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl DeliveryHostRepository for DeliveryHostDataBase {
+    async fn save(&self, save: &DeliveryHost) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::save(save, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_host(&self, host: &str) -> Result<Option<DeliveryHost>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_host(host, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct DeliveryHostRow {
+    host: String,
+    failures: i32,
+    dead_since: Option<OffsetDateTime>
+}
+
+impl From<DeliveryHostRow> for DeliveryHost {
+    fn from(row: DeliveryHostRow) -> Self {
+        DeliveryHost::new(row.host, row.failures, row.dead_since)
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn save(save: &DeliveryHost, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO delivery_hosts (host, failures, dead_since)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (host) DO UPDATE SET
+                failures = EXCLUDED.failures,
+                dead_since = EXCLUDED.dead_since
+        "#)
+        .bind(save.host())
+        .bind(save.failures())
+        .bind(save.dead_since())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_host(host: &str, con: &mut PgConnection) -> Result<Option<DeliveryHost>, DriverError> {
+        let found = sqlx::query_as::<_, DeliveryHostRow>(r#"
+            SELECT * FROM delivery_hosts WHERE host = $1
+        "#)
+        .bind(host)
+        .fetch_optional(&mut *con)
+        .await?
+        .map(DeliveryHost::from);
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let now = PrimitiveDateTime::new(date!(2023-5-15), time!(0:00)).assume_utc();
+        let host = "shuttlepub-test.example";
+
+        assert!(Internal::find_by_host(host, &mut con).await?.is_none());
+
+        let failed = DeliveryHost::alive(host).failed(now);
+        Internal::save(&failed, &mut con).await?;
+        assert_eq!(Internal::find_by_host(host, &mut con).await?, Some(failed.clone()));
+
+        let dead = DeliveryHost::new(host, DeliveryHost::DEAD_AFTER, Some(now));
+        Internal::save(&dead, &mut con).await?;
+        assert_eq!(Internal::find_by_host(host, &mut con).await?, Some(dead.clone()));
+
+        Internal::save(&dead.succeeded(), &mut con).await?;
+        assert!(!Internal::find_by_host(host, &mut con).await?.unwrap().is_dead());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
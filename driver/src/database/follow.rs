@@ -0,0 +1,392 @@
+use kernel::{
+    repository::FollowRepository,
+    entities::{Follow, FollowId, FollowState, AccountId, AccountTypes},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct FollowDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl FollowDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl FollowRepository for FollowDataBase {
+    async fn create(&self, create: &Follow) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn update(&self, update: &Follow) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::update(update, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, source: &AccountTypes, destination: &AccountTypes) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(source, destination, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &FollowId) -> Result<Option<Follow>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_id(id, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find(&self, source: &AccountTypes, destination: &AccountTypes) -> Result<Option<Follow>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find(source, destination, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_followers(&self, account: &AccountTypes) -> Result<Vec<Follow>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_followers(account, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_followings(&self, account: &AccountTypes) -> Result<Vec<Follow>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_followings(account, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_requests(&self, account: &AccountTypes) -> Result<Vec<Follow>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_requests(account, &mut con).await?;
+        Ok(found)
+    }
+}
+
+/// Splits `account` into the `(*_local, *_remote)` column pair. Exactly one is `Some`.
+fn columns(account: &AccountTypes) -> (Option<i64>, Option<&str>) {
+    match account {
+        AccountTypes::Local(id) => (Some(*id.as_ref()), None),
+        AccountTypes::Federate(url) => (None, Some(url.as_str()))
+    }
+}
+
+fn account_types(local: Option<i64>, remote: Option<String>) -> Result<AccountTypes, DriverError> {
+    match (local, remote) {
+        (Some(id), None) => Ok(AccountTypes::Local(AccountId::new(id))),
+        (None, Some(url)) => Ok(AccountTypes::Federate(url)),
+        _ => Err(DriverError::Convert("follow must have exactly one of local or remote account.".to_string()))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct FollowRow {
+    id: Uuid,
+    created_at: OffsetDateTime,
+    source_local: Option<i64>,
+    source_remote: Option<String>,
+    destination_local: Option<i64>,
+    destination_remote: Option<String>,
+    accepted: bool
+}
+
+impl TryFrom<FollowRow> for Follow {
+    type Error = DriverError;
+    fn try_from(fetched: FollowRow) -> Result<Self, Self::Error> {
+        Ok(Follow::new(
+            fetched.id,
+            account_types(fetched.source_local, fetched.source_remote)?,
+            account_types(fetched.destination_local, fetched.destination_remote)?,
+            if fetched.accepted { FollowState::Accepted } else { FollowState::Pending },
+            fetched.created_at
+        ))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Follow, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (source_local, source_remote) = columns(create.source());
+        let (destination_local, destination_remote) = columns(create.destination());
+
+        sqlx::query(r#"
+            INSERT INTO follows (
+                id,
+                created_at,
+                source_local,
+                source_remote,
+                destination_local,
+                destination_remote,
+                accepted
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7
+            )
+            ON CONFLICT DO NOTHING;
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.created_at().as_ref())
+        .bind(source_local)
+        .bind(source_remote)
+        .bind(destination_local)
+        .bind(destination_remote)
+        .bind(create.state().is_accepted())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn update(update: &Follow, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            UPDATE follows SET accepted = $1 WHERE id = $2
+        "#)
+        .bind(update.state().is_accepted())
+        .bind(update.id().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(source: &AccountTypes, destination: &AccountTypes, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (source_local, source_remote) = columns(source);
+        let (destination_local, destination_remote) = columns(destination);
+
+        sqlx::query(r#"
+            DELETE FROM follows
+            WHERE (source_local = $1 OR source_remote = $2)
+              AND (destination_local = $3 OR destination_remote = $4)
+        "#)
+        .bind(source_local)
+        .bind(source_remote)
+        .bind(destination_local)
+        .bind(destination_remote)
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_id(id: &FollowId, con: &mut PgConnection) -> Result<Option<Follow>, DriverError> {
+        sqlx::query_as::<_, FollowRow>(r#"
+            SELECT * FROM follows WHERE id = $1
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Follow::try_from)
+        .transpose()
+    }
+
+    pub async fn find(source: &AccountTypes, destination: &AccountTypes, con: &mut PgConnection) -> Result<Option<Follow>, DriverError> {
+        let (source_local, source_remote) = columns(source);
+        let (destination_local, destination_remote) = columns(destination);
+
+        sqlx::query_as::<_, FollowRow>(r#"
+            SELECT * FROM follows
+            WHERE (source_local = $1 OR source_remote = $2)
+              AND (destination_local = $3 OR destination_remote = $4)
+        "#)
+        .bind(source_local)
+        .bind(source_remote)
+        .bind(destination_local)
+        .bind(destination_remote)
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Follow::try_from)
+        .transpose()
+    }
+
+    pub async fn find_followers(account: &AccountTypes, con: &mut PgConnection) -> Result<Vec<Follow>, DriverError> {
+        let (local, remote) = columns(account);
+
+        sqlx::query_as::<_, FollowRow>(r#"
+            SELECT * FROM follows
+            WHERE (destination_local = $1 OR destination_remote = $2) AND accepted
+            ORDER BY created_at DESC
+        "#)
+        .bind(local)
+        .bind(remote)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Follow::try_from)
+        .collect()
+    }
+
+    pub async fn find_followings(account: &AccountTypes, con: &mut PgConnection) -> Result<Vec<Follow>, DriverError> {
+        let (local, remote) = columns(account);
+
+        sqlx::query_as::<_, FollowRow>(r#"
+            SELECT * FROM follows
+            WHERE (source_local = $1 OR source_remote = $2) AND accepted
+            ORDER BY created_at DESC
+        "#)
+        .bind(local)
+        .bind(remote)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Follow::try_from)
+        .collect()
+    }
+
+    pub async fn find_requests(account: &AccountTypes, con: &mut PgConnection) -> Result<Vec<Follow>, DriverError> {
+        let (local, remote) = columns(account);
+
+        sqlx::query_as::<_, FollowRow>(r#"
+            SELECT * FROM follows
+            WHERE (destination_local = $1 OR destination_remote = $2) AND NOT accepted
+            ORDER BY created_at ASC
+        "#)
+        .bind(local)
+        .bind(remote)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Follow::try_from)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::account::Internal as AccountDataBaseInternal;
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-20), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-20), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let b_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        let b = Account::new(b_id, "test2", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let a_type = AccountTypes::Local(a_id);
+        let b_type = AccountTypes::Local(b_id);
+        let remote = AccountTypes::Federate("https://remote.example/users/test3".to_string());
+
+        let a_to_b = Follow::new(FollowId::default(), a_type.clone(), b_type.clone(), FollowState::Accepted, created_at);
+        let remote_to_b = Follow::new(FollowId::default(), remote.clone(), b_type.clone(), FollowState::Accepted, created_at);
+        let a_to_remote = Follow::new(FollowId::default(), a_type.clone(), remote.clone(), FollowState::Accepted, created_at);
+
+        Internal::create(&a_to_b, &mut con).await?;
+        Internal::create(&remote_to_b, &mut con).await?;
+        Internal::create(&a_to_remote, &mut con).await?;
+
+        // Following twice keeps the first one.
+        Internal::create(&Follow::new(FollowId::default(), a_type.clone(), b_type.clone(), FollowState::Accepted, created_at), &mut con).await?;
+
+        assert_eq!(Internal::find(&a_type, &b_type, &mut con).await?, Some(a_to_b.clone()));
+        assert_eq!(Internal::find(&b_type, &a_type, &mut con).await?, None);
+
+        let followers = Internal::find_followers(&b_type, &mut con).await?;
+        assert_eq!(followers.len(), 2);
+        assert!(followers.contains(&a_to_b));
+        assert!(followers.contains(&remote_to_b));
+
+        let followings = Internal::find_followings(&a_type, &mut con).await?;
+        assert_eq!(followings.len(), 2);
+        assert!(followings.contains(&a_to_remote));
+
+        Internal::delete(&a_type, &b_type, &mut con).await?;
+        assert_eq!(Internal::find(&a_type, &b_type, &mut con).await?, None);
+        assert_eq!(Internal::find_followers(&b_type, &mut con).await?, vec![remote_to_b]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_request() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-25), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-25), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let b_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        let b = Account::new(b_id, "test2", false, true,  created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let a_type = AccountTypes::Local(a_id);
+        let b_type = AccountTypes::Local(b_id);
+
+        let pending = Follow::new(FollowId::default(), a_type.clone(), b_type.clone(), FollowState::Pending, created_at);
+        Internal::create(&pending, &mut con).await?;
+
+        assert_eq!(Internal::find_by_id(pending.id(), &mut con).await?, Some(pending.clone()));
+        assert_eq!(Internal::find_requests(&b_type, &mut con).await?, vec![pending.clone()]);
+        assert!(Internal::find_followers(&b_type, &mut con).await?.is_empty());
+        assert!(Internal::find_followings(&a_type, &mut con).await?.is_empty());
+
+        let accepted = Follow::new(*pending.id(), a_type.clone(), b_type.clone(), FollowState::Accepted, created_at);
+        Internal::update(&accepted, &mut con).await?;
+
+        assert!(Internal::find_requests(&b_type, &mut con).await?.is_empty());
+        assert_eq!(Internal::find_followers(&b_type, &mut con).await?, vec![accepted]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
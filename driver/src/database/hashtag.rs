@@ -0,0 +1,146 @@
+use kernel::{
+    repository::HashtagRepository,
+    entities::{Hashtag, HashtagName},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct HashtagDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl HashtagDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl HashtagRepository for HashtagDataBase {
+    async fn upsert(&self, names: &[HashtagName]) -> Result<Vec<Hashtag>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let upserted = Internal::upsert(names, &mut con).await?;
+        Ok(upserted)
+    }
+
+    async fn find_by_name(&self, name: &HashtagName) -> Result<Option<Hashtag>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_name(name, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct HashtagRow {
+    id: Uuid,
+    name: String,
+    created_at: OffsetDateTime
+}
+
+impl From<HashtagRow> for Hashtag {
+    fn from(fetched: HashtagRow) -> Self {
+        Hashtag::new(fetched.id, fetched.name, fetched.created_at)
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn upsert(names: &[HashtagName], con: &mut PgConnection) -> Result<Vec<Hashtag>, DriverError> {
+        let names = names.iter()
+            .map(|name| name.as_ref().to_string())
+            .collect::<Vec<String>>();
+
+        sqlx::query(r#"
+            INSERT INTO hashtags (id, name)
+            SELECT gen_random_uuid(), name FROM UNNEST($1::VARCHAR[]) AS tagged(name)
+            ON CONFLICT (name) DO NOTHING
+        "#)
+        .bind(&names)
+        .execute(&mut *con)
+        .await?;
+
+        let upserted = sqlx::query_as::<_, HashtagRow>(r#"
+            SELECT hashtags.* FROM UNNEST($1::VARCHAR[]) WITH ORDINALITY AS tagged(name, position)
+            JOIN hashtags ON hashtags.name = tagged.name
+            ORDER BY tagged.position
+        "#)
+        .bind(&names)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Hashtag::from)
+        .collect();
+
+        Ok(upserted)
+    }
+
+    pub async fn find_by_name(name: &HashtagName, con: &mut PgConnection) -> Result<Option<Hashtag>, DriverError> {
+        let found = sqlx::query_as::<_, HashtagRow>(r#"
+            SELECT * FROM hashtags WHERE name = $1
+        "#)
+        .bind(name.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Hashtag::from);
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let a = HashtagName::try_from("shuttlepub_test_a")?;
+        let b = HashtagName::try_from("shuttlepub_test_b")?;
+
+        let created = Internal::upsert(std::slice::from_ref(&a), &mut con).await?;
+        assert_eq!(created.len(), 1);
+
+        // Existing tags are returned as they are, in the requested order.
+        let upserted = Internal::upsert(&[b.clone(), a.clone()], &mut con).await?;
+        assert_eq!(upserted.iter().map(Hashtag::name).collect::<Vec<_>>(), vec![&b, &a]);
+        assert_eq!(upserted[1], created[0]);
+
+        assert_eq!(Internal::find_by_name(&a, &mut con).await?, Some(created[0].clone()));
+        assert!(Internal::find_by_name(&HashtagName::new("shuttlepub_test_c"), &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
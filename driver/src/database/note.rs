@@ -0,0 +1,628 @@
+use kernel::{
+    repository::NoteRepository,
+    entities::{AccountId, AccountTypes, HashtagId, Note, NoteId, NoteTypes, Pagination, Visibility},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+use super::timeline::{direction, newest_first};
+
+#[derive(Debug, Clone)]
+pub struct NoteDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl NoteDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl NoteRepository for NoteDataBase {
+    async fn create(&self, create: &Note) -> Result<(), KernelError> {
+        let mut tx = self.pool.begin().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut tx).await?;
+        tx.commit().await
+            .map_err(DriverError::SqlX)?;
+        Ok(())
+    }
+
+    async fn delete(&self, delete: &NoteId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(delete, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &NoteId) -> Result<Option<Note>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_id(id, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_ancestors(&self, id: &NoteId, depth: i64) -> Result<Vec<Note>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_ancestors(id, depth, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_descendants(&self, id: &NoteId, depth: i64) -> Result<Vec<Note>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_descendants(id, depth, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_hashtag(&self, hashtag: &HashtagId, page: &Pagination) -> Result<Vec<Note>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_hashtag(hashtag, page, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn count(&self) -> Result<i64, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let count = Internal::count(&mut con).await?;
+        Ok(count)
+    }
+
+    async fn count_authors(&self, since: &OffsetDateTime) -> Result<i64, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let count = Internal::count_authors(since, &mut con).await?;
+        Ok(count)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub(in crate::database) struct NoteRow {
+    id: Uuid,
+    account: i64,
+    created_at: OffsetDateTime,
+    content: String,
+    cw: Option<String>,
+    visibility: String,
+    media: Vec<Uuid>,
+    hashtags: Vec<Uuid>,
+    reply_local: Option<Uuid>,
+    reply_remote: Option<String>,
+    mention_local: Vec<Option<i64>>,
+    mention_remote: Vec<Option<String>>,
+    quote_local: Option<Uuid>,
+    quote_remote: Option<String>
+}
+
+/// Joins the `(*_local, *_remote)` column pair of a note. `of` names the relation in errors.
+pub(in crate::database) fn note_types(local: Option<Uuid>, remote: Option<String>, of: &str) -> Result<Option<NoteTypes>, DriverError> {
+    match (local, remote) {
+        (Some(id), None) => Ok(Some(NoteTypes::Local(NoteId::new(id)))),
+        (None, Some(url)) => Ok(Some(NoteTypes::Federate(url))),
+        (None, None) => Ok(None),
+        (Some(_), Some(_)) => Err(DriverError::Convert(format!("{} must have exactly one of local or remote note.", of)))
+    }
+}
+
+/// Splits `note` into the `(*_local, *_remote)` column pair. Exactly one is `Some`.
+pub(in crate::database) fn note_columns(note: &NoteTypes) -> (Option<Uuid>, Option<&str>) {
+    match note {
+        NoteTypes::Local(id) => (Some(*id.as_ref()), None),
+        NoteTypes::Federate(url) => (None, Some(url.as_str()))
+    }
+}
+
+impl TryFrom<NoteRow> for Note {
+    type Error = DriverError;
+    fn try_from(fetched: NoteRow) -> Result<Self, Self::Error> {
+        let visibility = Visibility::try_from(fetched.visibility.as_str())
+            .map_err(|e| DriverError::Convert(e.to_string()))?;
+        let in_reply_to = note_types(fetched.reply_local, fetched.reply_remote, "reply")?;
+        let quote_of = note_types(fetched.quote_local, fetched.quote_remote, "quote")?;
+        let mentions = fetched.mention_local.into_iter()
+            .zip(fetched.mention_remote)
+            .map(|mention| match mention {
+                (Some(id), None) => Ok(AccountTypes::Local(AccountId::new(id))),
+                (None, Some(url)) => Ok(AccountTypes::Federate(url)),
+                _ => Err(DriverError::Convert("mention must have exactly one of local or remote account.".to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Note::new(
+            fetched.id,
+            fetched.account,
+            fetched.content,
+            fetched.cw,
+            visibility,
+            in_reply_to,
+            quote_of,
+            mentions,
+            fetched.media,
+            fetched.hashtags,
+            fetched.created_at
+        ))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    /// Also writes the attachments, hashtags, reply, quote and mentions. Callers are expected to run it in a transaction.
+    pub async fn create(create: &Note, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO notes (
+                id,
+                account,
+                created_at,
+                content,
+                cw,
+                visibility
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            );
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.author().as_ref())
+        .bind(create.created_at().as_ref())
+        .bind(create.content().as_ref())
+        .bind(create.cw().map(AsRef::<str>::as_ref))
+        .bind(create.visibility().as_str())
+        .execute(&mut *con)
+        .await?;
+
+        let media = create.media().iter()
+            .map(|id| *id.as_ref())
+            .collect::<Vec<Uuid>>();
+
+        sqlx::query(r#"
+            INSERT INTO note_attachments (note, media, position)
+            SELECT $1, media, position FROM UNNEST($2::UUID[]) WITH ORDINALITY AS attached(media, position)
+        "#)
+        .bind(create.id().as_ref())
+        .bind(media)
+        .execute(&mut *con)
+        .await?;
+
+        let hashtags = create.hashtags().iter()
+            .map(|id| *id.as_ref())
+            .collect::<Vec<Uuid>>();
+
+        sqlx::query(r#"
+            INSERT INTO note_hashtags (note, hashtag)
+            SELECT $1, hashtag FROM UNNEST($2::UUID[]) AS tagged(hashtag)
+        "#)
+        .bind(create.id().as_ref())
+        .bind(hashtags)
+        .execute(&mut *con)
+        .await?;
+
+        if let Some(target) = create.in_reply_to() {
+            let (target_local, target_remote) = note_columns(target);
+
+            sqlx::query(r#"
+                INSERT INTO note_reply (
+                    id,
+                    origin_local,
+                    target_local,
+                    target_remote,
+                    created_at
+                )
+                VALUES (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    $5
+                );
+            "#)
+            .bind(Uuid::new_v4())
+            .bind(create.id().as_ref())
+            .bind(target_local)
+            .bind(target_remote)
+            .bind(create.created_at().as_ref())
+            .execute(&mut *con)
+            .await?;
+        }
+
+        if let Some(target) = create.quote_of() {
+            let (target_local, target_remote) = note_columns(target);
+
+            sqlx::query(r#"
+                INSERT INTO note_turbo_quote (
+                    id,
+                    origin_local,
+                    target_local,
+                    target_remote,
+                    created_at,
+                    implicit
+                )
+                VALUES (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    $5,
+                    FALSE
+                );
+            "#)
+            .bind(Uuid::new_v4())
+            .bind(create.id().as_ref())
+            .bind(target_local)
+            .bind(target_remote)
+            .bind(create.created_at().as_ref())
+            .execute(&mut *con)
+            .await?;
+        }
+
+        let (mention_local, mention_remote) = create.mentions().iter()
+            .map(|mention| match mention {
+                AccountTypes::Local(id) => (Some(*id.as_ref()), None),
+                AccountTypes::Federate(url) => (None, Some(url.clone()))
+            })
+            .unzip::<_, _, Vec<Option<i64>>, Vec<Option<String>>>();
+
+        sqlx::query(r#"
+            INSERT INTO note_mention (id, origin_local, target_local, target_remote, position, created_at)
+            SELECT gen_random_uuid(), $1, target_local, target_remote, position, $4
+            FROM UNNEST($2::BIGINT[], $3::VARCHAR[]) WITH ORDINALITY AS mentioned(target_local, target_remote, position)
+        "#)
+        .bind(create.id().as_ref())
+        .bind(mention_local)
+        .bind(mention_remote)
+        .bind(create.created_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(delete: &NoteId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM notes WHERE id = $1
+        "#)
+        .bind(delete.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_id(id: &NoteId, con: &mut PgConnection) -> Result<Option<Note>, DriverError> {
+        sqlx::query_as::<_, NoteRow>(r#"
+            SELECT * FROM note_details WHERE id = $1
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Note::try_from)
+        .transpose()
+    }
+
+    pub async fn find_ancestors(id: &NoteId, depth: i64, con: &mut PgConnection) -> Result<Vec<Note>, DriverError> {
+        sqlx::query_as::<_, NoteRow>(r#"
+            WITH RECURSIVE ancestors (id, depth) AS (
+                SELECT target_local, 1 FROM note_reply
+                WHERE origin_local = $1 AND target_local IS NOT NULL
+              UNION ALL
+                SELECT note_reply.target_local, ancestors.depth + 1 FROM note_reply
+                JOIN ancestors ON note_reply.origin_local = ancestors.id
+                WHERE note_reply.target_local IS NOT NULL AND ancestors.depth < $2
+            )
+            SELECT note_details.* FROM ancestors
+            JOIN note_details ON note_details.id = ancestors.id
+            ORDER BY ancestors.depth DESC
+        "#)
+        .bind(id.as_ref())
+        .bind(depth)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Note::try_from)
+        .collect()
+    }
+
+    pub async fn find_descendants(id: &NoteId, depth: i64, con: &mut PgConnection) -> Result<Vec<Note>, DriverError> {
+        sqlx::query_as::<_, NoteRow>(r#"
+            WITH RECURSIVE descendants (id, depth) AS (
+                SELECT origin_local, 1 FROM note_reply
+                WHERE target_local = $1
+              UNION ALL
+                SELECT note_reply.origin_local, descendants.depth + 1 FROM note_reply
+                JOIN descendants ON note_reply.target_local = descendants.id
+                WHERE descendants.depth < $2
+            )
+            SELECT note_details.* FROM descendants
+            JOIN note_details ON note_details.id = descendants.id
+            ORDER BY note_details.created_at ASC, note_details.id ASC
+        "#)
+        .bind(id.as_ref())
+        .bind(depth)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Note::try_from)
+        .collect()
+    }
+
+    pub async fn find_by_hashtag(hashtag: &HashtagId, page: &Pagination, con: &mut PgConnection) -> Result<Vec<Note>, DriverError> {
+        let found = sqlx::query_as::<_, NoteRow>(&format!(r#"
+            SELECT note_details.* FROM note_hashtags
+            JOIN note_details ON note_details.id = note_hashtags.note
+            WHERE note_hashtags.hashtag = $1
+              AND note_details.visibility = $2
+              AND ($3::UUID IS NULL OR (note_details.created_at, note_details.id) < (SELECT created_at, id FROM notes WHERE id = $3))
+              AND ($4::UUID IS NULL OR (note_details.created_at, note_details.id) > (SELECT created_at, id FROM notes WHERE id = $4))
+            ORDER BY note_details.created_at {order}, note_details.id {order}
+            LIMIT $5
+        "#, order = direction(page)))
+        .bind(hashtag.as_ref())
+        .bind(Visibility::Public.as_str())
+        .bind(page.max_id())
+        .bind(page.newer_than())
+        .bind(page.limit())
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(Note::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+        Ok(newest_first(page, found))
+    }
+
+    pub async fn count(con: &mut PgConnection) -> Result<i64, DriverError> {
+        let (count,): (i64,) = sqlx::query_as(r#"
+            SELECT COUNT(*) FROM notes
+        "#)
+        .fetch_one(&mut *con)
+        .await?;
+
+        Ok(count)
+    }
+
+    pub async fn count_authors(since: &OffsetDateTime, con: &mut PgConnection) -> Result<i64, DriverError> {
+        let (count,): (i64,) = sqlx::query_as(r#"
+            SELECT COUNT(DISTINCT account) FROM notes WHERE created_at >= $1
+        "#)
+        .bind(since)
+        .fetch_one(&mut *con)
+        .await?;
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use uuid::Uuid;
+    use crate::database::account::Internal as AccountDataBaseInternal;
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-30), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-30), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let media = [Uuid::new_v4(), Uuid::new_v4()];
+        for id in media {
+            sqlx::query("INSERT INTO note_media (id, sensitive) VALUES ($1, false)")
+                .bind(id)
+                .execute(&mut *con)
+                .await?;
+        }
+
+        let hashtag = Uuid::new_v4();
+        sqlx::query("INSERT INTO hashtags (id, name) VALUES ($1, 'shuttlepub_test')")
+            .bind(hashtag)
+            .execute(&mut *con)
+            .await?;
+
+        let b_id = AccountId::default();
+        let b = Account::new(b_id, "test2", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let mentions = [
+            AccountTypes::Federate("https://remote.example/users/test3".to_string()),
+            AccountTypes::Local(b_id)
+        ];
+
+        let a_note = Note::new(NoteId::default(), a_id, "Hello", None::<String>, Visibility::Public, None, None, mentions.clone(), media, [hashtag], created_at);
+        let b_note = Note::new(NoteId::default(), a_id, "World", Some("cw"), Visibility::FollowersOnly, None, Some(NoteTypes::Local(*a_note.id())), [], Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at);
+
+        Internal::create(&a_note, &mut con).await?;
+        Internal::create(&b_note, &mut con).await?;
+
+        assert_eq!(Internal::find_by_id(a_note.id(), &mut con).await?, Some(a_note.clone()));
+        assert_eq!(Internal::find_by_id(b_note.id(), &mut con).await?, Some(b_note.clone()));
+
+        // A mentioned account going away drops the mention, not the note.
+        AccountDataBaseInternal::delete(&b_id, &mut con).await?;
+        let found = Internal::find_by_id(a_note.id(), &mut con).await?.unwrap();
+        assert_eq!(found.mentions(), &mentions[..1]);
+
+        Internal::delete(a_note.id(), &mut con).await?;
+        assert!(Internal::find_by_id(a_note.id(), &mut con).await?.is_none());
+
+        // A quote outlives the quoted note.
+        let quoted = Internal::find_by_id(b_note.id(), &mut con).await?.unwrap();
+        assert!(quoted.quote_of().is_none());
+
+        // Notes go away with their author.
+        AccountDataBaseInternal::delete(&a_id, &mut con).await?;
+        assert!(Internal::find_by_id(b_note.id(), &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_thread() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-4-10), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-4-10), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let note = |reply: Option<NoteTypes>, minutes: i64| Note::new(
+            NoteId::default(), a_id, "thread", None::<String>, Visibility::Public, reply, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at + time::Duration::minutes(minutes)
+        );
+
+        // root <- a <- b, root <- c, and remote <- root.
+        let remote = NoteTypes::Federate("https://remote.example/notes/1".to_string());
+        let root = note(Some(remote), 0);
+        let a_reply = note(Some(NoteTypes::Local(*root.id())), 1);
+        let b_reply = note(Some(NoteTypes::Local(*a_reply.id())), 2);
+        let c_reply = note(Some(NoteTypes::Local(*root.id())), 3);
+
+        for created in [&root, &a_reply, &b_reply, &c_reply] {
+            Internal::create(created, &mut con).await?;
+        }
+
+        assert_eq!(Internal::find_by_id(root.id(), &mut con).await?, Some(root.clone()));
+        assert_eq!(Internal::find_ancestors(b_reply.id(), 10, &mut con).await?, vec![root.clone(), a_reply.clone()]);
+        assert_eq!(Internal::find_ancestors(b_reply.id(), 1, &mut con).await?, vec![a_reply.clone()]);
+        assert_eq!(Internal::find_descendants(root.id(), 10, &mut con).await?, vec![a_reply.clone(), b_reply.clone(), c_reply.clone()]);
+        assert_eq!(Internal::find_descendants(root.id(), 1, &mut con).await?, vec![a_reply, c_reply]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_hashtag() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-4-20), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-4-20), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let hashtag = HashtagId::new(Uuid::new_v4());
+        sqlx::query("INSERT INTO hashtags (id, name) VALUES ($1, 'shuttlepub_test')")
+            .bind(hashtag.as_ref())
+            .execute(&mut *con)
+            .await?;
+
+        let note = |visibility: Visibility, minutes: i64| Note::new(
+            NoteId::default(), a_id, "#shuttlepub_test", None::<String>, visibility, None, None, [],
+            Vec::<Uuid>::new(), [hashtag], created_at + time::Duration::minutes(minutes)
+        );
+
+        let a_note = note(Visibility::Public, 0);
+        let b_note = note(Visibility::Unlisted, 1);
+        let c_note = note(Visibility::Public, 2);
+        let d_note = note(Visibility::Public, 3);
+
+        for created in [&a_note, &b_note, &c_note, &d_note] {
+            Internal::create(created, &mut con).await?;
+        }
+
+        // Only public notes are listed, newest first.
+        let page = Pagination::default();
+        assert_eq!(Internal::find_by_hashtag(&hashtag, &page, &mut con).await?, vec![d_note.clone(), c_note.clone(), a_note.clone()]);
+
+        let page = Pagination::new(None, None, None, Some(2));
+        assert_eq!(Internal::find_by_hashtag(&hashtag, &page, &mut con).await?, vec![d_note.clone(), c_note.clone()]);
+
+        let page = Pagination::new(Some(*c_note.id().as_ref()), None, None, Some(2));
+        assert_eq!(Internal::find_by_hashtag(&hashtag, &page, &mut con).await?, vec![a_note.clone()]);
+
+        // `since_id` keeps the newest notes, `min_id` those right after it.
+        let page = Pagination::new(None, Some(*a_note.id().as_ref()), None, Some(1));
+        assert_eq!(Internal::find_by_hashtag(&hashtag, &page, &mut con).await?, vec![d_note.clone()]);
+
+        let page = Pagination::new(None, None, Some(*a_note.id().as_ref()), Some(2));
+        assert_eq!(Internal::find_by_hashtag(&hashtag, &page, &mut con).await?, vec![d_note, c_note]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_count() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        // Far enough in the future that no other note is counted as recent.
+        let since = PrimitiveDateTime::new(date!(2100-1-1), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let b_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, since, since);
+        let b = Account::new(b_id, "test2", false, false, since, since);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let note = |account: AccountId, created_at: time::OffsetDateTime| Note::new(
+            NoteId::default(), account, "count", None::<String>, Visibility::Public, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at
+        );
+
+        let before = Internal::count(&mut con).await?;
+
+        Internal::create(&note(a_id, since), &mut con).await?;
+        Internal::create(&note(a_id, since + time::Duration::days(1)), &mut con).await?;
+        Internal::create(&note(b_id, since - time::Duration::days(1)), &mut con).await?;
+
+        assert_eq!(Internal::count(&mut con).await?, before + 3);
+        assert_eq!(Internal::count_authors(&since, &mut con).await?, 1);
+        assert_eq!(Internal::count_authors(&(since - time::Duration::days(1)), &mut con).await?, 2);
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
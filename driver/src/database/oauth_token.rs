@@ -0,0 +1,217 @@
+use kernel::{
+    repository::OAuthTokenRepository,
+    entities::{OAuthToken, AccessToken, AccountId, ApplicationId, Scopes},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct OAuthTokenDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl OAuthTokenDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl OAuthTokenRepository for OAuthTokenDataBase {
+    async fn create(&self, create: &OAuthToken) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, delete: &AccessToken) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(delete, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete_all_by_account(account, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_token(&self, token: &AccessToken) -> Result<Option<OAuthToken>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_token(token, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct OAuthTokenRow {
+    token: String,
+    application: Uuid,
+    account: i64,
+    scopes: Vec<String>,
+    created_at: OffsetDateTime
+}
+
+impl From<OAuthTokenRow> for OAuthToken {
+    fn from(fetched: OAuthTokenRow) -> Self {
+        OAuthToken::new(
+            fetched.token,
+            ApplicationId::new(fetched.application),
+            fetched.account,
+            Scopes::new(fetched.scopes),
+            fetched.created_at
+        )
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &OAuthToken, con: &mut PgConnection) -> Result<(), DriverError> {
+        let scopes = create.scopes().iter()
+            .map(|scope| scope.as_ref())
+            .collect::<Vec<&str>>();
+
+        sqlx::query(r#"
+            INSERT INTO oauth_tokens (
+                token,
+                application,
+                account,
+                scopes,
+                created_at
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5
+            );
+        "#)
+        .bind(create.token().as_ref())
+        .bind(create.application().as_ref())
+        .bind(create.account().as_ref())
+        .bind(scopes)
+        .bind(create.created_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(delete: &AccessToken, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM oauth_tokens WHERE token = $1
+        "#)
+        .bind(delete.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_all_by_account(account: &AccountId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM oauth_tokens WHERE account = $1
+        "#)
+        .bind(account.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_token(token: &AccessToken, con: &mut PgConnection) -> Result<Option<OAuthToken>, DriverError> {
+        let found = sqlx::query_as::<_, OAuthTokenRow>(r#"
+            SELECT * FROM oauth_tokens WHERE token = $1
+        "#)
+        .bind(token.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(OAuthToken::from);
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::{account::Internal as AccountDataBaseInternal, application::Internal as ApplicationDataBaseInternal};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-5), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-5), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let app_id = ApplicationId::default();
+        let app = Application::new(
+            app_id,
+            created_at, updated_at,
+            "Test Client",
+            None::<String>,
+            ClientId::default(),
+            ClientSecret::default(),
+            ["https://example.com/callback"],
+            Scopes::all()
+        );
+        ApplicationDataBaseInternal::create(&app, &mut con).await?;
+
+        let a_token = OAuthToken::new(AccessToken::default(), app_id, a_id, Scopes::try_from("read")?, created_at);
+        let b_token = OAuthToken::new(AccessToken::default(), app_id, a_id, Scopes::try_from("write")?, created_at);
+
+        Internal::create(&a_token, &mut con).await?;
+        Internal::create(&b_token, &mut con).await?;
+
+        let fetched = Internal::find_by_token(a_token.token(), &mut con).await?.unwrap();
+        assert_eq!(fetched, a_token);
+
+        Internal::delete(a_token.token(), &mut con).await?;
+        assert!(Internal::find_by_token(a_token.token(), &mut con).await?.is_none());
+
+        Internal::delete_all_by_account(&a_id, &mut con).await?;
+        assert!(Internal::find_by_token(b_token.token(), &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+use kernel::{
+    repository::PasswordResetRepository,
+    entities::{PasswordReset, PasswordResetToken, AccountId},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct PasswordResetDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl PasswordResetDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordResetRepository for PasswordResetDataBase {
+    async fn create(&self, create: &PasswordReset) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete_all_by_account(account, &mut con).await?;
+        Ok(())
+    }
+
+    async fn take(&self, token: &PasswordResetToken) -> Result<Option<PasswordReset>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let taken = Internal::take(token, &mut con).await?;
+        Ok(taken)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct PasswordResetRow {
+    token: String,
+    account: i64,
+    created_at: OffsetDateTime,
+    expires_at: OffsetDateTime
+}
+
+impl From<PasswordResetRow> for PasswordReset {
+    fn from(fetched: PasswordResetRow) -> Self {
+        PasswordReset::new(
+            fetched.token,
+            fetched.account,
+            fetched.created_at,
+            fetched.expires_at
+        )
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &PasswordReset, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO password_resets (
+                token,
+                account,
+                created_at,
+                expires_at
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4
+            );
+        "#)
+        .bind(create.token().as_ref())
+        .bind(create.account().as_ref())
+        .bind(create.created_at().as_ref())
+        .bind(create.expires_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete_all_by_account(account: &AccountId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM password_resets WHERE account = $1
+        "#)
+        .bind(account.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn take(token: &PasswordResetToken, con: &mut PgConnection) -> Result<Option<PasswordReset>, DriverError> {
+        let taken = sqlx::query_as::<_, PasswordResetRow>(r#"
+            DELETE FROM password_resets WHERE token = $1 RETURNING *
+        "#)
+        .bind(token.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(PasswordReset::from);
+
+        Ok(taken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use crate::database::account::Internal as AccountDataBaseInternal;
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-3-10), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-3-10), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let a_reset = PasswordReset::new(PasswordResetToken::default(), a_id, created_at, created_at + PasswordReset::LIFETIME);
+        let b_reset = PasswordReset::new(PasswordResetToken::default(), a_id, created_at, created_at + PasswordReset::LIFETIME);
+
+        Internal::create(&a_reset, &mut con).await?;
+        Internal::create(&b_reset, &mut con).await?;
+
+        let taken = Internal::take(a_reset.token(), &mut con).await?.unwrap();
+        assert_eq!(taken, a_reset);
+
+        // A token can be used only once.
+        assert!(Internal::take(a_reset.token(), &mut con).await?.is_none());
+
+        Internal::delete_all_by_account(&a_id, &mut con).await?;
+        assert!(Internal::take(b_reset.token(), &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -9,6 +9,8 @@ use uuid::Uuid;
 
 use crate::DriverError;
 
+const ACCOUNT_INDEX: &str = "profiles_account_idx";
+
 #[derive(Debug, Clone)]
 pub struct ProfileDataBase {
     pool: Pool<Postgres>
@@ -26,7 +28,14 @@ impl ProfileRepository for ProfileDataBase {
     async fn create(&self, create: &Profile) -> Result<(), KernelError> {
         let mut con = self.pool.acquire().await
             .map_err(DriverError::SqlX)?;
-        Internal::create(create, &mut con).await?;
+        Internal::create(create, &mut con).await
+            .map_err(|e| match &e {
+                DriverError::SqlX(sqlx::Error::Database(db)) if db.constraint() == Some(ACCOUNT_INDEX) => KernelError::Conflict {
+                    entity: "profile",
+                    id: create.account().as_ref().to_string()
+                },
+                _ => e.into()
+            })?;
         Ok(())
     }
     
@@ -54,8 +63,8 @@ struct ProfileRow {
     pub account: i64,
     pub display_name: String,
     pub summary: String,
-    pub icon: String,
-    pub banner: String
+    pub icon: Option<String>,
+    pub banner: Option<String>
 }
 
 pub(in crate::database) struct Internal;
@@ -90,8 +99,8 @@ impl Internal {
         .bind(create.account().as_ref())
         .bind(create.name().as_ref())
         .bind(create.summary().as_ref())
-        .bind(create.icon().as_ref())
-        .bind(create.banner().as_ref())
+        .bind(create.icon().map(AsRef::<str>::as_ref))
+        .bind(create.banner().map(AsRef::<str>::as_ref))
         .execute(&mut *con)
         .await?;
 
@@ -112,8 +121,8 @@ impl Internal {
         .bind(update.date().updated_at().as_ref())
         .bind(update.name().as_ref())
         .bind(update.summary().as_ref())
-        .bind(update.icon().as_ref())
-        .bind(update.banner().as_ref())
+        .bind(update.icon().map(AsRef::<str>::as_ref))
+        .bind(update.banner().map(AsRef::<str>::as_ref))
         .bind(update.id().as_ref())
         .execute(&mut *con)
         .await?;
@@ -154,7 +163,9 @@ mod tests {
     use time_macros::{date, time};
     use crate::database::{account::Internal as AccountDataBaseInternal, profile::ProfileRow};
 
-    use super::Internal;
+    use crate::DriverError;
+
+    use super::{Internal, ACCOUNT_INDEX};
 
     async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
         dotenvy::dotenv().ok();
@@ -184,7 +195,7 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
 
         AccountDataBaseInternal::create(&a, &mut con).await?;
 
@@ -195,8 +206,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man A", 
             "野生のテストマンAが現れた!", 
-            format!("cdn.example.dev/icon/{}", a_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", a_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", a_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", a_id.as_ref()))
         );
 
         Internal::create(&a_prof, &mut con).await?;
@@ -204,6 +215,18 @@ mod tests {
         let a_prof_in_db = Internal::find_by_account_id(&a_id, &mut con).await?.unwrap();
         println!("{:#?}", a_prof_in_db);
 
+        let b_prof = Profile::new(
+            ProfileId::default(), a_id, 
+            created_at, updated_at, 
+            "Test Man B", 
+            "野生のテストマンBが現れた!", 
+            None::<String>, 
+            None::<String>
+        );
+
+        let duplicate = Internal::create(&b_prof, &mut con).await;
+        assert!(matches!(duplicate, Err(DriverError::SqlX(sqlx::Error::Database(db))) if db.constraint() == Some(ACCOUNT_INDEX)));
+
         con.rollback().await?;
 
         Ok(())
@@ -223,7 +246,7 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
 
         AccountDataBaseInternal::create(&a, &mut con).await?;
 
@@ -234,8 +257,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man A", 
             "野生のテストマンAが現れた!", 
-            format!("cdn.example.dev/icon/{}", a_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", a_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", a_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", a_id.as_ref()))
         );
 
         Internal::create(&a_prof, &mut con).await?;
@@ -248,8 +271,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man A", 
             "野生のテストマンAは逃げた!", 
-            format!("cdn.example.dev/icon/{}", a_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", a_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", a_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", a_id.as_ref()))
         );
 
         Internal::update(&a_prof, &mut con).await?;
@@ -280,9 +303,9 @@ mod tests {
         let created_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
         let updated_at = PrimitiveDateTime::new(date!(2023-2-20), time!(0:00)).assume_utc();
 
-        let a = Account::new(a_id, a_name.clone(), false, created_at, updated_at);
-        let b = Account::new(b_id, b_name.clone(), false, created_at, updated_at);
-        let c = Account::new(c_id, c_name.clone(), true,  created_at, updated_at);
+        let a = Account::new(a_id, a_name.clone(), false, false, created_at, updated_at);
+        let b = Account::new(b_id, b_name.clone(), false, false, created_at, updated_at);
+        let c = Account::new(c_id, c_name.clone(), true, false,  created_at, updated_at);
 
         
         AccountDataBaseInternal::create(&a, &mut con).await?;
@@ -298,8 +321,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man A", 
             "野生のテストマンAが現れた!", 
-            format!("cdn.example.dev/icon/{}", a_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", a_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", a_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", a_id.as_ref()))
         );
 
         let b_prof = Profile::new(
@@ -307,8 +330,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man B", 
             "野生のテストマンBが現れた!", 
-            format!("cdn.example.dev/icon/{}", b_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", b_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", b_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", b_id.as_ref()))
         );
 
         let c_prof = Profile::new(
@@ -316,8 +339,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man C", 
             "野生のテストマンCが現れた!", 
-            format!("cdn.example.dev/icon/{}", c_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", c_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", c_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", c_id.as_ref()))
         );
 
         Internal::create(&a_prof, &mut con).await?;
@@ -334,8 +357,8 @@ mod tests {
             created_at, updated_at, 
             "Test Man A", 
             "野生のテストマンAは逃げた!", 
-            format!("cdn.example.dev/icon/{}", a_id.as_ref()), 
-            format!("cdn.example.dev/banner/{}", a_id.as_ref())
+            Some(format!("https://cdn.example.dev/icon/{}", a_id.as_ref())), 
+            Some(format!("https://cdn.example.dev/banner/{}", a_id.as_ref()))
         );
 
         Internal::update(&a_prof, &mut con).await?;
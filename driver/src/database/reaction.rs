@@ -0,0 +1,306 @@
+use kernel::{
+    repository::ReactionRepository,
+    entities::{AccountId, AccountTypes, Emoji, NoteId, Reaction, ReactionAssetId, ReactionContent, ReactionCount},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct ReactionDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl ReactionDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactionRepository for ReactionDataBase {
+    async fn create(&self, create: &Reaction) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, note: &NoteId, account: &AccountTypes) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(note, account, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find(&self, note: &NoteId, account: &AccountTypes) -> Result<Option<Reaction>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find(note, account, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn count(&self, note: &NoteId) -> Result<Vec<ReactionCount>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::count(note, &mut con).await?;
+        Ok(found)
+    }
+}
+
+/// Splits `account` into the `(account_local, account_remote)` column pair. Exactly one is `Some`.
+fn account_columns(account: &AccountTypes) -> (Option<i64>, Option<&str>) {
+    match account {
+        AccountTypes::Local(id) => (Some(*id.as_ref()), None),
+        AccountTypes::Federate(url) => (None, Some(url.as_str()))
+    }
+}
+
+fn columns(content: &ReactionContent) -> (Option<&str>, Option<&Uuid>) {
+    match content {
+        ReactionContent::Emoji(emoji) => (Some(emoji.as_ref()), None),
+        ReactionContent::Asset(asset) => (None, Some(asset.as_ref()))
+    }
+}
+
+fn reaction_content(emoji: Option<String>, asset: Option<Uuid>) -> Result<ReactionContent, DriverError> {
+    match (emoji, asset) {
+        (Some(emoji), None) => Ok(ReactionContent::Emoji(Emoji::new(emoji))),
+        (None, Some(asset)) => Ok(ReactionContent::Asset(ReactionAssetId::new(asset))),
+        _ => Err(DriverError::Convert("reaction must have exactly one of emoji or asset.".to_string()))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ReactionRow {
+    id: Uuid,
+    note: Uuid,
+    account_local: Option<i64>,
+    account_remote: Option<String>,
+    emoji: Option<String>,
+    asset: Option<Uuid>,
+    created_at: OffsetDateTime
+}
+
+impl TryFrom<ReactionRow> for Reaction {
+    type Error = DriverError;
+    fn try_from(fetched: ReactionRow) -> Result<Self, Self::Error> {
+        let account = match (fetched.account_local, fetched.account_remote) {
+            (Some(id), None) => AccountTypes::Local(AccountId::new(id)),
+            (None, Some(url)) => AccountTypes::Federate(url),
+            _ => return Err(DriverError::Convert("reaction must have exactly one of local or remote account.".to_string()))
+        };
+        Ok(Reaction::new(
+            fetched.id,
+            fetched.note,
+            account,
+            reaction_content(fetched.emoji, fetched.asset)?,
+            fetched.created_at
+        ))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ReactionCountRow {
+    emoji: Option<String>,
+    asset: Option<Uuid>,
+    count: i64
+}
+
+impl TryFrom<ReactionCountRow> for ReactionCount {
+    type Error = DriverError;
+    fn try_from(fetched: ReactionCountRow) -> Result<Self, Self::Error> {
+        Ok(ReactionCount::new(reaction_content(fetched.emoji, fetched.asset)?, fetched.count))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Reaction, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (account_local, account_remote) = account_columns(create.account());
+        let (emoji, asset) = columns(create.content());
+
+        sqlx::query(r#"
+            INSERT INTO note_reaction (
+                id,
+                note,
+                account_local,
+                account_remote,
+                emoji,
+                asset,
+                created_at
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7
+            )
+            ON CONFLICT (note, COALESCE(account_local::TEXT, account_remote)) DO UPDATE SET
+                id = EXCLUDED.id,
+                emoji = EXCLUDED.emoji,
+                asset = EXCLUDED.asset,
+                created_at = EXCLUDED.created_at
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.note().as_ref())
+        .bind(account_local)
+        .bind(account_remote)
+        .bind(emoji)
+        .bind(asset)
+        .bind(create.created_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(note: &NoteId, account: &AccountTypes, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (account_local, account_remote) = account_columns(account);
+
+        sqlx::query(r#"
+            DELETE FROM note_reaction
+            WHERE note = $1 AND (account_local = $2 OR account_remote = $3)
+        "#)
+        .bind(note.as_ref())
+        .bind(account_local)
+        .bind(account_remote)
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find(note: &NoteId, account: &AccountTypes, con: &mut PgConnection) -> Result<Option<Reaction>, DriverError> {
+        let (account_local, account_remote) = account_columns(account);
+
+        sqlx::query_as::<_, ReactionRow>(r#"
+            SELECT * FROM note_reaction
+            WHERE note = $1 AND (account_local = $2 OR account_remote = $3)
+        "#)
+        .bind(note.as_ref())
+        .bind(account_local)
+        .bind(account_remote)
+        .fetch_optional(&mut *con)
+        .await?
+        .map(Reaction::try_from)
+        .transpose()
+    }
+
+    pub async fn count(note: &NoteId, con: &mut PgConnection) -> Result<Vec<ReactionCount>, DriverError> {
+        sqlx::query_as::<_, ReactionCountRow>(r#"
+            SELECT emoji, asset, COUNT(*) AS count FROM note_reaction
+            WHERE note = $1
+            GROUP BY emoji, asset
+            ORDER BY count DESC, MIN(created_at) ASC
+        "#)
+        .bind(note.as_ref())
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(ReactionCount::try_from)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use uuid::Uuid;
+    use crate::database::{
+        account::Internal as AccountDataBaseInternal,
+        note::Internal as NoteDataBaseInternal,
+        reaction_asset::Internal as ReactionAssetDataBaseInternal
+    };
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-4-25), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-4-25), time!(0:00)).assume_utc();
+
+        let accounts = [AccountId::default(), AccountId::default(), AccountId::default()];
+        for (i, id) in accounts.iter().enumerate() {
+            let account = Account::new(*id, format!("test{}", i + 1), false, false, created_at, updated_at);
+            AccountDataBaseInternal::create(&account, &mut con).await?;
+        }
+
+        let note = Note::new(NoteId::default(), accounts[0], "Hello", None::<String>, Visibility::Public, None, None, [], Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at);
+        NoteDataBaseInternal::create(&note, &mut con).await?;
+
+        let asset = ReactionAsset::new(ReactionAssetId::default(), "shuttlepub_test", "https://cdn.example/shuttlepub_test.png", License::new(None::<String>, None::<String>), created_at);
+        ReactionAssetDataBaseInternal::create(&asset, &mut con).await?;
+
+        let like = ReactionContent::Emoji(Emoji::new("👍"));
+        let custom = ReactionContent::Asset(*asset.id());
+
+        let reaction = |account: AccountTypes, content: &ReactionContent| Reaction::new(ReactionId::default(), *note.id(), account, content.clone(), created_at);
+        let accounts = accounts.map(AccountTypes::Local);
+
+        let a_reaction = reaction(accounts[0].clone(), &like);
+        Internal::create(&a_reaction, &mut con).await?;
+        Internal::create(&reaction(accounts[1].clone(), &like), &mut con).await?;
+        Internal::create(&reaction(accounts[2].clone(), &like), &mut con).await?;
+        assert_eq!(Internal::find(note.id(), &accounts[0], &mut con).await?, Some(a_reaction));
+
+        // Reacting again replaces the previous reaction.
+        let c_reaction = reaction(accounts[2].clone(), &custom);
+        Internal::create(&c_reaction, &mut con).await?;
+        assert_eq!(Internal::find(note.id(), &accounts[2], &mut con).await?, Some(c_reaction));
+
+        // Remote actors react the same way, keyed by their actor id.
+        let remote = AccountTypes::Federate("https://remote.example/users/shuttle".to_string());
+        Internal::create(&reaction(remote.clone(), &like), &mut con).await?;
+        Internal::create(&reaction(remote.clone(), &like), &mut con).await?;
+        assert!(Internal::find(note.id(), &remote, &mut con).await?.is_some());
+
+        assert_eq!(Internal::count(note.id(), &mut con).await?, vec![
+            ReactionCount::new(like.clone(), 3),
+            ReactionCount::new(custom, 1)
+        ]);
+
+        Internal::delete(note.id(), &accounts[0], &mut con).await?;
+        assert!(Internal::find(note.id(), &accounts[0], &mut con).await?.is_none());
+
+        // Removing the asset removes the reactions using it.
+        ReactionAssetDataBaseInternal::delete(asset.id(), &mut con).await?;
+        Internal::delete(note.id(), &remote, &mut con).await?;
+        assert_eq!(Internal::count(note.id(), &mut con).await?, vec![ReactionCount::new(like, 1)]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,250 @@
+use kernel::{
+    repository::ReactionAssetRepository,
+    entities::{License, ReactionAlias, ReactionAsset, ReactionAssetId},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct ReactionAssetDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl ReactionAssetDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReactionAssetRepository for ReactionAssetDataBase {
+    async fn create(&self, create: &ReactionAsset) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, delete: &ReactionAssetId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(delete, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<ReactionAsset>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_all(&mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_id(&self, id: &ReactionAssetId) -> Result<Option<ReactionAsset>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_id(id, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_ids(&self, ids: &[ReactionAssetId]) -> Result<Vec<ReactionAsset>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_ids(ids, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_alias(&self, alias: &ReactionAlias) -> Result<Option<ReactionAsset>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_alias(alias, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct ReactionAssetRow {
+    id: Uuid,
+    alias: String,
+    asset: String,
+    created_at: OffsetDateTime,
+    license_url: Option<String>,
+    license_spdx: Option<String>
+}
+
+impl From<ReactionAssetRow> for ReactionAsset {
+    fn from(fetched: ReactionAssetRow) -> Self {
+        ReactionAsset::new(
+            fetched.id,
+            fetched.alias,
+            fetched.asset,
+            License::new(fetched.license_url, fetched.license_spdx),
+            fetched.created_at
+        )
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &ReactionAsset, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            INSERT INTO reaction_asset (
+                id,
+                alias,
+                asset,
+                created_at,
+                license_url,
+                license_spdx
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6
+            )
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.alias().as_ref())
+        .bind(create.asset().as_ref())
+        .bind(create.created_at().as_ref())
+        .bind(create.license().url().map(AsRef::<str>::as_ref))
+        .bind(create.license().spdx())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(delete: &ReactionAssetId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM reaction_asset WHERE id = $1
+        "#)
+        .bind(delete.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn find_all(con: &mut PgConnection) -> Result<Vec<ReactionAsset>, DriverError> {
+        let found = sqlx::query_as::<_, ReactionAssetRow>(r#"
+            SELECT * FROM reaction_asset ORDER BY alias
+        "#)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(ReactionAsset::from)
+        .collect();
+
+        Ok(found)
+    }
+
+    pub async fn find_by_id(id: &ReactionAssetId, con: &mut PgConnection) -> Result<Option<ReactionAsset>, DriverError> {
+        let found = sqlx::query_as::<_, ReactionAssetRow>(r#"
+            SELECT * FROM reaction_asset WHERE id = $1
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(ReactionAsset::from);
+
+        Ok(found)
+    }
+
+    pub async fn find_by_ids(ids: &[ReactionAssetId], con: &mut PgConnection) -> Result<Vec<ReactionAsset>, DriverError> {
+        let ids = ids.iter()
+            .map(|id| *id.as_ref())
+            .collect::<Vec<Uuid>>();
+
+        let found = sqlx::query_as::<_, ReactionAssetRow>(r#"
+            SELECT * FROM reaction_asset WHERE id = ANY($1)
+        "#)
+        .bind(ids)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(ReactionAsset::from)
+        .collect();
+
+        Ok(found)
+    }
+
+    pub async fn find_by_alias(alias: &ReactionAlias, con: &mut PgConnection) -> Result<Option<ReactionAsset>, DriverError> {
+        let found = sqlx::query_as::<_, ReactionAssetRow>(r#"
+            SELECT * FROM reaction_asset WHERE alias = $1
+        "#)
+        .bind(alias.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(ReactionAsset::from);
+
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-4-25), time!(0:00)).assume_utc();
+
+        let license = License::new(Some("https://creativecommons.org/licenses/by/4.0/"), Some("CC-BY-4.0"));
+        let asset = ReactionAsset::new(ReactionAssetId::default(), "shuttlepub_test", "https://cdn.example/shuttlepub_test.png", license, created_at);
+
+        Internal::create(&asset, &mut con).await?;
+
+        assert_eq!(Internal::find_by_id(asset.id(), &mut con).await?, Some(asset.clone()));
+        assert_eq!(Internal::find_by_alias(asset.alias(), &mut con).await?, Some(asset.clone()));
+        assert_eq!(Internal::find_by_ids(&[*asset.id(), ReactionAssetId::default()], &mut con).await?, vec![asset.clone()]);
+        assert!(Internal::find_all(&mut con).await?.contains(&asset));
+
+        // Aliases are unique.
+        let duplicated = ReactionAsset::new(ReactionAssetId::default(), "shuttlepub_test", "https://cdn.example/other.png", License::new(None::<String>, None::<String>), created_at);
+        assert!(Internal::create(&duplicated, &mut con).await.is_err());
+
+        con.rollback().await?;
+        let mut con = pool.begin().await?;
+
+        Internal::create(&asset, &mut con).await?;
+        Internal::delete(asset.id(), &mut con).await?;
+        assert!(Internal::find_by_id(asset.id(), &mut con).await?.is_none());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
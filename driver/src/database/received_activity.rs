@@ -0,0 +1,107 @@
+use kernel::{
+    repository::ReceivedActivityRepository,
+    entities::ActivityId,
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+
+use crate::DriverError;
+
+#[derive(Debug, Clone)]
+pub struct ReceivedActivityDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl ReceivedActivityDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReceivedActivityRepository for ReceivedActivityDataBase {
+    async fn claim(&self, id: &ActivityId) -> Result<bool, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let claimed = Internal::claim(id, &mut con).await?;
+        Ok(claimed)
+    }
+
+    async fn release(&self, id: &ActivityId) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::release(id, &mut con).await?;
+        Ok(())
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn claim(id: &ActivityId, con: &mut PgConnection) -> Result<bool, DriverError> {
+        let claimed = sqlx::query_as::<_, (String,)>(r#"
+            INSERT INTO received_activities (id) VALUES ($1)
+            ON CONFLICT (id) DO NOTHING
+            RETURNING id
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?;
+
+        Ok(claimed.is_some())
+    }
+
+    pub async fn release(id: &ActivityId, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM received_activities WHERE id = $1
+        "#)
+        .bind(id.as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let id = ActivityId::new("https://remote.example/activities/1");
+        assert!(Internal::claim(&id, &mut con).await?);
+        assert!(!Internal::claim(&id, &mut con).await?);
+
+        Internal::release(&id, &mut con).await?;
+        assert!(Internal::claim(&id, &mut con).await?);
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
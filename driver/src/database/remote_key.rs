@@ -0,0 +1,138 @@
+use deadpool_redis::{Pool, Connection, redis::{self, AsyncCommands}};
+use kernel::{
+    repository::RemoteKeyRepository,
+    entities::RemoteKey,
+    KernelError
+};
+use time::OffsetDateTime;
+
+use crate::DriverError;
+
+/// Keys are refetched after a day, which also picks up rotated keys.
+const LIFETIME_SECONDS: usize = 60 * 60 * 24;
+
+#[derive(Clone)]
+pub struct RemoteKeyDataBase {
+    pool: Pool
+}
+
+#[allow(dead_code)]
+impl RemoteKeyDataBase {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl RemoteKeyRepository for RemoteKeyDataBase {
+    async fn save(&self, save: &RemoteKey) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::save(save, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, key_id: &str) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::delete(key_id, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, key_id: &str) -> Result<Option<RemoteKey>, KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        let found = Internal::find_by_id(key_id, &mut con).await?;
+        Ok(found)
+    }
+}
+
+fn remote_key(key_id: &str) -> String {
+    format!("remote_key:{}", key_id)
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn save(save: &RemoteKey, con: &mut Connection) -> Result<(), DriverError> {
+        let key = remote_key(save.id());
+        let fetched_at = save.fetched_at().unix_timestamp().to_string();
+
+        redis::pipe()
+            .atomic()
+            .hset_multiple(&key, &[
+                ("owner", save.owner()),
+                ("public_key", save.public_key().as_ref()),
+                ("fetched_at", &fetched_at)
+            ])
+            .ignore()
+            .expire(&key, LIFETIME_SECONDS).ignore()
+            .query_async::<_, ()>(&mut *con)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(key_id: &str, con: &mut Connection) -> Result<(), DriverError> {
+        con.del::<_, ()>(remote_key(key_id)).await?;
+        Ok(())
+    }
+
+    pub async fn find_by_id(key_id: &str, con: &mut Connection) -> Result<Option<RemoteKey>, DriverError> {
+        let (owner, public_key, fetched_at): (Option<String>, Option<String>, Option<i64>) = redis::cmd("HMGET")
+            .arg(remote_key(key_id))
+            .arg(&["owner", "public_key", "fetched_at"])
+            .query_async(&mut *con)
+            .await?;
+
+        let (Some(owner), Some(public_key)) = (owner, public_key) else {
+            return Ok(None);
+        };
+
+        // Keys cached without the time they were fetched can be refetched at once.
+        let fetched_at = fetched_at
+            .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+            .unwrap_or(OffsetDateTime::UNIX_EPOCH);
+
+        Ok(Some(RemoteKey::new(key_id, owner, public_key, fetched_at)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use deadpool_redis::{Config, Pool, Runtime};
+    use kernel::entities::RemoteKey;
+    use time::OffsetDateTime;
+
+    use super::Internal;
+
+    fn test_pool() -> anyhow::Result<Pool> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("REDIS_URL")
+            .expect("`REDIS_URL` is not set. This is a required environment variable.");
+        let pool = Config::from_url(url)
+            .create_pool(Some(Runtime::Tokio1))?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Redis and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool()?;
+        let mut con = pool.get().await?;
+
+        let key = RemoteKey::new("https://remote.example/users/a#main-key", "https://remote.example/users/a", "public", OffsetDateTime::from_unix_timestamp(1_684_000_000)?);
+
+        assert!(Internal::find_by_id(key.id(), &mut con).await?.is_none());
+
+        Internal::save(&key, &mut con).await?;
+        assert_eq!(Internal::find_by_id(key.id(), &mut con).await?, Some(key.clone()));
+
+        Internal::delete(key.id(), &mut con).await?;
+        assert!(Internal::find_by_id(key.id(), &mut con).await?.is_none());
+
+        Ok(())
+    }
+}
\ No newline at end of file
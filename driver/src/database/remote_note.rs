@@ -0,0 +1,267 @@
+use kernel::{
+    repository::RemoteNoteRepository,
+    entities::{NoteId, RemoteNote, RemoteNoteId, Visibility},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+use super::note::{note_columns, note_types};
+
+#[derive(Debug, Clone)]
+pub struct RemoteNoteDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl RemoteNoteDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl RemoteNoteRepository for RemoteNoteDataBase {
+    async fn create(&self, create: &RemoteNote) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &RemoteNoteId, author: &str) -> Result<(), KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        Internal::delete(id, author, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &RemoteNoteId) -> Result<Option<RemoteNote>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_id(id, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_replies(&self, targets: &[NoteId], depth: i64) -> Result<Vec<RemoteNote>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_replies(targets, depth, &mut con).await?;
+        Ok(found)
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct RemoteNoteRow {
+    id: String,
+    cursor: Uuid,
+    author: String,
+    content: String,
+    cw: Option<String>,
+    visibility: String,
+    reply_local: Option<Uuid>,
+    reply_remote: Option<String>,
+    created_at: OffsetDateTime
+}
+
+impl TryFrom<RemoteNoteRow> for RemoteNote {
+    type Error = DriverError;
+    fn try_from(fetched: RemoteNoteRow) -> Result<Self, Self::Error> {
+        let visibility = Visibility::try_from(fetched.visibility.as_str())
+            .map_err(|e| DriverError::Convert(e.to_string()))?;
+        Ok(RemoteNote::new(
+            fetched.id,
+            fetched.author,
+            fetched.content,
+            fetched.cw,
+            visibility,
+            note_types(fetched.reply_local, fetched.reply_remote, "remote note")?,
+            fetched.created_at
+        ))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &RemoteNote, con: &mut PgConnection) -> Result<(), DriverError> {
+        let (reply_local, reply_remote) = create.in_reply_to()
+            .map(note_columns)
+            .unwrap_or_default();
+
+        sqlx::query(r#"
+            INSERT INTO remote_notes (
+                id,
+                author,
+                content,
+                cw,
+                visibility,
+                reply_local,
+                reply_remote,
+                created_at
+            )
+            VALUES (
+                $1,
+                $2,
+                $3,
+                $4,
+                $5,
+                $6,
+                $7,
+                $8
+            )
+            ON CONFLICT (id) DO NOTHING
+        "#)
+        .bind(create.id().as_ref())
+        .bind(create.author())
+        .bind(create.content())
+        .bind(create.cw())
+        .bind(create.visibility().as_str())
+        .bind(reply_local)
+        .bind(reply_remote)
+        .bind(create.created_at().as_ref())
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(id: &RemoteNoteId, author: &str, con: &mut PgConnection) -> Result<(), DriverError> {
+        sqlx::query(r#"
+            DELETE FROM remote_notes WHERE id = $1 AND author = $2
+        "#)
+        .bind(id.as_ref())
+        .bind(author)
+        .execute(&mut *con)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Notes by the cursor they are paged by, each with its cursor. The order is unspecified.
+    pub async fn find_by_cursors(cursors: &[Uuid], con: &mut PgConnection) -> Result<Vec<(Uuid, RemoteNote)>, DriverError> {
+        sqlx::query_as::<_, RemoteNoteRow>(r#"
+            SELECT * FROM remote_notes WHERE cursor = ANY($1)
+        "#)
+        .bind(cursors)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(|fetched| Ok((fetched.cursor, RemoteNote::try_from(fetched)?)))
+        .collect()
+    }
+
+    pub async fn find_by_id(id: &RemoteNoteId, con: &mut PgConnection) -> Result<Option<RemoteNote>, DriverError> {
+        sqlx::query_as::<_, RemoteNoteRow>(r#"
+            SELECT * FROM remote_notes WHERE id = $1
+        "#)
+        .bind(id.as_ref())
+        .fetch_optional(&mut *con)
+        .await?
+        .map(RemoteNote::try_from)
+        .transpose()
+    }
+
+    pub async fn find_replies(targets: &[NoteId], depth: i64, con: &mut PgConnection) -> Result<Vec<RemoteNote>, DriverError> {
+        let targets = targets.iter().map(|id| *id.as_ref()).collect::<Vec<Uuid>>();
+        sqlx::query_as::<_, RemoteNoteRow>(r#"
+            WITH RECURSIVE replies (id, depth) AS (
+                SELECT id, 1 FROM remote_notes
+                WHERE reply_local = ANY($1)
+              UNION ALL
+                SELECT remote_notes.id, replies.depth + 1 FROM remote_notes
+                JOIN replies ON remote_notes.reply_remote = replies.id
+                WHERE replies.depth < $2
+            )
+            SELECT remote_notes.* FROM replies
+            JOIN remote_notes ON remote_notes.id = replies.id
+            ORDER BY remote_notes.created_at ASC, remote_notes.id ASC
+        "#)
+        .bind(&targets)
+        .bind(depth)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(RemoteNote::try_from)
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use uuid::Uuid;
+    use crate::database::{
+        account::Internal as AccountDataBaseInternal,
+        note::Internal as NoteDataBaseInternal
+    };
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-5-10), time!(0:00)).assume_utc();
+
+        let account = Account::new(AccountId::default(), "test1", false, false, created_at, created_at);
+        AccountDataBaseInternal::create(&account, &mut con).await?;
+        let local = Note::new(NoteId::default(), *account.id(), "Hello", None::<String>, Visibility::Public, None, None, [], Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at);
+        NoteDataBaseInternal::create(&local, &mut con).await?;
+
+        let author = "https://remote.example/users/shuttle";
+        let note = RemoteNote::new("https://remote.example/notes/1", author, "<p>Hi</p>", Some("cw"), Visibility::Unlisted, Some((*local.id()).into()), created_at);
+        Internal::create(&note, &mut con).await?;
+        assert_eq!(Internal::find_by_id(note.id(), &mut con).await?, Some(note.clone()));
+
+        // Redelivered notes keep the first copy.
+        let again = RemoteNote::new("https://remote.example/notes/1", author, "<p>Edited</p>", None::<String>, Visibility::Public, None, created_at);
+        Internal::create(&again, &mut con).await?;
+        assert_eq!(Internal::find_by_id(note.id(), &mut con).await?, Some(note.clone()));
+
+        // Only the author can delete the note.
+        Internal::delete(note.id(), "https://other.example/users/shuttle", &mut con).await?;
+        assert!(Internal::find_by_id(note.id(), &mut con).await?.is_some());
+        Internal::delete(note.id(), author, &mut con).await?;
+        assert!(Internal::find_by_id(note.id(), &mut con).await?.is_none());
+
+        // Replies are followed through remote notes only as deep as asked.
+        let reply = RemoteNote::new("https://remote.example/notes/2", author, "<p>Hi</p>", None::<String>, Visibility::Public, Some((*local.id()).into()), created_at);
+        let nested = RemoteNote::new("https://remote.example/notes/3", author, "<p>Hi</p>", None::<String>, Visibility::Public, Some(NoteTypes::Federate(reply.id().as_ref().to_string())), created_at + time::Duration::SECOND);
+        let other = RemoteNote::new("https://remote.example/notes/4", author, "<p>Hi</p>", None::<String>, Visibility::Public, None, created_at);
+        for note in [&reply, &nested, &other] {
+            Internal::create(note, &mut con).await?;
+        }
+        assert_eq!(Internal::find_replies(&[*local.id()], 10, &mut con).await?, vec![reply.clone(), nested.clone()]);
+        assert_eq!(Internal::find_replies(&[*local.id()], 1, &mut con).await?, vec![reply.clone()]);
+        assert!(Internal::find_replies(&[], 10, &mut con).await?.is_empty());
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,237 @@
+use deadpool_redis::{Pool, Connection, redis::{self, AsyncCommands}};
+use kernel::{
+    repository::SessionRepository,
+    entities::{Session, SessionToken, AccountId},
+    KernelError
+};
+use time::OffsetDateTime;
+
+use crate::DriverError;
+
+#[derive(Clone)]
+pub struct SessionDataBase {
+    pool: Pool
+}
+
+#[allow(dead_code)]
+impl SessionDataBase {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for SessionDataBase {
+    async fn create(&self, create: &Session) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::create(create, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, delete: &SessionToken) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::delete(delete, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::delete_all_by_account(account, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find_by_token(&self, token: &SessionToken) -> Result<Option<Session>, KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        let found = Internal::find_by_token(token, &mut con).await?;
+        Ok(found)
+    }
+}
+
+fn session_key(token: &SessionToken) -> String {
+    format!("session:{}", token.as_ref())
+}
+
+fn account_key(account: &AccountId) -> String {
+    format!("session_account:{}", account.as_ref())
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn create(create: &Session, con: &mut Connection) -> Result<(), DriverError> {
+        let key = session_key(create.token());
+        let account = account_key(create.account());
+        let expires_at = create.expires_at().as_ref().unix_timestamp();
+
+        // The index lives as long as the longest session in it. `TTL` is negative without an expiry.
+        let ttl: i64 = con.ttl(&account).await?;
+        let index_expires_at = if ttl > 0 {
+            expires_at.max(OffsetDateTime::now_utc().unix_timestamp() + ttl)
+        } else {
+            expires_at
+        };
+
+        redis::pipe()
+            .atomic()
+            .hset_multiple(&key, &[
+                ("account", *create.account().as_ref()),
+                ("created_at", create.created_at().as_ref().unix_timestamp()),
+                ("expires_at", expires_at)
+            ])
+            .ignore()
+            .cmd("EXPIREAT").arg(&key).arg(expires_at).ignore()
+            .sadd(&account, create.token().as_ref()).ignore()
+            .cmd("EXPIREAT").arg(&account).arg(index_expires_at).ignore()
+            .query_async::<_, ()>(&mut *con)
+            .await?;
+
+        Self::prune(create.account(), con).await?;
+
+        Ok(())
+    }
+
+    /// Drops the tokens of expired sessions from the index of `account`.
+    async fn prune(account: &AccountId, con: &mut Connection) -> Result<(), DriverError> {
+        let key = account_key(account);
+        let tokens: Vec<String> = con.smembers(&key).await?;
+        if tokens.is_empty() {
+            return Ok(());
+        }
+
+        let mut pipe = redis::pipe();
+        for token in &tokens {
+            pipe.exists(session_key(&SessionToken::new(token.as_str())));
+        }
+        let alive: Vec<bool> = pipe.query_async(&mut *con).await?;
+
+        let expired: Vec<&String> = tokens.iter()
+            .zip(alive)
+            .filter_map(|(token, alive)| (!alive).then_some(token))
+            .collect();
+        if !expired.is_empty() {
+            con.srem::<_, _, ()>(&key, expired).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete(delete: &SessionToken, con: &mut Connection) -> Result<(), DriverError> {
+        let key = session_key(delete);
+        let account: Option<i64> = con.hget(&key, "account").await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&key).ignore();
+        if let Some(account) = account {
+            pipe.srem(account_key(&AccountId::new(account)), delete.as_ref()).ignore();
+        }
+        pipe.query_async::<_, ()>(&mut *con).await?;
+
+        if let Some(account) = account {
+            Self::prune(&AccountId::new(account), con).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_all_by_account(account: &AccountId, con: &mut Connection) -> Result<(), DriverError> {
+        let key = account_key(account);
+        let tokens: Vec<String> = con.smembers(&key).await?;
+
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for token in tokens {
+            pipe.del(session_key(&SessionToken::new(token))).ignore();
+        }
+        pipe.del(&key).ignore();
+        pipe.query_async::<_, ()>(&mut *con).await?;
+
+        Ok(())
+    }
+
+    pub async fn find_by_token(token: &SessionToken, con: &mut Connection) -> Result<Option<Session>, DriverError> {
+        let (account, created_at, expires_at): (Option<i64>, Option<i64>, Option<i64>) = redis::cmd("HMGET")
+            .arg(session_key(token))
+            .arg(&["account", "created_at", "expires_at"])
+            .query_async(&mut *con)
+            .await?;
+
+        let (Some(account), Some(created_at), Some(expires_at)) = (account, created_at, expires_at) else {
+            return Ok(None);
+        };
+
+        let convert = |timestamp: i64| OffsetDateTime::from_unix_timestamp(timestamp)
+            .map_err(|e| DriverError::Convert(format!("failed parse timestamp. `time`: {}", e)));
+
+        Ok(Some(Session::new(
+            token.clone(),
+            account,
+            convert(created_at)?,
+            convert(expires_at)?
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use deadpool_redis::{Config, Pool, Runtime, redis::AsyncCommands};
+    use kernel::entities::{AccountId, Session, SessionToken};
+    use time::{Duration, OffsetDateTime};
+
+    use super::Internal;
+
+    fn test_pool() -> anyhow::Result<Pool> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("REDIS_URL")
+            .expect("`REDIS_URL` is not set. This is a required environment variable.");
+        let pool = Config::from_url(url)
+            .create_pool(Some(Runtime::Tokio1))?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Redis and does not work as is."]
+    #[tokio::test]
+    async fn test() -> anyhow::Result<()> {
+        let pool = test_pool()?;
+        let mut con = pool.get().await?;
+
+        let account = AccountId::default();
+        let now = OffsetDateTime::now_utc().replace_nanosecond(0)?;
+
+        let a = Session::new(SessionToken::default(), account, now, now + Session::LIFETIME);
+        let b = Session::new(SessionToken::default(), account, now, now + Session::LIFETIME);
+
+        Internal::create(&a, &mut con).await?;
+        Internal::create(&b, &mut con).await?;
+
+        let fetched = Internal::find_by_token(a.token(), &mut con).await?.unwrap();
+        assert_eq!(fetched, a);
+
+        Internal::delete(a.token(), &mut con).await?;
+        assert!(Internal::find_by_token(a.token(), &mut con).await?.is_none());
+        assert!(Internal::find_by_token(b.token(), &mut con).await?.is_some());
+
+        Internal::delete_all_by_account(&account, &mut con).await?;
+        assert!(Internal::find_by_token(b.token(), &mut con).await?.is_none());
+
+        // Tokens of sessions that expired on their own are dropped from the index on the next login.
+        let expired = Session::new(SessionToken::default(), account, now, now + Duration::seconds(1));
+        Internal::create(&expired, &mut con).await?;
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+        let c = Session::new(SessionToken::default(), account, now, now + Session::LIFETIME);
+        Internal::create(&c, &mut con).await?;
+        let indexed: Vec<String> = con.smembers(super::account_key(&account)).await?;
+        assert_eq!(indexed, vec![c.token().as_ref().to_string()]);
+        let ttl: i64 = con.ttl(super::account_key(&account)).await?;
+        assert!(ttl > 0);
+
+        Internal::delete_all_by_account(&account, &mut con).await?;
+
+        Ok(())
+    }
+}
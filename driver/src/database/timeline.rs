@@ -0,0 +1,691 @@
+use std::collections::HashMap;
+
+use kernel::{
+    repository::TimelineRepository,
+    entities::{AccountId, Boost, Note, Pagination, TimelineCursor, TimelineEntry, Visibility},
+    KernelError
+};
+use sqlx::{Pool, Postgres, PgConnection};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::DriverError;
+
+use super::{note::NoteRow, remote_note::Internal as RemoteNoteDataBaseInternal};
+
+#[derive(Debug, Clone)]
+pub struct TimelineDataBase {
+    pool: Pool<Postgres>
+}
+
+#[allow(dead_code)]
+impl TimelineDataBase {
+    pub fn new(pool: Pool<Postgres>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl TimelineRepository for TimelineDataBase {
+    async fn find_by_account(
+        &self,
+        account: &AccountId,
+        visibilities: &[Visibility],
+        viewer: Option<AccountId>,
+        page: &Pagination
+    ) -> Result<Vec<TimelineEntry>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_account(account, visibilities, viewer, page, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_home(&self, account: &AccountId, following: &[AccountId], page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_home(account, following, page, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_public(&self, page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_public(page, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_federated(&self, page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_federated(page, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_mentions(&self, account: &AccountId, page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_mentions(account, page, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_home_cursors(&self, account: &AccountId, following: &[AccountId], limit: i64) -> Result<Vec<TimelineCursor>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_home_cursors(account, following, limit, &mut con).await?;
+        Ok(found)
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TimelineEntry>, KernelError> {
+        let mut con = self.pool.acquire().await
+            .map_err(DriverError::SqlX)?;
+        let found = Internal::find_by_ids(ids, &mut con).await?;
+        Ok(found)
+    }
+}
+
+/// Direction of `ORDER BY` for `page`. Ascending pages are fetched oldest first.
+pub(in crate::database) fn direction(page: &Pagination) -> &'static str {
+    if page.is_ascending() { "ASC" } else { "DESC" }
+}
+
+/// Puts a page fetched in the `direction` of `page` back to newest first.
+pub(in crate::database) fn newest_first<T>(page: &Pagination, mut fetched: Vec<T>) -> Vec<T> {
+    if page.is_ascending() {
+        fetched.reverse();
+    }
+    fetched
+}
+
+/// Query of a page over `entries`, a CTE of `(id, created_at, note, account, visibility, implicit)`
+/// where the last three are only set for boosts.
+///
+/// The bounds and limit of the page are the three parameters after the `params` of `entries`.
+fn paged(entries: &str, params: usize, page: &Pagination) -> String {
+    let (max_id, newer_than, limit) = (params + 1, params + 2, params + 3);
+    format!(r#"
+        WITH entries AS ({entries}),
+        cursors AS (
+            SELECT id, created_at FROM notes WHERE id IN (${max_id}, ${newer_than})
+          UNION ALL
+            SELECT id, created_at FROM note_turbo WHERE id IN (${max_id}, ${newer_than})
+        )
+        SELECT
+            entries.id AS entry_id,
+            entries.created_at AS entry_created_at,
+            entries.account AS boost_account,
+            entries.visibility AS boost_visibility,
+            entries.implicit AS boost_implicit,
+            note_details.*
+        FROM entries
+        JOIN note_details ON note_details.id = entries.note
+        WHERE (${max_id}::UUID IS NULL OR (entries.created_at, entries.id) < (SELECT created_at, id FROM cursors WHERE id = ${max_id}))
+          AND (${newer_than}::UUID IS NULL OR (entries.created_at, entries.id) > (SELECT created_at, id FROM cursors WHERE id = ${newer_than}))
+        ORDER BY entries.created_at {order}, entries.id {order}
+        LIMIT ${limit}
+    "#, order = direction(page))
+}
+
+/// Entries of the home timeline of `$1`, who follows the accounts in `$2`. `$3` is the direct visibility.
+const HOME_ENTRIES: &str = r#"
+    SELECT id, created_at, id AS note, NULL::BIGINT AS account, NULL AS visibility, NULL::BOOLEAN AS implicit
+    FROM notes
+    WHERE (account = $1 OR account = ANY($2))
+      AND (visibility <> $3 OR account = $1 OR EXISTS (
+        SELECT 1 FROM note_mention WHERE origin_local = notes.id AND target_local = $1
+      ))
+  UNION ALL
+    SELECT id, created_at, target_local, origin_local, visibility, implicit
+    FROM note_turbo
+    WHERE (origin_local = $1 OR origin_local = ANY($2)) AND target_local IS NOT NULL AND visibility <> $3
+"#;
+
+/// A note, or a boost joined with the note it reshares.
+#[derive(sqlx::FromRow)]
+struct TimelineRow {
+    entry_id: Uuid,
+    entry_created_at: OffsetDateTime,
+    /// Only set for boosts.
+    boost_account: Option<i64>,
+    boost_visibility: Option<String>,
+    boost_implicit: Option<bool>,
+    #[sqlx(flatten)]
+    note: NoteRow
+}
+
+impl TryFrom<TimelineRow> for TimelineEntry {
+    type Error = DriverError;
+    fn try_from(fetched: TimelineRow) -> Result<Self, Self::Error> {
+        let note = Note::try_from(fetched.note)?;
+        let (Some(account), Some(visibility), Some(implicit)) = (fetched.boost_account, fetched.boost_visibility, fetched.boost_implicit) else {
+            return Ok(TimelineEntry::Note(note));
+        };
+        let visibility = Visibility::try_from(visibility.as_str())
+            .map_err(|e| DriverError::Convert(e.to_string()))?;
+        let boost = Boost::new(
+            fetched.entry_id,
+            AccountId::new(account),
+            *note.id(),
+            visibility,
+            implicit,
+            fetched.entry_created_at
+        );
+        Ok(TimelineEntry::Boost(boost, note))
+    }
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn find_by_account(
+        account: &AccountId,
+        visibilities: &[Visibility],
+        viewer: Option<AccountId>,
+        page: &Pagination,
+        con: &mut PgConnection
+    ) -> Result<Vec<TimelineEntry>, DriverError> {
+        let visibilities = visibilities.iter()
+            .map(|visibility| visibility.as_str())
+            .collect::<Vec<&str>>();
+
+        let query = paged(r#"
+            SELECT id, created_at, id AS note, NULL::BIGINT AS account, NULL AS visibility, NULL::BOOLEAN AS implicit
+            FROM notes
+            WHERE account = $1
+              AND (visibility = ANY($2) OR EXISTS (
+                SELECT 1 FROM note_mention WHERE origin_local = notes.id AND target_local = $3
+              ))
+          UNION ALL
+            SELECT id, created_at, target_local, origin_local, visibility, implicit
+            FROM note_turbo
+            WHERE origin_local = $1 AND target_local IS NOT NULL AND visibility = ANY($2)
+        "#, 3, page);
+
+        let found = sqlx::query_as::<_, TimelineRow>(&query)
+            .bind(account.as_ref())
+            .bind(visibilities)
+            .bind(viewer.map(i64::from))
+            .bind(page.max_id())
+            .bind(page.newer_than())
+            .bind(page.limit())
+            .fetch_all(&mut *con)
+            .await?
+            .into_iter()
+            .map(TimelineEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(newest_first(page, found))
+    }
+
+    pub async fn find_home(
+        account: &AccountId,
+        following: &[AccountId],
+        page: &Pagination,
+        con: &mut PgConnection
+    ) -> Result<Vec<TimelineEntry>, DriverError> {
+        let following = following.iter()
+            .map(|id| *id.as_ref())
+            .collect::<Vec<i64>>();
+
+        let query = paged(HOME_ENTRIES, 3, page);
+
+        let found = sqlx::query_as::<_, TimelineRow>(&query)
+            .bind(account.as_ref())
+            .bind(following)
+            .bind(Visibility::Direct.as_str())
+            .bind(page.max_id())
+            .bind(page.newer_than())
+            .bind(page.limit())
+            .fetch_all(&mut *con)
+            .await?
+            .into_iter()
+            .map(TimelineEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(newest_first(page, found))
+    }
+
+    pub async fn find_mentions(account: &AccountId, page: &Pagination, con: &mut PgConnection) -> Result<Vec<TimelineEntry>, DriverError> {
+        let query = paged(r#"
+            SELECT id, created_at, id AS note, NULL::BIGINT AS account, NULL AS visibility, NULL::BOOLEAN AS implicit
+            FROM notes
+            WHERE account <> $1 AND EXISTS (
+              SELECT 1 FROM note_mention WHERE origin_local = notes.id AND target_local = $1
+            )
+        "#, 1, page);
+
+        let found = sqlx::query_as::<_, TimelineRow>(&query)
+            .bind(account.as_ref())
+            .bind(page.max_id())
+            .bind(page.newer_than())
+            .bind(page.limit())
+            .fetch_all(&mut *con)
+            .await?
+            .into_iter()
+            .map(TimelineEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(newest_first(page, found))
+    }
+
+    pub async fn find_home_cursors(
+        account: &AccountId,
+        following: &[AccountId],
+        limit: i64,
+        con: &mut PgConnection
+    ) -> Result<Vec<TimelineCursor>, DriverError> {
+        let following = following.iter()
+            .map(|id| *id.as_ref())
+            .collect::<Vec<i64>>();
+
+        let found = sqlx::query_as::<_, (Uuid, OffsetDateTime)>(&format!(r#"
+            WITH entries AS ({HOME_ENTRIES})
+            SELECT id, created_at FROM entries
+            ORDER BY created_at DESC, id DESC
+            LIMIT $4
+        "#))
+        .bind(account.as_ref())
+        .bind(following)
+        .bind(Visibility::Direct.as_str())
+        .bind(limit)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(|(id, created_at)| TimelineCursor::new(id, created_at))
+        .collect();
+        Ok(found)
+    }
+
+    pub async fn find_by_ids(ids: &[Uuid], con: &mut PgConnection) -> Result<Vec<TimelineEntry>, DriverError> {
+        sqlx::query_as::<_, TimelineRow>(r#"
+            WITH entries AS (
+                SELECT id, created_at, id AS note, NULL::BIGINT AS account, NULL AS visibility, NULL::BOOLEAN AS implicit
+                FROM notes
+                WHERE id = ANY($1)
+              UNION ALL
+                SELECT id, created_at, target_local, origin_local, visibility, implicit
+                FROM note_turbo
+                WHERE id = ANY($1) AND target_local IS NOT NULL
+            )
+            SELECT
+                entries.id AS entry_id,
+                entries.created_at AS entry_created_at,
+                entries.account AS boost_account,
+                entries.visibility AS boost_visibility,
+                entries.implicit AS boost_implicit,
+                note_details.*
+            FROM entries
+            JOIN note_details ON note_details.id = entries.note
+            ORDER BY entries.created_at DESC, entries.id DESC
+        "#)
+        .bind(ids)
+        .fetch_all(&mut *con)
+        .await?
+        .into_iter()
+        .map(TimelineEntry::try_from)
+        .collect()
+    }
+
+    pub async fn find_public(page: &Pagination, con: &mut PgConnection) -> Result<Vec<TimelineEntry>, DriverError> {
+        let query = paged(r#"
+            SELECT id, created_at, id AS note, NULL::BIGINT AS account, NULL AS visibility, NULL::BOOLEAN AS implicit
+            FROM notes
+            WHERE visibility = $1
+        "#, 1, page);
+
+        let found = sqlx::query_as::<_, TimelineRow>(&query)
+            .bind(Visibility::Public.as_str())
+            .bind(page.max_id())
+            .bind(page.newer_than())
+            .bind(page.limit())
+            .fetch_all(&mut *con)
+            .await?
+            .into_iter()
+            .map(TimelineEntry::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(newest_first(page, found))
+    }
+
+    /// Pages over local and remote public notes by their cursors, then reads the notes of the page.
+    pub async fn find_federated(page: &Pagination, con: &mut PgConnection) -> Result<Vec<TimelineEntry>, DriverError> {
+        let order = direction(page);
+        let cursors: Vec<(Uuid, bool)> = sqlx::query_as(&format!(r#"
+            WITH entries AS (
+                SELECT id, created_at, FALSE AS remote FROM notes WHERE visibility = $1
+              UNION ALL
+                SELECT cursor, created_at, TRUE FROM remote_notes WHERE visibility = $1
+            ),
+            cursors AS (
+                SELECT id, created_at FROM notes WHERE id IN ($2, $3)
+              UNION ALL
+                SELECT cursor, created_at FROM remote_notes WHERE cursor IN ($2, $3)
+            )
+            SELECT id, remote FROM entries
+            WHERE ($2::UUID IS NULL OR (created_at, id) < (SELECT created_at, id FROM cursors WHERE id = $2))
+              AND ($3::UUID IS NULL OR (created_at, id) > (SELECT created_at, id FROM cursors WHERE id = $3))
+            ORDER BY created_at {order}, id {order}
+            LIMIT $4
+        "#))
+        .bind(Visibility::Public.as_str())
+        .bind(page.max_id())
+        .bind(page.newer_than())
+        .bind(page.limit())
+        .fetch_all(&mut *con)
+        .await?;
+
+        let (remote, local): (Vec<_>, Vec<_>) = cursors.iter().partition(|(_, remote)| *remote);
+        let local = local.into_iter().map(|&(id, _)| id).collect::<Vec<_>>();
+        let remote = remote.into_iter().map(|&(id, _)| id).collect::<Vec<_>>();
+
+        let mut found = Self::find_by_ids(&local, con).await?
+            .into_iter()
+            .map(|entry| (*entry.id(), entry))
+            .collect::<HashMap<_, _>>();
+        found.extend(RemoteNoteDataBaseInternal::find_by_cursors(&remote, con).await?
+            .into_iter()
+            .map(|(cursor, note)| (cursor, TimelineEntry::Remote(cursor, note))));
+
+        // Notes deleted in between are skipped.
+        let found = cursors.iter()
+            .filter_map(|(id, _)| found.remove(id))
+            .collect();
+        Ok(newest_first(page, found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use kernel::entities::*;
+    use sqlx::{Postgres, Pool, postgres::PgPoolOptions};
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use uuid::Uuid;
+    use crate::database::{
+        account::Internal as AccountDataBaseInternal,
+        boost::Internal as BoostDataBaseInternal,
+        note::Internal as NoteDataBaseInternal,
+        remote_note::Internal as RemoteNoteDataBaseInternal
+    };
+
+    use super::Internal;
+
+    async fn test_pool() -> anyhow::Result<Pool<Postgres>> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let pool = PgPoolOptions::new()
+            .max_connections(4)
+            .idle_timeout(Duration::new(5, 0))
+            .connect(&url)
+            .await?;
+
+        Ok(pool)
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_account() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-4-30), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-4-30), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let b_id = AccountId::default();
+        let b = Account::new(b_id, "test2", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let note = |author: AccountId, visibility: Visibility, mentions: Vec<AccountTypes>, minutes: i64| Note::new(
+            NoteId::default(), author, "timeline", None::<String>, visibility, None, None, mentions,
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at + time::Duration::minutes(minutes)
+        );
+
+        let a_note = note(a_id, Visibility::Public, vec![], 0);
+        let b_note = note(a_id, Visibility::FollowersOnly, vec![], 1);
+        let c_note = note(a_id, Visibility::Direct, vec![AccountTypes::Local(b_id)], 2);
+        let d_note = note(b_id, Visibility::Public, vec![], 3);
+
+        for created in [&a_note, &b_note, &c_note, &d_note] {
+            NoteDataBaseInternal::create(created, &mut con).await?;
+        }
+
+        let boost = Boost::new(BoostId::default(), a_id, *d_note.id(), Visibility::Public, false, created_at + time::Duration::minutes(4));
+        BoostDataBaseInternal::create(&boost, &mut con).await?;
+
+        let public = [Visibility::Public, Visibility::Unlisted];
+
+        // Anonymous viewers only see public notes and boosts.
+        let found = Internal::find_by_account(&a_id, &public, None, &Pagination::default(), &mut con).await?;
+        assert_eq!(found, vec![
+            TimelineEntry::Boost(boost.clone(), d_note.clone()),
+            TimelineEntry::Note(a_note.clone())
+        ]);
+
+        // Mentioned accounts see direct notes as well.
+        let found = Internal::find_by_account(&a_id, &public, Some(b_id), &Pagination::default(), &mut con).await?;
+        assert_eq!(found.iter().map(TimelineEntry::id).collect::<Vec<_>>(), vec![boost.id().as_ref(), c_note.id().as_ref(), a_note.id().as_ref()]);
+
+        let all = [Visibility::Public, Visibility::Unlisted, Visibility::FollowersOnly, Visibility::Direct];
+        let page = Pagination::new(Some(*boost.id().as_ref()), None, None, Some(2));
+        let found = Internal::find_by_account(&a_id, &all, Some(a_id), &page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(c_note), TimelineEntry::Note(b_note)]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_home() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-5-5), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-5-5), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let b_id = AccountId::default();
+        let b = Account::new(b_id, "test2", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let c_id = AccountId::default();
+        let c = Account::new(c_id, "test3", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&c, &mut con).await?;
+
+        let note = |author: AccountId, visibility: Visibility, mentions: Vec<AccountTypes>, minutes: i64| Note::new(
+            NoteId::default(), author, "home", None::<String>, visibility, None, None, mentions,
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at + time::Duration::minutes(minutes)
+        );
+
+        let a_note = note(a_id, Visibility::Direct, vec![AccountTypes::Local(c_id)], 0);
+        let b_note = note(b_id, Visibility::FollowersOnly, vec![], 1);
+        let c_note = note(b_id, Visibility::Direct, vec![AccountTypes::Local(c_id)], 2);
+        let d_note = note(b_id, Visibility::Direct, vec![AccountTypes::Local(a_id)], 3);
+        let e_note = note(c_id, Visibility::Public, vec![], 4);
+
+        for created in [&a_note, &b_note, &c_note, &d_note, &e_note] {
+            NoteDataBaseInternal::create(created, &mut con).await?;
+        }
+
+        let boost = Boost::new(BoostId::default(), b_id, *e_note.id(), Visibility::Public, false, created_at + time::Duration::minutes(5));
+        BoostDataBaseInternal::create(&boost, &mut con).await?;
+
+        // Own direct notes and those mentioning the account are listed, other direct notes are not.
+        let found = Internal::find_home(&a_id, &[b_id], &Pagination::default(), &mut con).await?;
+        assert_eq!(found, vec![
+            TimelineEntry::Boost(boost.clone(), e_note.clone()),
+            TimelineEntry::Note(d_note.clone()),
+            TimelineEntry::Note(b_note.clone()),
+            TimelineEntry::Note(a_note.clone())
+        ]);
+
+        let page = Pagination::new(None, None, Some(*a_note.id().as_ref()), Some(2));
+        let found = Internal::find_home(&a_id, &[b_id], &page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(d_note.clone()), TimelineEntry::Note(b_note.clone())]);
+
+        let page = Pagination::new(Some(*boost.id().as_ref()), Some(*a_note.id().as_ref()), None, None);
+        let found = Internal::find_home(&a_id, &[b_id], &page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(d_note.clone()), TimelineEntry::Note(b_note.clone())]);
+
+        let cursors = Internal::find_home_cursors(&a_id, &[b_id], 3, &mut con).await?;
+        let entries = Internal::find_by_ids(&cursors.iter().map(|cursor| *cursor.id()).collect::<Vec<_>>(), &mut con).await?;
+        assert_eq!(cursors, entries.iter().map(TimelineEntry::cursor).collect::<Vec<_>>());
+        assert_eq!(entries, vec![
+            TimelineEntry::Boost(boost, e_note),
+            TimelineEntry::Note(d_note),
+            TimelineEntry::Note(b_note)
+        ]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_mentions() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2023-5-5), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2023-5-5), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let b_id = AccountId::default();
+        let b = Account::new(b_id, "test2", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&b, &mut con).await?;
+
+        let note = |author: AccountId, visibility: Visibility, mentions: Vec<AccountTypes>, minutes: i64| Note::new(
+            NoteId::default(), author, "mentions", None::<String>, visibility, None, None, mentions,
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at + time::Duration::minutes(minutes)
+        );
+
+        let a_note = note(b_id, Visibility::Public, vec![AccountTypes::Local(a_id)], 0);
+        let b_note = note(b_id, Visibility::Public, vec![], 1);
+        let c_note = note(a_id, Visibility::Public, vec![AccountTypes::Local(a_id)], 2);
+        let d_note = note(b_id, Visibility::Direct, vec![AccountTypes::Local(b_id), AccountTypes::Local(a_id)], 3);
+
+        for created in [&a_note, &b_note, &c_note, &d_note] {
+            NoteDataBaseInternal::create(created, &mut con).await?;
+        }
+
+        // Mentions of oneself are left out, direct notes are not.
+        let found = Internal::find_mentions(&a_id, &Pagination::default(), &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(d_note.clone()), TimelineEntry::Note(a_note.clone())]);
+
+        let page = Pagination::new(Some(*d_note.id().as_ref()), None, None, None);
+        let found = Internal::find_mentions(&a_id, &page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(a_note)]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_public() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        // Notes are dated far ahead, so that others in the database do not get in between.
+        let created_at = PrimitiveDateTime::new(date!(2999-1-1), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2999-1-1), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let note = |visibility: Visibility, minutes: i64| Note::new(
+            NoteId::default(), a_id, "public", None::<String>, visibility, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at + time::Duration::minutes(minutes)
+        );
+
+        let a_note = note(Visibility::Public, 0);
+        let b_note = note(Visibility::Unlisted, 1);
+        let c_note = note(Visibility::Public, 2);
+
+        for created in [&a_note, &b_note, &c_note] {
+            NoteDataBaseInternal::create(created, &mut con).await?;
+        }
+
+        let boost = Boost::new(BoostId::default(), a_id, *a_note.id(), Visibility::Public, false, created_at + time::Duration::minutes(3));
+        BoostDataBaseInternal::create(&boost, &mut con).await?;
+
+        let page = Pagination::new(None, None, None, Some(2));
+        let found = Internal::find_public(&page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(c_note.clone()), TimelineEntry::Note(a_note.clone())]);
+
+        let page = Pagination::new(None, Some(*a_note.id().as_ref()), None, None);
+        let found = Internal::find_public(&page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(c_note)]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_federated() -> anyhow::Result<()> {
+        let pool = test_pool().await?;
+
+        let mut con = pool.begin().await?;
+
+        let created_at = PrimitiveDateTime::new(date!(2999-1-1), time!(0:00)).assume_utc();
+        let updated_at = PrimitiveDateTime::new(date!(2999-1-1), time!(0:00)).assume_utc();
+
+        let a_id = AccountId::default();
+        let a = Account::new(a_id, "test1", false, false, created_at, updated_at);
+        AccountDataBaseInternal::create(&a, &mut con).await?;
+
+        let a_note = Note::new(
+            NoteId::default(), a_id, "local", None::<String>, Visibility::Public, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), created_at
+        );
+        NoteDataBaseInternal::create(&a_note, &mut con).await?;
+
+        let remote = |id: &str, visibility: Visibility, minutes: i64| RemoteNote::new(
+            format!("https://remote.example/notes/{}", id), "https://remote.example/users/a", "<p>remote</p>",
+            None::<String>, visibility, None, created_at + time::Duration::minutes(minutes)
+        );
+        let b_note = remote("b", Visibility::Public, 1);
+        let c_note = remote("c", Visibility::Unlisted, 2);
+        let d_note = remote("d", Visibility::Public, 3);
+        for created in [&b_note, &c_note, &d_note] {
+            RemoteNoteDataBaseInternal::create(created, &mut con).await?;
+        }
+        let cursor = |note: &RemoteNote| sqlx::query_scalar::<_, Uuid>("SELECT cursor FROM remote_notes WHERE id = $1")
+            .bind(note.id().as_ref().to_string());
+        let b_cursor = cursor(&b_note).fetch_one(&mut *con).await?;
+        let d_cursor = cursor(&d_note).fetch_one(&mut *con).await?;
+
+        let page = Pagination::new(None, None, None, Some(3));
+        let found = Internal::find_federated(&page, &mut con).await?;
+        assert_eq!(found, vec![
+            TimelineEntry::Remote(d_cursor, d_note.clone()),
+            TimelineEntry::Remote(b_cursor, b_note.clone()),
+            TimelineEntry::Note(a_note.clone())
+        ]);
+
+        // Remote cursors page like local ones.
+        let page = Pagination::new(Some(b_cursor), None, None, Some(1));
+        let found = Internal::find_federated(&page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Note(a_note.clone())]);
+        let page = Pagination::new(None, None, Some(*a_note.id().as_ref()), Some(1));
+        let found = Internal::find_federated(&page, &mut con).await?;
+        assert_eq!(found, vec![TimelineEntry::Remote(b_cursor, b_note)]);
+
+        con.rollback().await?;
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,394 @@
+use std::{collections::HashMap, ops::Range, sync::{Arc, Mutex}};
+
+use deadpool_redis::{Pool, Connection, redis::{self, AsyncCommands}};
+use kernel::{
+    repository::TimelineCacheRepository,
+    entities::{AccountId, Pagination, TimelineCursor},
+    KernelError
+};
+use uuid::Uuid;
+
+use crate::DriverError;
+
+/// Seconds a home timeline stays cached without being read.
+const HOME_EXPIRES_IN: i64 = 60 * 60 * 24 * 7;
+
+/// Adds `ARGV[2]` scored `ARGV[1]` to the sorted set `KEYS[1]` if it exists, keeping the newest `ARGV[3]`.
+const PUSH_SCRIPT: &str = r#"
+if redis.call('EXISTS', KEYS[1]) == 1 then
+    redis.call('ZADD', KEYS[1], ARGV[1], ARGV[2])
+    redis.call('ZREMRANGEBYRANK', KEYS[1], 0, -tonumber(ARGV[3]) - 1)
+end
+return 0
+"#;
+
+/// Keeps home timelines in Redis, as sorted sets of entry ids scored by their creation time.
+///
+/// Entries created in the same microsecond fall back to their ids,
+/// which sort the same in Redis and Postgres.
+#[derive(Clone)]
+pub struct TimelineCacheDataBase {
+    pool: Pool
+}
+
+#[allow(dead_code)]
+impl TimelineCacheDataBase {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl TimelineCacheRepository for TimelineCacheDataBase {
+    async fn push(&self, accounts: &[AccountId], entry: &TimelineCursor) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::push(accounts, entry, &mut con).await?;
+        Ok(())
+    }
+
+    async fn rebuild(&self, account: &AccountId, entries: &[TimelineCursor]) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::rebuild(account, entries, &mut con).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, account: &AccountId) -> Result<(), KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        Internal::delete(account, &mut con).await?;
+        Ok(())
+    }
+
+    async fn find(&self, account: &AccountId, page: &Pagination) -> Result<Option<Vec<Uuid>>, KernelError> {
+        let mut con = self.pool.get().await
+            .map_err(DriverError::RedisPool)?;
+        let found = Internal::find(account, page, &mut con).await?;
+        Ok(found)
+    }
+}
+
+fn home_key(account: &AccountId) -> String {
+    format!("timeline_home:{}", account.as_ref())
+}
+
+fn score(entry: &TimelineCursor) -> f64 {
+    // Microseconds stay exact in a double for the foreseeable future.
+    (entry.created_at().as_ref().unix_timestamp_nanos() / 1000) as f64
+}
+
+/// Ranks of the entries in `page` out of `len` cached ones, newest first.
+/// `max_rank` and `newer_rank` are the ranks of the bounds of the page.
+///
+/// `None` if the page may go on past the oldest cached entry.
+fn window(len: i64, max_rank: Option<i64>, newer_rank: Option<i64>, page: &Pagination) -> Option<Range<i64>> {
+    let top = max_rank.map_or(0, |rank| rank + 1);
+    let bottom = newer_rank.unwrap_or(len);
+
+    // A full cache has dropped older entries, which only the database still has.
+    if newer_rank.is_none() && top + page.limit() > len && len >= TimelineCursor::HOME_CAPACITY {
+        return None;
+    }
+
+    let (start, end) = if page.is_ascending() {
+        ((bottom - page.limit()).max(top), bottom)
+    } else {
+        (top, (top + page.limit()).min(bottom))
+    };
+    Some(start..end.max(start))
+}
+
+pub(in crate::database) struct Internal;
+
+impl Internal {
+    pub async fn push(accounts: &[AccountId], entry: &TimelineCursor, con: &mut Connection) -> Result<(), DriverError> {
+        let score = score(entry);
+        let member = entry.id().to_string();
+
+        let mut pipe = redis::pipe();
+        for account in accounts {
+            pipe.cmd("EVAL")
+                .arg(PUSH_SCRIPT)
+                .arg(1)
+                .arg(home_key(account))
+                .arg(score)
+                .arg(&member)
+                .arg(TimelineCursor::HOME_CAPACITY)
+                .ignore();
+        }
+        pipe.query_async::<_, ()>(&mut *con).await?;
+
+        Ok(())
+    }
+
+    pub async fn rebuild(account: &AccountId, entries: &[TimelineCursor], con: &mut Connection) -> Result<(), DriverError> {
+        let key = home_key(account);
+        let members = entries.iter()
+            .map(|entry| (score(entry), entry.id().to_string()))
+            .collect::<Vec<(f64, String)>>();
+
+        let mut pipe = redis::pipe();
+        pipe.atomic().del(&key).ignore();
+        if !members.is_empty() {
+            pipe.zadd_multiple(&key, &members).ignore()
+                .cmd("ZREMRANGEBYRANK").arg(&key).arg(0).arg(-TimelineCursor::HOME_CAPACITY - 1).ignore()
+                .expire(&key, HOME_EXPIRES_IN as usize).ignore();
+        }
+        pipe.query_async::<_, ()>(&mut *con).await?;
+
+        Ok(())
+    }
+
+    pub async fn delete(account: &AccountId, con: &mut Connection) -> Result<(), DriverError> {
+        con.del::<_, ()>(home_key(account)).await?;
+        Ok(())
+    }
+
+    pub async fn find(account: &AccountId, page: &Pagination, con: &mut Connection) -> Result<Option<Vec<Uuid>>, DriverError> {
+        let key = home_key(account);
+
+        let len: i64 = con.zcard(&key).await?;
+        if len == 0 {
+            return Ok(None);
+        }
+
+        // A bound missing from the cache is older than all of it, or not in the timeline at all.
+        let max_rank = match page.max_id() {
+            Some(id) => match con.zrevrank::<_, _, Option<i64>>(&key, id.to_string()).await? {
+                Some(rank) => Some(rank),
+                None => return Ok(None)
+            },
+            None => None
+        };
+        let newer_rank = match page.newer_than() {
+            Some(id) => match con.zrevrank::<_, _, Option<i64>>(&key, id.to_string()).await? {
+                Some(rank) => Some(rank),
+                None => return Ok(None)
+            },
+            None => None
+        };
+
+        let Some(ranks) = window(len, max_rank, newer_rank, page) else {
+            return Ok(None);
+        };
+        if ranks.is_empty() {
+            return Ok(Some(Vec::new()));
+        }
+
+        let (ids,): (Vec<String>,) = redis::pipe()
+            .zrevrange(&key, ranks.start as isize, ranks.end as isize - 1)
+            .expire(&key, HOME_EXPIRES_IN as usize).ignore()
+            .query_async(&mut *con)
+            .await?;
+
+        ids.iter()
+            .map(|id| Uuid::parse_str(id)
+                .map_err(|e| DriverError::Convert(format!("failed parse timeline entry. `uuid`: {}", e))))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+}
+
+/// Keeps home timelines in process memory, for tests and single process setups.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryTimelineCache {
+    /// Entries of each timeline, newest first.
+    timelines: Arc<Mutex<HashMap<AccountId, Vec<TimelineCursor>>>>
+}
+
+impl MemoryTimelineCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn timelines(&self) -> std::sync::MutexGuard<'_, HashMap<AccountId, Vec<TimelineCursor>>> {
+        // The map is never left half updated, so a poisoned lock is still usable.
+        self.timelines.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Orders newest first, the same as `ZREVRANGE` over scores and members.
+fn newest_first(a: &TimelineCursor, b: &TimelineCursor) -> std::cmp::Ordering {
+    (b.created_at().as_ref(), b.id()).cmp(&(a.created_at().as_ref(), a.id()))
+}
+
+#[async_trait::async_trait]
+impl TimelineCacheRepository for MemoryTimelineCache {
+    async fn push(&self, accounts: &[AccountId], entry: &TimelineCursor) -> Result<(), KernelError> {
+        let mut timelines = self.timelines();
+        for account in accounts {
+            let Some(timeline) = timelines.get_mut(account) else {
+                continue;
+            };
+            if let Err(at) = timeline.binary_search_by(|cached| newest_first(cached, entry)) {
+                timeline.insert(at, entry.clone());
+                timeline.truncate(TimelineCursor::HOME_CAPACITY as usize);
+            }
+        }
+        Ok(())
+    }
+
+    async fn rebuild(&self, account: &AccountId, entries: &[TimelineCursor]) -> Result<(), KernelError> {
+        let mut timeline = entries.to_vec();
+        timeline.sort_by(newest_first);
+        timeline.dedup();
+        timeline.truncate(TimelineCursor::HOME_CAPACITY as usize);
+
+        let mut timelines = self.timelines();
+        // Like an empty sorted set in Redis, an empty timeline is not cached.
+        if timeline.is_empty() {
+            timelines.remove(account);
+        } else {
+            timelines.insert(*account, timeline);
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, account: &AccountId) -> Result<(), KernelError> {
+        self.timelines().remove(account);
+        Ok(())
+    }
+
+    async fn find(&self, account: &AccountId, page: &Pagination) -> Result<Option<Vec<Uuid>>, KernelError> {
+        let timelines = self.timelines();
+        let Some(timeline) = timelines.get(account) else {
+            return Ok(None);
+        };
+
+        let rank = |id: &Uuid| timeline.iter()
+            .position(|cached| cached.id() == id)
+            .map(|rank| rank as i64);
+        let max_rank = match page.max_id() {
+            Some(id) => match rank(id) {
+                Some(rank) => Some(rank),
+                None => return Ok(None)
+            },
+            None => None
+        };
+        let newer_rank = match page.newer_than() {
+            Some(id) => match rank(id) {
+                Some(rank) => Some(rank),
+                None => return Ok(None)
+            },
+            None => None
+        };
+
+        let Some(ranks) = window(timeline.len() as i64, max_rank, newer_rank, page) else {
+            return Ok(None);
+        };
+
+        Ok(Some(timeline[ranks.start as usize..ranks.end as usize].iter()
+            .map(|cached| *cached.id())
+            .collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use deadpool_redis::{Config, Pool, Runtime};
+    use kernel::{
+        repository::TimelineCacheRepository,
+        entities::{AccountId, Pagination, TimelineCursor}
+    };
+    use time::PrimitiveDateTime;
+    use time_macros::{date, time};
+    use uuid::Uuid;
+
+    use super::{MemoryTimelineCache, TimelineCacheDataBase};
+
+    fn test_pool() -> anyhow::Result<Pool> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("REDIS_URL")
+            .expect("`REDIS_URL` is not set. This is a required environment variable.");
+        let pool = Config::from_url(url)
+            .create_pool(Some(Runtime::Tokio1))?;
+
+        Ok(pool)
+    }
+
+    async fn scenario(cache: &impl TimelineCacheRepository) -> anyhow::Result<()> {
+        let created_at = PrimitiveDateTime::new(date!(2023-5-10), time!(0:00)).assume_utc();
+
+        let a = AccountId::new(i64::MAX - 1);
+        let b = AccountId::new(i64::MAX - 2);
+        cache.delete(&a).await?;
+        cache.delete(&b).await?;
+
+        let entry = |minutes: i64| TimelineCursor::new(Uuid::new_v4(), created_at + time::Duration::minutes(minutes));
+        let ids = |entries: &[&TimelineCursor]| entries.iter().map(|entry| *entry.id()).collect::<Vec<_>>();
+
+        let e0 = entry(0);
+        let e1 = entry(1);
+        let e2 = entry(2);
+        let e3 = entry(3);
+
+        // Entries may be given in any order.
+        cache.rebuild(&a, &[e1.clone(), e0.clone(), e2.clone()]).await?;
+
+        // Only cached timelines get pushed to.
+        cache.push(&[a, b], &e3).await?;
+        assert!(cache.find(&b, &Pagination::default()).await?.is_none());
+
+        let found = cache.find(&a, &Pagination::default()).await?;
+        assert_eq!(found, Some(ids(&[&e3, &e2, &e1, &e0])));
+
+        let page = Pagination::new(Some(*e3.id()), None, None, Some(2));
+        assert_eq!(cache.find(&a, &page).await?, Some(ids(&[&e2, &e1])));
+
+        let page = Pagination::new(None, Some(*e0.id()), None, Some(2));
+        assert_eq!(cache.find(&a, &page).await?, Some(ids(&[&e3, &e2])));
+
+        let page = Pagination::new(None, None, Some(*e0.id()), Some(2));
+        assert_eq!(cache.find(&a, &page).await?, Some(ids(&[&e2, &e1])));
+
+        let page = Pagination::new(Some(*e0.id()), None, None, None);
+        assert_eq!(cache.find(&a, &page).await?, Some(vec![]));
+
+        // Bounds the cache does not hold are left to the database.
+        let page = Pagination::new(Some(Uuid::new_v4()), None, None, None);
+        assert!(cache.find(&a, &page).await?.is_none());
+
+        cache.delete(&a).await?;
+        assert!(cache.find(&a, &Pagination::default()).await?.is_none());
+
+        Ok(())
+    }
+
+    async fn scenario_capacity(cache: &impl TimelineCacheRepository) -> anyhow::Result<()> {
+        let created_at = PrimitiveDateTime::new(date!(2023-5-10), time!(0:00)).assume_utc();
+
+        let a = AccountId::new(i64::MAX - 3);
+
+        let entries = (0..TimelineCursor::HOME_CAPACITY + 1)
+            .map(|seconds| TimelineCursor::new(Uuid::new_v4(), created_at + time::Duration::seconds(seconds)))
+            .collect::<Vec<_>>();
+        cache.rebuild(&a, &entries).await?;
+
+        // The oldest entry is dropped, so a page reaching it is not served.
+        let oldest = Pagination::new(Some(*entries[2].id()), None, None, None);
+        assert!(cache.find(&a, &oldest).await?.is_none());
+
+        let page = Pagination::new(Some(*entries[3].id()), None, None, Some(1));
+        assert_eq!(cache.find(&a, &page).await?, Some(vec![*entries[2].id()]));
+
+        cache.delete(&a).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_memory() -> anyhow::Result<()> {
+        let cache = MemoryTimelineCache::new();
+        scenario(&cache).await?;
+        scenario_capacity(&cache).await
+    }
+
+    #[ignore = "It depends on Redis and does not work as is."]
+    #[tokio::test]
+    async fn test_redis() -> anyhow::Result<()> {
+        let cache = TimelineCacheDataBase::new(test_pool()?);
+        scenario(&cache).await?;
+        scenario_capacity(&cache).await
+    }
+}
\ No newline at end of file
@@ -0,0 +1,542 @@
+use kernel::{
+    entities::{AccountId, AccountKey, Delivery, DeliveryHost, DeliveryStatus, RequestSigner},
+    repository::{AccountKeyRepository, DeliveryHostRepository, DeliveryRepository},
+    service::Deliverer,
+    KernelError
+};
+use time::{Duration, OffsetDateTime};
+
+/// Takes due deliveries from the queue and posts them.
+///
+/// Any number of workers can run at once, in one process or several, as claiming a delivery hides it from the others.
+pub struct DeliveryWorker<D, H, K, S> {
+    delivery_repo: D,
+    host_repo: H,
+    key_repo: K,
+    deliverer: S
+}
+
+impl<D, H, K, S> DeliveryWorker<D, H, K, S>
+  where D: DeliveryRepository,
+        H: DeliveryHostRepository,
+        K: AccountKeyRepository,
+        S: Deliverer
+{
+    /// Deliveries claimed at a time.
+    pub const BATCH: i64 = 16;
+    /// Longer than a batch can take, so that a delivery is not claimed twice while being attempted.
+    pub const LEASE: Duration = Duration::minutes(10);
+    /// Wait between polls while the queue is empty.
+    pub const IDLE: std::time::Duration = std::time::Duration::from_secs(5);
+
+    pub fn new(delivery_repo: D, host_repo: H, key_repo: K, deliverer: S) -> Self {
+        Self { delivery_repo, host_repo, key_repo, deliverer }
+    }
+
+    /// Works through the queue until the process exits.
+    pub async fn run(&self) {
+        loop {
+            match self.run_once().await {
+                Ok(0) => tokio::time::sleep(Self::IDLE).await,
+                Ok(_) => (),
+                Err(e) => {
+                    tracing::warn!("failed to process deliveries. {:?}", e);
+                    tokio::time::sleep(Self::IDLE).await;
+                }
+            }
+        }
+    }
+
+    /// Attempts one batch of due deliveries and returns how many were claimed.
+    /// An error with one delivery is logged and that delivery retried later, so the rest of the batch still goes out.
+    pub async fn run_once(&self) -> Result<usize, KernelError> {
+        let now = OffsetDateTime::now_utc();
+        let claimed = self.delivery_repo.claim(&now, &Self::LEASE, Self::BATCH).await?;
+
+        for delivery in &claimed {
+            if let Err(e) = self.process(delivery, now).await {
+                tracing::warn!("failed to process a delivery to `{}`. {:?}", delivery.host(), e);
+                self.reschedule(delivery).await;
+            }
+        }
+
+        Ok(claimed.len())
+    }
+
+    async fn process(&self, delivery: &Delivery, now: OffsetDateTime) -> Result<(), KernelError> {
+        let host = self.host_repo.find_by_host(delivery.host()).await?
+            .unwrap_or_else(|| DeliveryHost::alive(delivery.host()));
+
+        if !host.is_available(now) {
+            tracing::debug!("dropped a delivery to dead host `{}`.", host.host());
+            self.delivery_repo.delete(delivery.id()).await?;
+            return Ok(());
+        }
+
+        match self.attempt(delivery).await? {
+            // A refusal still shows that the host is up.
+            DeliveryStatus::Delivered | DeliveryStatus::Rejected => {
+                self.delivery_repo.delete(delivery.id()).await?;
+                if host.failures() > 0 {
+                    self.host_repo.save(&host.succeeded()).await?;
+                }
+            },
+            DeliveryStatus::Failed => {
+                let host = host.failed(OffsetDateTime::now_utc());
+                if host.is_dead() {
+                    tracing::info!("marked `{}` as dead after {} failed deliveries.", host.host(), host.failures());
+                }
+                self.host_repo.save(&host).await?;
+
+                match delivery.clone().retry(OffsetDateTime::now_utc()) {
+                    Some(retry) => self.delivery_repo.update(&retry).await?,
+                    None => {
+                        tracing::warn!("gave up a delivery to `{}` after {} attempts.", delivery.host(), Delivery::MAX_ATTEMPTS);
+                        self.delivery_repo.delete(delivery.id()).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Backs off a delivery that could not be processed, as for a failed attempt but without blaming the host.
+    /// If even that fails, the delivery is retried once its lease runs out.
+    async fn reschedule(&self, delivery: &Delivery) {
+        let rescheduled = match delivery.clone().retry(OffsetDateTime::now_utc()) {
+            Some(retry) => self.delivery_repo.update(&retry).await,
+            None => {
+                tracing::warn!("gave up a delivery to `{}` after {} attempts.", delivery.host(), Delivery::MAX_ATTEMPTS);
+                self.delivery_repo.delete(delivery.id()).await
+            }
+        };
+        if let Err(e) = rescheduled {
+            tracing::warn!("failed to reschedule a delivery to `{}`. {:?}", delivery.host(), e);
+        }
+    }
+
+    /// The key of `account`. Accounts created before keys existed get one on first use.
+    ///
+    /// Workers may generate a key for the same account at once. Only the first is stored,
+    /// so the key is read back rather than the generated one used.
+    async fn signing_key(&self, account: &AccountId) -> Result<AccountKey, KernelError> {
+        if let Some(key) = self.key_repo.find_by_account_id(account).await? {
+            return Ok(key);
+        }
+
+        // Generating an RSA key takes long enough to stall the executor.
+        let id = *account.as_ref();
+        let key = tokio::task::spawn_blocking(move || AccountKey::generate(id)).await
+            .map_err(|e| KernelError::External(anyhow::Error::new(e)))??;
+        self.key_repo.create(&key).await?;
+
+        self.key_repo.find_by_account_id(account).await?
+            .ok_or_else(|| KernelError::NotFound {
+                method: "signing_key",
+                entity: "account_key",
+                id: account.as_ref().to_string()
+            })
+    }
+
+    /// Posts to the shared inbox of the host if it has one, otherwise to the inbox of each recipient.
+    /// Any failure fails the whole delivery, since receivers ignore activities they have seen already.
+    async fn attempt(&self, delivery: &Delivery) -> Result<DeliveryStatus, KernelError> {
+        let key = self.signing_key(delivery.sender()).await?;
+        let signer = RequestSigner::new(delivery.key_id(), key.private_key().clone());
+
+        let mut inboxes = Vec::new();
+        for actor in delivery.recipients() {
+            let found = match self.deliverer.resolve(actor).await {
+                Ok(found) => found,
+                Err(e) => {
+                    tracing::debug!("failed to resolve `{}`. {:?}", actor, e);
+                    return Ok(DeliveryStatus::Failed);
+                }
+            };
+            let Some(found) = found else {
+                continue;
+            };
+            // Every actor on a host is served by the same shared inbox.
+            if let Some(shared_inbox) = found.shared_inbox() {
+                inboxes = vec![shared_inbox.to_string()];
+                break;
+            }
+            inboxes.push(found.inbox().to_string());
+        }
+        inboxes.sort();
+        inboxes.dedup();
+
+        let mut status = DeliveryStatus::Rejected;
+        for inbox in &inboxes {
+            match self.deliverer.deliver(inbox, delivery.activity().as_bytes(), &signer).await? {
+                DeliveryStatus::Failed => return Ok(DeliveryStatus::Failed),
+                DeliveryStatus::Delivered => status = DeliveryStatus::Delivered,
+                DeliveryStatus::Rejected => ()
+            }
+        }
+
+        Ok(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        convert::Infallible,
+        net::SocketAddr,
+        sync::{Arc, Mutex}
+    };
+
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Body, Method, Request, Response, Server, StatusCode
+    };
+    use kernel::{
+        entities::*,
+        repository::{MockAccountKeyRepository, MockDeliveryHostRepository, MockDeliveryRepository},
+        KernelError
+    };
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use crate::http::HttpDriver;
+
+    use super::DeliveryWorker;
+
+    /// A request the fake inbox received.
+    #[derive(Debug, Clone)]
+    struct Received {
+        path: String,
+        headers: HashMap<String, String>,
+        body: Vec<u8>
+    }
+
+    /// A remote server running in-process. It serves actor documents under `/users/{name}`
+    /// and answers every inbox post with `status`.
+    #[derive(Clone)]
+    struct FakeInbox {
+        addr: SocketAddr,
+        shared_inbox: bool,
+        status: Arc<Mutex<StatusCode>>,
+        received: Arc<Mutex<Vec<Received>>>
+    }
+
+    impl FakeInbox {
+        async fn start(shared_inbox: bool) -> Self {
+            let status = Arc::new(Mutex::new(StatusCode::ACCEPTED));
+            let received = Arc::new(Mutex::new(Vec::new()));
+
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let addr = listener.local_addr().unwrap();
+            let inbox = Self { addr, shared_inbox, status, received };
+
+            let serving = inbox.clone();
+            let make_service = make_service_fn(move |_| {
+                let inbox = serving.clone();
+                async move { Ok::<_, Infallible>(service_fn(move |request| inbox.clone().handle(request))) }
+            });
+            let server = Server::from_tcp(listener).unwrap().serve(make_service);
+            tokio::spawn(server);
+
+            inbox
+        }
+
+        fn base(&self) -> String {
+            format!("http://{}", self.addr)
+        }
+
+        fn host(&self) -> String {
+            self.addr.to_string()
+        }
+
+        fn actor(&self, name: &str) -> String {
+            format!("{}/users/{}", self.base(), name)
+        }
+
+        fn respond_with(&self, status: StatusCode) {
+            *self.status.lock().unwrap() = status;
+        }
+
+        fn received(&self) -> Vec<Received> {
+            self.received.lock().unwrap().clone()
+        }
+
+        async fn handle(self, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+            let path = request.uri().path().to_string();
+
+            if request.method() == Method::GET {
+                let Some(name) = path.strip_prefix("/users/") else {
+                    return Ok(Response::builder().status(StatusCode::NOT_FOUND).body(Body::empty()).unwrap());
+                };
+                let id = self.actor(name);
+                let mut actor = json!({ "id": id, "type": "Person", "inbox": format!("{}/inbox", id) });
+                if self.shared_inbox {
+                    actor["endpoints"] = json!({ "sharedInbox": format!("{}/inbox", self.base()) });
+                }
+                return Ok(Response::new(Body::from(actor.to_string())));
+            }
+
+            let headers = request.headers().iter()
+                .map(|(name, value)| (name.as_str().to_string(), value.to_str().unwrap().to_string()))
+                .collect();
+            let body = hyper::body::to_bytes(request.into_body()).await.unwrap().to_vec();
+            self.received.lock().unwrap().push(Received { path, headers, body });
+
+            let status = *self.status.lock().unwrap();
+            Ok(Response::builder().status(status).body(Body::empty()).unwrap())
+        }
+    }
+
+    #[derive(Default)]
+    struct Repositories {
+        deliveries: MockDeliveryRepository,
+        hosts: MockDeliveryHostRepository,
+        keys: MockAccountKeyRepository
+    }
+
+    impl Repositories {
+        /// `claimed` is the batch the queue hands out.
+        fn new(claimed: Vec<Delivery>) -> Self {
+            let mut repos = Self::default();
+            repos.deliveries.expect_claim()
+                .times(1)
+                .returning(move |_, _, _| Ok(claimed.clone()));
+            repos
+        }
+
+        /// Hosts are found in the state `host` returns for them.
+        fn hosts(&mut self, host: impl Fn(&str) -> Option<DeliveryHost> + Send + 'static) {
+            self.hosts.expect_find_by_host()
+                .returning(move |name| Ok(host(name)));
+        }
+
+        /// The sender has `key` stored already.
+        fn key(&mut self, key: &AccountKey) {
+            let key = key.clone();
+            self.keys.expect_find_by_account_id()
+                .returning(move |_| Ok(Some(key.clone())));
+        }
+
+        fn worker(self) -> DeliveryWorker<MockDeliveryRepository, MockDeliveryHostRepository, MockAccountKeyRepository, HttpDriver> {
+            DeliveryWorker::new(self.deliveries, self.hosts, self.keys, HttpDriver::setup_local().unwrap())
+        }
+    }
+
+    fn delivery(remote: &FakeInbox, sender: AccountId, recipients: &[&str]) -> Delivery {
+        let now = OffsetDateTime::now_utc();
+        Delivery::new(
+            DeliveryId::default(),
+            sender,
+            "https://local.example/users/shuttle#main-key",
+            remote.host(),
+            recipients.iter().map(|name| remote.actor(name)),
+            r#"{"id":"https://local.example/notes/1/activity","type":"Create"}"#,
+            0,
+            now,
+            now
+        )
+    }
+
+    /// Checks that `received` was signed with `key` under the key id of `delivery`.
+    fn assert_signed(received: &Received, delivery: &Delivery, key: &AccountKey) -> anyhow::Result<()> {
+        let headers = &received.headers;
+        let signature = HttpSignature::parse(&headers["signature"])?;
+        assert_eq!(signature.key_id(), delivery.key_id());
+        let signing_string = signature.signing_string("POST", &received.path, |name| headers.get(name).cloned())?;
+        assert!(signature.verify(&signing_string, key.public_key()));
+        assert_eq!(headers["digest"], body_digest(&received.body));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shared_inbox() -> anyhow::Result<()> {
+        let remote = FakeInbox::start(true).await;
+        let delivery = delivery(&remote, AccountId::default(), &["a", "b", "c"]);
+        let delivery_id = *delivery.id();
+
+        let mut repos = Repositories::new(vec![delivery.clone()]);
+        repos.hosts(|_| None);
+        repos.deliveries.expect_delete()
+            .withf(move |id| *id == delivery_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // The sender has no key yet, so the worker creates one.
+        let stored = Arc::new(Mutex::new(None::<AccountKey>));
+        let created = Arc::clone(&stored);
+        repos.keys.expect_create()
+            .times(1)
+            .returning(move |key| {
+                *created.lock().unwrap() = Some(key.clone());
+                Ok(())
+            });
+        let found = Arc::clone(&stored);
+        repos.keys.expect_find_by_account_id()
+            .returning(move |_| Ok(found.lock().unwrap().clone()));
+
+        assert_eq!(repos.worker().run_once().await?, 1);
+
+        // Followers on the same host get one request at the shared inbox.
+        let received = remote.received();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].path, "/inbox");
+        assert_eq!(received[0].body, delivery.activity().as_bytes());
+
+        // The request verifies against the key the worker created for the sender.
+        let key = stored.lock().unwrap().clone().unwrap();
+        assert_signed(&received[0], &delivery, &key)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_personal_inboxes() -> anyhow::Result<()> {
+        let remote = FakeInbox::start(false).await;
+        let sender = AccountId::default();
+        let delivery = delivery(&remote, sender, &["a", "b", "b"]);
+
+        let mut repos = Repositories::new(vec![delivery]);
+        repos.hosts(|_| None);
+        repos.key(&AccountKey::generate(*sender.as_ref())?);
+        repos.deliveries.expect_delete()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        repos.worker().run_once().await?;
+
+        let mut paths = remote.received().into_iter().map(|received| received.path).collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["/users/a/inbox", "/users/b/inbox"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_and_dead_host() -> anyhow::Result<()> {
+        let remote = FakeInbox::start(true).await;
+        let sender = AccountId::default();
+        let key = AccountKey::generate(*sender.as_ref())?;
+
+        // A failure is retried later with backoff, and counts against the host.
+        remote.respond_with(StatusCode::SERVICE_UNAVAILABLE);
+        let before = OffsetDateTime::now_utc();
+        let mut repos = Repositories::new(vec![delivery(&remote, sender, &["a"])]);
+        repos.hosts(|_| None);
+        repos.key(&key);
+        repos.hosts.expect_save()
+            .withf(|host| host.failures() == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.deliveries.expect_update()
+            .withf(move |retry| retry.attempts() == 1 && *retry.next_attempt_at() >= before + Delivery::MIN_BACKOFF)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.deliveries.expect_delete().never();
+        repos.worker().run_once().await?;
+
+        // A refusal is not retried, and shows that the host is up again.
+        remote.respond_with(StatusCode::GONE);
+        let mut repos = Repositories::new(vec![delivery(&remote, sender, &["a"])]);
+        repos.hosts(|name| Some(DeliveryHost::new(name, 1, None::<OffsetDateTime>)));
+        repos.key(&key);
+        repos.hosts.expect_save()
+            .withf(|host| host.failures() == 0)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.deliveries.expect_update().never();
+        repos.deliveries.expect_delete()
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.worker().run_once().await?;
+
+        // Deliveries to a dead host are dropped without a request.
+        let requests = remote.received().len();
+        let mut repos = Repositories::new(vec![delivery(&remote, sender, &["a"])]);
+        repos.hosts(|name| Some(DeliveryHost::new(name, DeliveryHost::DEAD_AFTER, Some(OffsetDateTime::now_utc()))));
+        repos.keys.expect_find_by_account_id().never();
+        repos.deliveries.expect_delete()
+            .times(1)
+            .returning(|_| Ok(()));
+        assert_eq!(repos.worker().run_once().await?, 1);
+        assert_eq!(remote.received().len(), requests);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_processing() -> anyhow::Result<()> {
+        let remote = FakeInbox::start(true).await;
+        let sender = AccountId::default();
+        let key = AccountKey::generate(*sender.as_ref())?;
+        let delivered = delivery(&remote, sender, &["a"]);
+        let delivered_id = *delivered.id();
+
+        // The key of this sender cannot be read.
+        let broken = AccountId::default();
+        let failing = delivery(&remote, broken, &["b"]);
+        let failing_id = *failing.id();
+
+        let now = OffsetDateTime::now_utc();
+        let mut repos = Repositories::new(vec![failing, delivered]);
+        repos.hosts(|_| None);
+        repos.keys.expect_find_by_account_id()
+            .returning(move |account| if *account == broken {
+                Err(KernelError::Driver(anyhow::anyhow!("the key store is down.")))
+            } else {
+                Ok(Some(key.clone()))
+            });
+
+        // The error is kept to the delivery it happened in, which backs off without counting against the host.
+        repos.deliveries.expect_update()
+            .withf(move |retry| *retry.id() == failing_id && retry.attempts() == 1 && *retry.next_attempt_at() >= now + Delivery::MIN_BACKOFF)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.deliveries.expect_delete()
+            .withf(move |id| *id == delivered_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        repos.hosts.expect_save().never();
+
+        assert_eq!(repos.worker().run_once().await?, 2);
+        assert_eq!(remote.received().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stored_key() -> anyhow::Result<()> {
+        let remote = FakeInbox::start(true).await;
+        let sender = AccountId::default();
+        let delivery = delivery(&remote, sender, &["a"]);
+
+        // Another worker stores its key in the meantime, so the one generated here is not kept.
+        let stored = AccountKey::generate(*sender.as_ref())?;
+        let mut repos = Repositories::new(vec![delivery.clone()]);
+        repos.hosts(|_| None);
+        repos.keys.expect_create()
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut reads = 0;
+        let found = stored.clone();
+        repos.keys.expect_find_by_account_id()
+            .times(2)
+            .returning(move |_| {
+                reads += 1;
+                Ok((reads > 1).then(|| found.clone()))
+            });
+        repos.deliveries.expect_delete()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        repos.worker().run_once().await?;
+
+        // The stored key is the one used.
+        let received = remote.received();
+        assert_eq!(received.len(), 1);
+        assert_signed(&received[0], &delivery, &stored)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
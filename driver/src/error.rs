@@ -3,13 +3,46 @@ use kernel::KernelError;
 #[derive(Debug, thiserror::Error)]
 pub enum DriverError {
     #[error("failed execute transation. `sqlx`: {0}")]
-    SqlX(#[from] sqlx::Error)
+    SqlX(#[from] sqlx::Error),
+    #[error("failed apply migration. `sqlx`: {0}")]
+    Migrate(#[from] sqlx::migrate::MigrateError),
+    #[error("failed execute command. `redis`: {0}")]
+    Redis(#[from] deadpool_redis::redis::RedisError),
+    #[error("failed acquire connection. `deadpool`: {0}")]
+    RedisPool(#[from] deadpool_redis::PoolError),
+    #[error("failed create connection pool. `deadpool`: {0}")]
+    RedisSetup(#[from] deadpool_redis::CreatePoolError),
+    #[error("failed build mail. `lettre`: {0}")]
+    Mail(#[from] lettre::error::Error),
+    #[error("illegal mail address. `lettre`: {0}")]
+    MailAddress(#[from] lettre::address::AddressError),
+    #[error("failed send mail. `smtp`: {0}")]
+    Smtp(#[from] lettre::transport::smtp::Error),
+    #[error("failed write mail. `file`: {0}")]
+    MailFile(#[from] lettre::transport::file::Error),
+    #[error("failed request. `reqwest`: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("io error. {0}")]
+    Io(#[from] std::io::Error),
+    #[error("this value illegal. {0}")]
+    Convert(String)
 }
 
 impl From<DriverError> for KernelError {
     fn from(driver: DriverError) -> Self {
         match driver {
-            DriverError::SqlX(e) => KernelError::Driver(anyhow::Error::new(e))
+            DriverError::SqlX(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::Migrate(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::Redis(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::RedisPool(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::RedisSetup(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::Mail(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::MailAddress(e) => KernelError::Convert(e.to_string()),
+            DriverError::Smtp(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::MailFile(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::Http(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::Io(e) => KernelError::Driver(anyhow::Error::new(e)),
+            DriverError::Convert(msg) => KernelError::Convert(msg)
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,413 @@
+use std::{
+    net::IpAddr,
+    sync::Arc,
+    time::Duration
+};
+
+use kernel::{
+    entities::{DeliveryStatus, Inboxes, RemoteKey, RequestSigner},
+    service::{ActorResolver, Deliverer, KeyFetcher},
+    KernelError
+};
+use hyper::client::connect::dns::Name;
+use reqwest::{
+    dns::{Addrs, Resolve, Resolving},
+    header::{ACCEPT, CONTENT_TYPE},
+    redirect::Policy,
+    Client, StatusCode
+};
+use serde_json::Value;
+use time::OffsetDateTime;
+use url::{Host, Url};
+
+use crate::DriverError;
+
+const ACTIVITY_JSON: &str = "application/activity+json, application/ld+json; profile=\"https://www.w3.org/ns/activitystreams\"";
+const CONTENT_ACTIVITY_JSON: &str = "application/activity+json";
+const JRD_JSON: &str = "application/jrd+json, application/json";
+const MAX_REDIRECTS: usize = 3;
+
+/// Client for requests to other servers.
+///
+/// Remote servers pick the urls requested here, so addresses that are not reachable
+/// from the internet, such as loopback and private networks, are refused.
+#[derive(Clone)]
+pub struct HttpDriver {
+    client: Client,
+    allow_private: bool
+}
+
+impl HttpDriver {
+    pub fn setup() -> Result<Self, DriverError> {
+        tracing::info!("setup `http` client.");
+        Self::build(false)
+    }
+
+    /// A client that also connects to private addresses, for tests against servers on this machine.
+    #[cfg(test)]
+    pub(crate) fn setup_local() -> Result<Self, DriverError> {
+        Self::build(true)
+    }
+
+    fn build(allow_private: bool) -> Result<Self, DriverError> {
+        let redirect = Policy::custom(move |attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("too many redirects.")
+            } else if !allow_private && !is_public_url(attempt.url()) {
+                attempt.error("redirected to a private address.")
+            } else {
+                attempt.follow()
+            }
+        });
+
+        let mut builder = Client::builder()
+            .user_agent(concat!("shuttlepub/", env!("CARGO_PKG_VERSION")))
+            .connect_timeout(Duration::from_secs(5))
+            .timeout(Duration::from_secs(10))
+            .redirect(redirect);
+        if !allow_private {
+            builder = builder.dns_resolver(Arc::new(PublicResolver));
+        }
+
+        Ok(Self { client: builder.build()?, allow_private })
+    }
+
+    /// Parses `url` to be requested, or `None` if it is not http(s) or points at a private address.
+    fn target(&self, url: &str) -> Option<Url> {
+        let url = Url::parse(url).ok()
+            .filter(|url| url.scheme() == "https" || url.scheme() == "http")?;
+        if !self.allow_private && !is_public_url(&url) {
+            tracing::debug!("refused to request a private address. `url`: {}", url);
+            return None;
+        }
+        Some(url)
+    }
+}
+
+/// Resolves names with the system resolver, leaving out addresses that are not public.
+/// A name with no public address fails to resolve.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0)).await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect::<Vec<_>>();
+            if addrs.is_empty() {
+                return Err(format!("`{}` has no public address.", name.as_str()).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Whether `ip` is reachable from the internet.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            // 100.64.0.0/10 is shared by carrier-grade NATs.
+            let [a, b, ..] = ip.octets();
+            let shared = a == 100 && (b & 0xc0) == 64;
+            !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified()
+                || ip.is_broadcast() || ip.is_multicast() || ip.is_documentation() || shared)
+        },
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public(ip.into()),
+            None => !(ip.is_loopback() || ip.is_unspecified() || ip.is_multicast()
+                || ip.is_unique_local() || ip.is_unicast_link_local())
+        }
+    }
+}
+
+/// Whether `url` does not name a private address. Host names are checked when they are resolved.
+fn is_public_url(url: &Url) -> bool {
+    match url.host() {
+        Some(Host::Ipv4(ip)) => is_public(ip.into()),
+        Some(Host::Ipv6(ip)) => is_public(ip.into()),
+        Some(Host::Domain(_)) => true,
+        None => false
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyFetcher for HttpDriver {
+    async fn fetch(&self, key_id: &str) -> Result<Option<RemoteKey>, KernelError> {
+        let Some(url) = self.target(key_id) else {
+            return Ok(None);
+        };
+
+        let response = self.client.get(url)
+            .header(ACCEPT, ACTIVITY_JSON)
+            .send()
+            .await
+            .map_err(DriverError::Http)?;
+
+        if matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::GONE) {
+            return Ok(None);
+        }
+
+        let body = response.error_for_status()
+            .map_err(DriverError::Http)?
+            .bytes()
+            .await
+            .map_err(DriverError::Http)?;
+
+        let Ok(document) = serde_json::from_slice::<Value>(&body) else {
+            tracing::debug!("key document is not json. `key_id`: {}", key_id);
+            return Ok(None);
+        };
+
+        Ok(find_key(&document, key_id, OffsetDateTime::now_utc()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Deliverer for HttpDriver {
+    async fn resolve(&self, actor: &str) -> Result<Option<Inboxes>, KernelError> {
+        let Some(url) = self.target(actor) else {
+            return Ok(None);
+        };
+
+        let response = self.client.get(url)
+            .header(ACCEPT, ACTIVITY_JSON)
+            .send()
+            .await
+            .map_err(DriverError::Http)?;
+
+        if matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::GONE) {
+            return Ok(None);
+        }
+
+        let body = response.error_for_status()
+            .map_err(DriverError::Http)?
+            .bytes()
+            .await
+            .map_err(DriverError::Http)?;
+
+        let Ok(document) = serde_json::from_slice::<Value>(&body) else {
+            tracing::debug!("actor document is not json. `actor`: {}", actor);
+            return Ok(None);
+        };
+
+        Ok(find_inboxes(&document))
+    }
+
+    async fn deliver(&self, inbox: &str, activity: &[u8], signer: &RequestSigner) -> Result<DeliveryStatus, KernelError> {
+        let Some(url) = self.target(inbox) else {
+            return Ok(DeliveryStatus::Rejected);
+        };
+        let signed = signer.sign("POST", &url, Some(activity))?;
+
+        let mut request = self.client.post(url)
+            .header(CONTENT_TYPE, CONTENT_ACTIVITY_JSON)
+            .header("date", signed.date)
+            .header("signature", signed.signature);
+        if let Some(digest) = signed.digest {
+            request = request.header("digest", digest);
+        }
+
+        match request.body(activity.to_vec()).send().await {
+            Ok(response) => Ok(delivery_status(response.status())),
+            Err(e) => {
+                tracing::debug!("failed to deliver to `{}`. {:?}", inbox, e);
+                Ok(DeliveryStatus::Failed)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ActorResolver for HttpDriver {
+    async fn resolve_handle(&self, name: &str, host: &str) -> Result<Option<String>, KernelError> {
+        let Some(mut url) = self.target(&format!("https://{}/.well-known/webfinger", host)) else {
+            return Ok(None);
+        };
+        url.query_pairs_mut().append_pair("resource", &format!("acct:{}@{}", name, host));
+
+        let response = self.client.get(url)
+            .header(ACCEPT, JRD_JSON)
+            .send()
+            .await
+            .map_err(DriverError::Http)?;
+
+        if matches!(response.status(), StatusCode::NOT_FOUND | StatusCode::GONE) {
+            return Ok(None);
+        }
+
+        let body = response.error_for_status()
+            .map_err(DriverError::Http)?
+            .bytes()
+            .await
+            .map_err(DriverError::Http)?;
+
+        let Ok(document) = serde_json::from_slice::<Value>(&body) else {
+            tracing::debug!("webfinger document is not json. `handle`: {}@{}", name, host);
+            return Ok(None);
+        };
+
+        Ok(find_actor(&document))
+    }
+}
+
+/// `4xx` means the activity will never be accepted, except for statuses that may clear up on their own.
+/// `401` is among them because the receiver may have failed to fetch the signing key.
+fn delivery_status(status: StatusCode) -> DeliveryStatus {
+    match status {
+        status if status.is_success() => DeliveryStatus::Delivered,
+        StatusCode::UNAUTHORIZED | StatusCode::REQUEST_TIMEOUT | StatusCode::TOO_MANY_REQUESTS => DeliveryStatus::Failed,
+        status if status.is_client_error() => DeliveryStatus::Rejected,
+        _ => DeliveryStatus::Failed
+    }
+}
+
+/// The ActivityPub actor among the `self` links of a WebFinger document.
+fn find_actor(document: &Value) -> Option<String> {
+    document.get("links")?
+        .as_array()?
+        .iter()
+        .filter(|link| link.get("rel").and_then(Value::as_str) == Some("self"))
+        .find(|link| link.get("type")
+            .and_then(Value::as_str)
+            .is_some_and(|kind| kind == CONTENT_ACTIVITY_JSON || kind.starts_with("application/ld+json")))
+        .and_then(|link| link.get("href"))
+        .and_then(Value::as_str)
+        .filter(|href| Url::parse(href).is_ok_and(|url| url.scheme() == "https" || url.scheme() == "http"))
+        .map(str::to_string)
+}
+
+fn find_inboxes(document: &Value) -> Option<Inboxes> {
+    let inbox = document.get("inbox").and_then(Value::as_str)?;
+    let shared_inbox = document.get("endpoints")
+        .and_then(|endpoints| endpoints.get("sharedInbox"))
+        .and_then(Value::as_str);
+    Some(Inboxes::new(inbox, shared_inbox))
+}
+
+/// Finds `key_id` in either an actor document or a bare key document.
+/// The key is only trusted if its owner lives on the same host as the key.
+fn find_key(document: &Value, key_id: &str, fetched_at: OffsetDateTime) -> Option<RemoteKey> {
+    let str_of = |value: &Value, field: &str| value.get(field).and_then(Value::as_str).map(str::to_string);
+
+    let (key, owner) = if document.get("publicKeyPem").is_some() {
+        (document, str_of(document, "owner")?)
+    } else {
+        let key = match document.get("publicKey")? {
+            Value::Array(keys) => keys.iter().find(|key| key.get("id").and_then(Value::as_str) == Some(key_id))?,
+            key => key
+        };
+        (key, str_of(key, "owner").or_else(|| str_of(document, "id"))?)
+    };
+
+    if key.get("id").and_then(Value::as_str) != Some(key_id) {
+        return None;
+    }
+
+    let same_host = |a: &str, b: &str| match (Url::parse(a), Url::parse(b)) {
+        (Ok(a), Ok(b)) => a.host_str().is_some() && a.host_str() == b.host_str(),
+        _ => false
+    };
+    if !same_host(key_id, &owner) {
+        return None;
+    }
+
+    Some(RemoteKey::new(key_id, owner, str_of(key, "publicKeyPem")?, fetched_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::client::connect::dns::Name;
+    use kernel::service::KeyFetcher;
+    use reqwest::dns::Resolve;
+    use serde_json::json;
+    use time::OffsetDateTime;
+
+    use super::{find_actor, find_key, is_public, HttpDriver, PublicResolver};
+
+    #[test]
+    fn test_find_key() {
+        let key_id = "https://remote.example/users/a#main-key";
+        let now = OffsetDateTime::now_utc();
+        let actor = json!({
+            "id": "https://remote.example/users/a",
+            "type": "Person",
+            "publicKey": {
+                "id": key_id,
+                "owner": "https://remote.example/users/a",
+                "publicKeyPem": "pem"
+            }
+        });
+        let found = find_key(&actor, key_id, now).unwrap();
+        assert_eq!(found.owner(), "https://remote.example/users/a");
+        assert_eq!(found.public_key().as_ref(), "pem");
+
+        // Some servers serve the key on its own, or several keys at once.
+        let key = json!({ "id": key_id, "owner": "https://remote.example/users/a", "publicKeyPem": "pem" });
+        assert_eq!(find_key(&key, key_id, now), Some(found.clone()));
+        let keys = json!({
+            "id": "https://remote.example/users/a",
+            "publicKey": [
+                { "id": "https://remote.example/users/a#other-key", "publicKeyPem": "other" },
+                { "id": key_id, "publicKeyPem": "pem" }
+            ]
+        });
+        assert_eq!(find_key(&keys, key_id, now), Some(found));
+
+        // A document for a different key, or a key claimed for an actor elsewhere.
+        assert!(find_key(&actor, "https://remote.example/users/b#main-key", now).is_none());
+        let foreign = json!({ "id": key_id, "owner": "https://elsewhere.example/users/a", "publicKeyPem": "pem" });
+        assert!(find_key(&foreign, key_id, now).is_none());
+    }
+
+    #[test]
+    fn test_find_actor() {
+        let document = json!({
+            "subject": "acct:a@remote.example",
+            "links": [
+                { "rel": "http://webfinger.net/rel/profile-page", "type": "text/html", "href": "https://remote.example/@a" },
+                { "rel": "self", "type": "application/activity+json", "href": "https://remote.example/users/a" }
+            ]
+        });
+        assert_eq!(find_actor(&document).as_deref(), Some("https://remote.example/users/a"));
+
+        let ld = json!({
+            "links": [{
+                "rel": "self",
+                "type": "application/ld+json; profile=\"https://www.w3.org/ns/activitystreams\"",
+                "href": "https://remote.example/users/a"
+            }]
+        });
+        assert_eq!(find_actor(&ld).as_deref(), Some("https://remote.example/users/a"));
+
+        // Only an ActivityPub `self` link points to the actor.
+        let profile_only = json!({ "links": [{ "rel": "self", "type": "text/html", "href": "https://remote.example/@a" }] });
+        assert!(find_actor(&profile_only).is_none());
+        let broken = json!({ "links": [{ "rel": "self", "type": "application/activity+json", "href": "not a url" }] });
+        assert!(find_actor(&broken).is_none());
+        assert!(find_actor(&json!({})).is_none());
+    }
+
+    #[test]
+    fn test_is_public() {
+        for ip in ["93.184.216.34", "2606:2800:220:1::1"] {
+            assert!(is_public(ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in [
+            "127.0.0.1", "10.0.0.1", "172.16.0.1", "192.168.1.1", "169.254.169.254", "100.64.0.1", "0.0.0.0",
+            "::1", "::", "fc00::1", "fe80::1", "::ffff:127.0.0.1"
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_private_addresses() {
+        // Names are checked after resolution, addresses in urls before connecting.
+        assert!(PublicResolver.resolve("localhost".parse::<Name>().unwrap()).await.is_err());
+
+        let http = HttpDriver::setup().unwrap();
+        assert!(http.fetch("http://127.0.0.1:1/users/a#main-key").await.unwrap().is_none());
+        assert!(http.fetch("http://[::1]:1/users/a#main-key").await.unwrap().is_none());
+        assert!(http.fetch("http://localhost:1/users/a#main-key").await.is_err());
+    }
+}
\ No newline at end of file
@@ -1,4 +1,8 @@
 pub mod postgres;
+pub mod redis;
+pub mod mail;
+pub mod http;
+pub mod delivery;
 pub mod database;
 mod error;
 
@@ -0,0 +1,144 @@
+use std::{path::Path, sync::Arc};
+
+use kernel::{entities::Mail, service::Mailer, KernelError};
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncFileTransport, AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor
+};
+
+use crate::DriverError;
+
+fn build(from: &Mailbox, mail: &Mail) -> Result<Message, DriverError> {
+    let message = Message::builder()
+        .from(from.clone())
+        .to(mail.to().as_ref().parse()?)
+        .subject(mail.subject())
+        .header(ContentType::TEXT_PLAIN)
+        .body(mail.body().to_string())?;
+    Ok(message)
+}
+
+#[derive(Clone)]
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox
+}
+
+impl SmtpMailer {
+    pub fn new(transport: AsyncSmtpTransport<Tokio1Executor>, from: Mailbox) -> Self {
+        Self { transport, from }
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: &Mail) -> Result<(), KernelError> {
+        let message = build(&self.from, mail)?;
+        self.transport.send(message).await
+            .map_err(DriverError::Smtp)?;
+        Ok(())
+    }
+}
+
+/// Writes each message as an `.eml` file instead of delivering it. Meant for development and tests.
+#[derive(Clone)]
+pub struct FileMailer {
+    transport: Arc<AsyncFileTransport<Tokio1Executor>>,
+    from: Mailbox
+}
+
+impl FileMailer {
+    pub fn new(dir: impl AsRef<Path>, from: Mailbox) -> Self {
+        Self { transport: Arc::new(AsyncFileTransport::new(dir)), from }
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, mail: &Mail) -> Result<(), KernelError> {
+        let message = build(&self.from, mail)?;
+        let id = self.transport.send(message).await
+            .map_err(DriverError::MailFile)?;
+        tracing::debug!("mail written. `id`: {}", id);
+        Ok(())
+    }
+}
+
+/// Mailer selected by the environment.
+/// `SMTP_HOST` enables SMTP delivery, otherwise messages are written to `MAIL_DIR`.
+#[derive(Clone)]
+pub enum MailDriver {
+    Smtp(SmtpMailer),
+    File(FileMailer)
+}
+
+impl MailDriver {
+    pub fn setup() -> Result<Self, DriverError> {
+        tracing::info!("enviroment variable check.");
+        dotenvy::dotenv().ok();
+        let from = dotenvy::var("MAIL_FROM")
+            .expect("`MAIL_FROM` does not set! This value required.")
+            .parse::<Mailbox>()?;
+
+        let driver = match dotenvy::var("SMTP_HOST") {
+            Ok(host) => {
+                tracing::info!("setup `smtp` transport.");
+                let mut builder = AsyncSmtpTransport::<Tokio1Executor>::relay(&host)?;
+                if let (Ok(user), Ok(pass)) = (dotenvy::var("SMTP_USER"), dotenvy::var("SMTP_PASS")) {
+                    builder = builder.credentials(Credentials::new(user, pass));
+                }
+                Self::Smtp(SmtpMailer::new(builder.build(), from))
+            },
+            Err(_) => {
+                let dir = dotenvy::var("MAIL_DIR").unwrap_or_else(|_| "./mails".to_string());
+                tracing::info!("`SMTP_HOST` is not set. mails are written to `{}`.", dir);
+                std::fs::create_dir_all(&dir)?;
+                Self::File(FileMailer::new(dir, from))
+            }
+        };
+
+        tracing::info!("setup successful!");
+
+        Ok(driver)
+    }
+}
+
+#[async_trait::async_trait]
+impl Mailer for MailDriver {
+    async fn send(&self, mail: &Mail) -> Result<(), KernelError> {
+        match self {
+            MailDriver::Smtp(mailer) => mailer.send(mail).await,
+            MailDriver::File(mailer) => mailer.send(mail).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kernel::{entities::Mail, service::Mailer};
+
+    use super::FileMailer;
+
+    #[tokio::test]
+    async fn test_file() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("shuttle-mail-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+
+        let mailer = FileMailer::new(&dir, "ShuttlePub <noreply@example.com>".parse()?);
+        mailer.send(&Mail::new("shuttle@example.com", "Verify your address", "token: abcdef")).await?;
+
+        let written = std::fs::read_dir(&dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(written.len(), 1);
+
+        let eml = std::fs::read_to_string(&written[0])?;
+        assert!(eml.contains("To: shuttle@example.com"));
+        assert!(eml.contains("Subject: Verify your address"));
+        assert!(eml.contains("token: abcdef"));
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
\ No newline at end of file
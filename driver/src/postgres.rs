@@ -1,9 +1,12 @@
 use std::time::Duration;
 
-use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
+use sqlx::{Pool, Postgres, migrate::Migrator, postgres::PgPoolOptions};
 
 use crate::DriverError;
 
+/// Every migration under `/migrations`, embedded at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("../migrations");
+
 pub struct DataBaseDriver;
 
 impl DataBaseDriver {
@@ -18,6 +21,10 @@ impl DataBaseDriver {
             .connect(&url)
             .await?;
 
+        tracing::info!("apply pending migrations.");
+
+        MIGRATOR.run(&pool).await?;
+
         tracing::info!("setup successful!");
         
         Ok(pool)
@@ -32,4 +39,46 @@ impl DataBaseDriver {
 
         url
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use sqlx::{Connection, Executor, PgConnection, postgres::{PgConnectOptions, PgPoolOptions}};
+    use uuid::Uuid;
+
+    use super::MIGRATOR;
+
+    #[ignore = "It depends on Postgres and does not work as is."]
+    #[tokio::test]
+    async fn test_migrate() -> anyhow::Result<()> {
+        dotenvy::dotenv().ok();
+
+        let url = dotenvy::var("DATABASE_URL")
+            .expect("`DATABASE_URL` is not set. This is a required environment variable.");
+        let options = PgConnectOptions::from_str(&url)?;
+
+        let name = format!("shuttlepub_migrate_{}", Uuid::new_v4().simple());
+        let mut admin = PgConnection::connect_with(&options).await?;
+        admin.execute(format!(r#"CREATE DATABASE "{}""#, name).as_str()).await?;
+
+        let applied = async {
+            let pool = PgPoolOptions::new()
+                .max_connections(1)
+                .connect_with(options.clone().database(&name))
+                .await?;
+
+            MIGRATOR.run(&pool).await?;
+            // Already applied migrations are skipped.
+            MIGRATOR.run(&pool).await?;
+
+            pool.close().await;
+            anyhow::Ok(())
+        }.await;
+
+        admin.execute(format!(r#"DROP DATABASE "{}" WITH (FORCE)"#, name).as_str()).await?;
+
+        applied
+    }
+}
@@ -0,0 +1,33 @@
+use deadpool_redis::{Config, Pool, Runtime};
+
+use crate::DriverError;
+
+pub struct RedisDriver;
+
+impl RedisDriver {
+    pub async fn setup() -> Result<Pool, DriverError> {
+        let url = Self::env_setup();
+
+        tracing::info!("setup `redis` connection pool.");
+
+        let pool = Config::from_url(url)
+            .create_pool(Some(Runtime::Tokio1))?;
+
+        // Fail fast on misconfiguration rather than on the first request.
+        let _ = pool.get().await?;
+
+        tracing::info!("setup successful!");
+
+        Ok(pool)
+    }
+
+    fn env_setup() -> String {
+        tracing::info!("enviroment variable check.");
+        dotenvy::dotenv().ok();
+        let url = dotenvy::var("REDIS_URL")
+            .expect("`REDIS_URL` does not set! This value required.");
+        tracing::info!("checked.");
+
+        url
+    }
+}
@@ -2,10 +2,51 @@ mod update_time;
 mod account;
 mod profile;
 mod follow;
+mod confidential;
+mod session;
+mod scope;
+mod application;
+mod authorization_code;
+mod oauth_token;
+mod verification;
+mod password_reset;
+mod note;
+mod hashtag;
+mod pagination;
+mod reaction;
+mod boost;
+mod timeline;
+mod remote_note;
+mod activity;
+mod delivery;
+mod key;
+mod signature;
+mod mail;
+mod random;
 
 pub use self::{
     account::*,
     profile::*,
     follow::*,
+    confidential::*,
+    session::*,
+    scope::*,
+    application::*,
+    authorization_code::*,
+    oauth_token::*,
+    verification::*,
+    password_reset::*,
+    note::*,
+    hashtag::*,
+    pagination::*,
+    reaction::*,
+    boost::*,
+    timeline::*,
+    remote_note::*,
+    activity::*,
+    delivery::*,
+    key::*,
+    signature::*,
+    mail::*,
     update_time::*
 };
\ No newline at end of file
@@ -1,9 +1,13 @@
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 use destructure::Destructure;
 use time::OffsetDateTime;
 
 use super::UpdateTime;
 
+use crate::error::KernelError;
+
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountId(i64);
 
@@ -50,9 +54,94 @@ impl AsRef<str> for AccountName {
 }
 
 impl AccountName {
+    pub const MAX_LENGTH: usize = 30;
+
     pub fn new(name: impl Into<String>) -> Self {
         Self(name.into())
     }
+
+    /// Validates `name` as the local part of a `@name@host` handle.
+    ///
+    /// Names are stored as typed but compared case-insensitively,
+    /// so `Shuttle` and `shuttle` are the same account.
+    pub fn parse(name: impl Into<String>, reserved: &ReservedNames) -> Result<Self, KernelError> {
+        let name = name.into();
+        if name.is_empty() || name.len() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "account name must be between 1 and {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(KernelError::Convert(
+                "account name may only contain ascii letters, digits and `_`.".to_string()
+            ));
+        }
+        if reserved.contains(&name) {
+            return Err(KernelError::Convert(format!("account name `{}` is reserved.", name)));
+        }
+        Ok(Self(name))
+    }
+
+    /// Form used for uniqueness comparison.
+    pub fn normalized(&self) -> String {
+        self.0.to_ascii_lowercase()
+    }
+}
+
+impl TryFrom<String> for AccountName {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(value, &ReservedNames::default())
+    }
+}
+
+/// Names that cannot be registered, compared case-insensitively.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReservedNames(BTreeSet<String>);
+
+impl ReservedNames {
+    /// Reserved regardless of configuration. Mostly route names and well-known mailboxes.
+    pub const DEFAULT: &'static [&'static str] = &[
+        "about", "account", "accounts", "admin", "administrator", "api", "apps",
+        "help", "inbox", "instance", "login", "logout", "moderator", "nodeinfo",
+        "noreply", "notes", "oauth", "outbox", "postmaster", "root", "settings",
+        "shuttle", "shuttlepub", "signup", "staff", "support", "system", "tags",
+        "users", "webmaster"
+    ];
+
+    pub fn new(names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self(names.into_iter().map(|name| name.into().to_ascii_lowercase()).collect())
+    }
+
+    /// Adds `names` to the list. Used for names configured by the instance.
+    pub fn with(mut self, names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.0.extend(names.into_iter().map(|name| name.into().to_ascii_lowercase()));
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains(&name.to_ascii_lowercase())
+    }
+}
+
+impl Default for ReservedNames {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT.iter().copied())
+    }
+}
+
+/// Names of the accounts allowed to manage the instance, compared case-insensitively.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Administrators(BTreeSet<String>);
+
+impl Administrators {
+    pub fn new(names: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self(names.into_iter().map(|name| name.into().to_ascii_lowercase()).collect())
+    }
+
+    pub fn contains(&self, name: &AccountName) -> bool {
+        self.0.contains(&name.normalized())
+    }
 }
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
@@ -76,11 +165,34 @@ impl From<IsBot> for bool {
     }
 }
 
+/// Locked accounts approve each follower by hand.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IsLocked(bool);
+
+impl IsLocked {
+    pub fn new(flag: bool) -> Self {
+        Self(flag)
+    }
+}
+
+impl AsRef<bool> for IsLocked {
+    fn as_ref(&self) -> &bool {
+        &self.0
+    }
+}
+
+impl From<IsLocked> for bool {
+    fn from(flag: IsLocked) -> Self {
+        flag.0
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, Deserialize, Serialize, Destructure)]
 pub struct Account {
     id: AccountId,
     name: AccountName,
     bot: IsBot,
+    locked: IsLocked,
     date: UpdateTime
 }
 
@@ -89,6 +201,7 @@ impl Account {
         id: impl Into<i64>,
         name: impl Into<String>,
         bot: impl Into<bool>,
+        locked: impl Into<bool>,
         created_at: impl Into<OffsetDateTime>,
         updated_at: impl Into<OffsetDateTime>
     ) -> Self {
@@ -96,6 +209,7 @@ impl Account {
             id: AccountId::new(id),
             name: AccountName::new(name),
             bot: IsBot::new(bot.into()),
+            locked: IsLocked::new(locked.into()),
             date: UpdateTime::new(created_at.into(), updated_at.into())
         }
     }
@@ -112,6 +226,10 @@ impl Account {
         &self.bot
     }
 
+    pub fn locked(&self) -> &IsLocked {
+        &self.locked
+    }
+
     pub fn date(&self) -> &UpdateTime {
         &self.date
     }
@@ -121,7 +239,7 @@ impl Account {
 mod test {
     use time::OffsetDateTime;
 
-    use crate::entities::Account;
+    use crate::entities::{Account, AccountName, Administrators, ReservedNames};
 
     #[test]
     fn struct_test() {
@@ -129,8 +247,30 @@ mod test {
             1234567890, 
             "test_man", 
             false, 
+            false, 
             OffsetDateTime::now_utc(),
             OffsetDateTime::now_utc()
         );
     }
+
+    #[test]
+    fn name_test() {
+        assert!(AccountName::try_from("Test_Man_1".to_string()).is_ok());
+        assert!(AccountName::try_from("".to_string()).is_err());
+        assert!(AccountName::try_from("a".repeat(AccountName::MAX_LENGTH + 1)).is_err());
+        assert!(AccountName::try_from("test-man".to_string()).is_err());
+        assert!(AccountName::try_from("test.man".to_string()).is_err());
+        assert!(AccountName::try_from("tëst".to_string()).is_err());
+        assert!(AccountName::try_from("Admin".to_string()).is_err());
+
+        let reserved = ReservedNames::default().with(["Shuttle_Staff"]);
+        assert!(AccountName::parse("shuttle_staff", &reserved).is_err());
+        assert!(AccountName::parse("shuttle_staff", &ReservedNames::default()).is_ok());
+
+        assert_eq!(AccountName::new("Test_Man").normalized(), AccountName::new("test_man").normalized());
+
+        let admins = Administrators::new(["Shuttle_Admin"]);
+        assert!(admins.contains(&AccountName::new("shuttle_admin")));
+        assert!(!admins.contains(&AccountName::new("test_man")));
+    }
 }
\ No newline at end of file
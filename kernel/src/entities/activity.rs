@@ -0,0 +1,103 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+
+use super::{Emoji, RemoteNote, Visibility};
+
+/// ActivityPub id of an activity received from another server.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityId(String);
+
+impl ActivityId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+}
+
+impl From<ActivityId> for String {
+    fn from(id: ActivityId) -> Self {
+        id.0
+    }
+}
+
+impl AsRef<str> for ActivityId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The activity types an inbox acts on, with the objects they refer to by id.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActivityKind {
+    Follow { object: String },
+    Undo { object: Box<Activity> },
+    Create { note: RemoteNote },
+    /// `object` is the id of the deleted object, which may also be the actor itself.
+    Delete { object: String },
+    /// `content` is set when the actor reacted with a Unicode emoji instead of a plain like.
+    Like { object: String, content: Option<Emoji> },
+    Announce { object: String, visibility: Visibility }
+}
+
+impl ActivityKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Follow { .. } => "Follow",
+            Self::Undo { .. } => "Undo",
+            Self::Create { .. } => "Create",
+            Self::Delete { .. } => "Delete",
+            Self::Like { .. } => "Like",
+            Self::Announce { .. } => "Announce"
+        }
+    }
+}
+
+/// Activity delivered to an inbox by a remote `actor`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Activity {
+    id: ActivityId,
+    actor: String,
+    kind: ActivityKind
+}
+
+impl Activity {
+    pub fn new(id: impl Into<String>, actor: impl Into<String>, kind: ActivityKind) -> Self {
+        Self {
+            id: ActivityId::new(id),
+            actor: actor.into(),
+            kind
+        }
+    }
+
+    pub fn id(&self) -> &ActivityId {
+        &self.id
+    }
+
+    pub fn actor(&self) -> &str {
+        &self.actor
+    }
+
+    pub fn kind(&self) -> &ActivityKind {
+        &self.kind
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::entities::{Activity, ActivityKind};
+
+    #[test]
+    fn struct_test() {
+        let follow = Activity::new(
+            "https://remote.example/activities/1",
+            "https://remote.example/users/shuttle",
+            ActivityKind::Follow { object: "https://local.example/users/shuttle".to_string() }
+        );
+        let undo = Activity::new(
+            "https://remote.example/activities/2",
+            "https://remote.example/users/shuttle",
+            ActivityKind::Undo { object: Box::new(follow.clone()) }
+        );
+        assert_eq!(undo.kind().as_str(), "Undo");
+        assert!(matches!(undo.kind(), ActivityKind::Undo { object } if **object == follow));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,320 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use url::Url;
+use uuid::Uuid;
+
+use super::{random, Scopes, UpdateTime};
+
+use crate::error::KernelError;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationId(Uuid);
+
+impl ApplicationId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for ApplicationId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<ApplicationId> for Uuid {
+    fn from(id: ApplicationId) -> Self {
+        id.0
+    }
+}
+
+impl Default for ApplicationId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationName(String);
+
+impl From<ApplicationName> for String {
+    fn from(name: ApplicationName) -> Self {
+        name.0
+    }
+}
+
+impl AsRef<str> for ApplicationName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ApplicationName {
+    pub const MAX_LENGTH: usize = 128;
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
+impl TryFrom<String> for ApplicationName {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let name = value.trim();
+        if name.is_empty() || name.chars().count() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "application name must be between 1 and {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(name.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Website(String);
+
+impl From<Website> for String {
+    fn from(url: Website) -> Self {
+        url.0
+    }
+}
+
+impl AsRef<str> for Website {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Website {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self(url.into())
+    }
+}
+
+impl TryFrom<String> for Website {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let url = Url::parse(&value)
+            .map_err(|e| KernelError::Convert(format!("failed parse website url. `url`: {}", e)))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(KernelError::Convert("website must be http(s) url.".to_string()));
+        }
+        Ok(Self(url.into()))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientId(String);
+
+impl From<ClientId> for String {
+    fn from(id: ClientId) -> Self {
+        id.0
+    }
+}
+
+impl AsRef<str> for ClientId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ClientId {
+    pub const LENGTH: usize = 43;
+
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+}
+
+impl Default for ClientId {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClientSecret(String);
+
+impl std::fmt::Debug for ClientSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ClientSecret(**hidden**)")
+    }
+}
+
+impl From<ClientSecret> for String {
+    fn from(secret: ClientSecret) -> Self {
+        secret.0
+    }
+}
+
+impl AsRef<str> for ClientSecret {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ClientSecret {
+    pub const LENGTH: usize = 43;
+
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Compares in constant time so the secret cannot be guessed from response timing.
+    pub fn verify(&self, secret: impl AsRef<str>) -> bool {
+        let (a, b) = (self.0.as_bytes(), secret.as_ref().as_bytes());
+        a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+    }
+}
+
+impl Default for ClientSecret {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedirectUri(String);
+
+impl From<RedirectUri> for String {
+    fn from(uri: RedirectUri) -> Self {
+        uri.0
+    }
+}
+
+impl AsRef<str> for RedirectUri {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl RedirectUri {
+    /// Out-of-band redirect. The authorization code is shown to the user instead of redirected.
+    pub const OUT_OF_BAND: &'static str = "urn:ietf:wg:oauth:2.0:oob";
+
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self(uri.into())
+    }
+
+    pub fn is_out_of_band(&self) -> bool {
+        self.0 == Self::OUT_OF_BAND
+    }
+}
+
+impl TryFrom<String> for RedirectUri {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value == Self::OUT_OF_BAND {
+            return Ok(Self(value));
+        }
+
+        // Native apps register custom schemes (`com.example.app:/callback`), so any scheme is allowed.
+        let url = Url::parse(&value)
+            .map_err(|e| KernelError::Convert(format!("failed parse redirect uri. `url`: {}", e)))?;
+        if url.fragment().is_some() {
+            return Err(KernelError::Convert("redirect uri must not contain a fragment.".to_string()));
+        }
+        Ok(Self(value))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Application {
+    id: ApplicationId,
+    date: UpdateTime,
+    name: ApplicationName,
+    website: Option<Website>,
+    client_id: ClientId,
+    client_secret: ClientSecret,
+    redirect_uris: Vec<RedirectUri>,
+    scopes: Scopes
+}
+
+impl Application {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<Uuid>,
+        created_at: impl Into<OffsetDateTime>,
+        updated_at: impl Into<OffsetDateTime>,
+        name: impl Into<String>,
+        website: Option<impl Into<String>>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        redirect_uris: impl IntoIterator<Item = impl Into<String>>,
+        scopes: impl Into<Scopes>
+    ) -> Self {
+        Self {
+            id: ApplicationId::new(id.into()),
+            date: UpdateTime::new(created_at.into(), updated_at.into()),
+            name: ApplicationName::new(name),
+            website: website.map(Website::new),
+            client_id: ClientId::new(client_id),
+            client_secret: ClientSecret::new(client_secret),
+            redirect_uris: redirect_uris.into_iter().map(RedirectUri::new).collect(),
+            scopes: scopes.into()
+        }
+    }
+
+    pub fn id(&self) -> &ApplicationId {
+        &self.id
+    }
+
+    pub fn date(&self) -> &UpdateTime {
+        &self.date
+    }
+
+    pub fn name(&self) -> &ApplicationName {
+        &self.name
+    }
+
+    pub fn website(&self) -> Option<&Website> {
+        self.website.as_ref()
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    pub fn client_secret(&self) -> &ClientSecret {
+        &self.client_secret
+    }
+
+    pub fn redirect_uris(&self) -> &[RedirectUri] {
+        &self.redirect_uris
+    }
+
+    pub fn scopes(&self) -> &Scopes {
+        &self.scopes
+    }
+
+    pub fn is_registered_redirect(&self, uri: &RedirectUri) -> bool {
+        self.redirect_uris.contains(uri)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{Application, ApplicationId, ClientId, ClientSecret, RedirectUri, Scopes};
+
+    #[test]
+    fn struct_test() {
+        let app = Application::new(
+            ApplicationId::default(),
+            OffsetDateTime::now_utc(),
+            OffsetDateTime::now_utc(),
+            "Shuttle Client",
+            Some("https://example.com"),
+            ClientId::default(),
+            ClientSecret::default(),
+            ["https://example.com/callback", RedirectUri::OUT_OF_BAND],
+            Scopes::try_from("read write").unwrap()
+        );
+
+        assert!(app.is_registered_redirect(&RedirectUri::new(RedirectUri::OUT_OF_BAND)));
+        assert!(!app.is_registered_redirect(&RedirectUri::new("https://evil.example.com/callback")));
+        assert!(RedirectUri::try_from("not a uri".to_string()).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use time::{Duration, OffsetDateTime};
+
+use super::{random, AccountId, ApplicationId, CreatedAt, ExpiresAt, RedirectUri, Scopes};
+
+use crate::error::KernelError;
+
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Code(String);
+
+impl std::fmt::Debug for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Code(**hidden**)")
+    }
+}
+
+impl From<Code> for String {
+    fn from(code: Code) -> Self {
+        code.0
+    }
+}
+
+impl AsRef<str> for Code {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Code {
+    pub const LENGTH: usize = 43;
+
+    pub fn new(code: impl Into<String>) -> Self {
+        Self(code.into())
+    }
+}
+
+impl Default for Code {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+/// PKCE (RFC 7636) transformation method.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChallengeMethod {
+    Plain,
+    S256
+}
+
+impl AsRef<str> for ChallengeMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            ChallengeMethod::Plain => "plain",
+            ChallengeMethod::S256 => "S256"
+        }
+    }
+}
+
+impl TryFrom<&str> for ChallengeMethod {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "plain" => Ok(Self::Plain),
+            "S256" => Ok(Self::S256),
+            _ => Err(KernelError::Convert(format!("`{}` is not a supported code challenge method.", value)))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct CodeChallenge {
+    challenge: String,
+    method: ChallengeMethod
+}
+
+impl CodeChallenge {
+    pub fn new(challenge: impl Into<String>, method: ChallengeMethod) -> Self {
+        Self { challenge: challenge.into(), method }
+    }
+
+    pub fn challenge(&self) -> &str {
+        &self.challenge
+    }
+
+    pub fn method(&self) -> &ChallengeMethod {
+        &self.method
+    }
+
+    pub fn verify(&self, verifier: impl AsRef<str>) -> bool {
+        let verifier = verifier.as_ref();
+        // RFC 7636 4.1: 43 to 128 characters.
+        if !(43..=128).contains(&verifier.len()) {
+            return false;
+        }
+        match self.method {
+            ChallengeMethod::Plain => self.challenge == verifier,
+            ChallengeMethod::S256 => {
+                let digest = Sha256::digest(verifier.as_bytes());
+                URL_SAFE_NO_PAD.encode(digest) == self.challenge
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct AuthorizationCode {
+    code: Code,
+    application: ApplicationId,
+    account: AccountId,
+    redirect_uri: RedirectUri,
+    scopes: Scopes,
+    challenge: Option<CodeChallenge>,
+    created_at: CreatedAt,
+    expires_at: ExpiresAt
+}
+
+impl AuthorizationCode {
+    pub const LIFETIME: Duration = Duration::minutes(10);
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        code: impl Into<String>,
+        application: impl Into<ApplicationId>,
+        account: impl Into<i64>,
+        redirect_uri: impl Into<String>,
+        scopes: impl Into<Scopes>,
+        challenge: Option<CodeChallenge>,
+        created_at: impl Into<OffsetDateTime>,
+        expires_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            code: Code::new(code),
+            application: application.into(),
+            account: AccountId::new(account),
+            redirect_uri: RedirectUri::new(redirect_uri),
+            scopes: scopes.into(),
+            challenge,
+            created_at: CreatedAt::new(created_at.into()),
+            expires_at: ExpiresAt::new(expires_at.into())
+        }
+    }
+
+    pub fn code(&self) -> &Code {
+        &self.code
+    }
+
+    pub fn application(&self) -> &ApplicationId {
+        &self.application
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn redirect_uri(&self) -> &RedirectUri {
+        &self.redirect_uri
+    }
+
+    pub fn scopes(&self) -> &Scopes {
+        &self.scopes
+    }
+
+    pub fn challenge(&self) -> Option<&CodeChallenge> {
+        self.challenge.as_ref()
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &ExpiresAt {
+        &self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, ApplicationId, AuthorizationCode, ChallengeMethod, Code, CodeChallenge, Scopes};
+
+    #[test]
+    fn struct_test() {
+        let now = OffsetDateTime::now_utc();
+        let _code = AuthorizationCode::new(
+            Code::default(),
+            ApplicationId::default(),
+            AccountId::default(),
+            "https://example.com/callback",
+            Scopes::all(),
+            None,
+            now,
+            now + AuthorizationCode::LIFETIME
+        );
+    }
+
+    #[test]
+    fn challenge_test() {
+        // RFC 7636 Appendix B.
+        let verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        let challenge = CodeChallenge::new("E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM", ChallengeMethod::S256);
+        assert!(challenge.verify(verifier));
+        assert!(!challenge.verify("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXz"));
+
+        let plain = CodeChallenge::new(verifier, ChallengeMethod::Plain);
+        assert!(plain.verify(verifier));
+    }
+}
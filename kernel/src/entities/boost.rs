@@ -0,0 +1,107 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{AccountTypes, CreatedAt, NoteTypes, Visibility};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoostId(Uuid);
+
+impl BoostId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for BoostId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<BoostId> for Uuid {
+    fn from(id: BoostId) -> Self {
+        id.0
+    }
+}
+
+impl Default for BoostId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Reshare of a note by an account, without content of its own.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Boost {
+    id: BoostId,
+    account: AccountTypes,
+    note: NoteTypes,
+    /// Never wider than the visibility of the boosted note.
+    visibility: Visibility,
+    /// Inferred from a remote activity rather than requested by the account.
+    implicit: bool,
+    created_at: CreatedAt
+}
+
+impl Boost {
+    pub fn new(
+        id: impl Into<Uuid>,
+        account: impl Into<AccountTypes>,
+        note: impl Into<NoteTypes>,
+        visibility: Visibility,
+        implicit: bool,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: BoostId::new(id.into()),
+            account: account.into(),
+            note: note.into(),
+            visibility,
+            implicit,
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &BoostId {
+        &self.id
+    }
+
+    pub fn account(&self) -> &AccountTypes {
+        &self.account
+    }
+
+    pub fn note(&self) -> &NoteTypes {
+        &self.note
+    }
+
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    pub fn is_implicit(&self) -> bool {
+        self.implicit
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, AccountTypes, Boost, BoostId, NoteId, NoteTypes, Visibility};
+
+    #[test]
+    fn struct_test() {
+        let account = AccountId::default();
+        let note = NoteId::default();
+        let boost = Boost::new(BoostId::default(), account, note, Visibility::Unlisted, false, OffsetDateTime::now_utc());
+        assert_eq!(boost.account(), &AccountTypes::Local(account));
+        assert_eq!(boost.note(), &NoteTypes::Local(note));
+        assert!(!boost.is_implicit());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,253 @@
+use argon2::{
+    Argon2,
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString}
+};
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{AccountId, UpdateTime};
+
+use crate::error::KernelError;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfidentialId(Uuid);
+
+impl ConfidentialId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for ConfidentialId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<ConfidentialId> for Uuid {
+    fn from(id: ConfidentialId) -> Self {
+        id.0
+    }
+}
+
+impl Default for ConfidentialId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Address(String);
+
+impl From<Address> for String {
+    fn from(address: Address) -> Self {
+        address.0
+    }
+}
+
+impl AsRef<str> for Address {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Address {
+    /// Same as `confidentials.address` column length.
+    pub const MAX_LENGTH: usize = 128;
+
+    pub fn new(address: impl Into<String>) -> Self {
+        Self(address.into())
+    }
+}
+
+impl TryFrom<String> for Address {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let address = value.trim();
+        if address.len() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!("email address must be at most {} bytes.", Self::MAX_LENGTH)));
+        }
+
+        let Some((local, domain)) = address.split_once('@') else {
+            return Err(KernelError::Convert("email address must contain `@`.".to_string()));
+        };
+
+        if local.is_empty()
+            || domain.is_empty()
+            || domain.contains('@')
+            || !domain.contains('.')
+            || domain.starts_with('.')
+            || domain.ends_with('.')
+            || address.chars().any(char::is_whitespace) {
+            return Err(KernelError::Convert(format!("`{}` is not a valid email address.", address)));
+        }
+
+        // The domain part is case-insensitive, so it is normalized to lowercase.
+        Ok(Self(format!("{}@{}", local, domain.to_lowercase())))
+    }
+}
+
+/// PHC formatted password hash.
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Password(String);
+
+impl std::fmt::Debug for Password {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Password(**hidden**)")
+    }
+}
+
+impl From<Password> for String {
+    fn from(pass: Password) -> Self {
+        pass.0
+    }
+}
+
+impl AsRef<str> for Password {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Password {
+    pub const MIN_LENGTH: usize = 8;
+    pub const MAX_LENGTH: usize = 128;
+
+    /// Argon2id hash with the parameters of [`Password::hash`] for a password no account has.
+    const DUMMY: &'static str = "$argon2id$v=19$m=19456,t=2,p=1$OpjTeg44vH7b9CVv62OBxQ$KhnLu03uGhl8GqWa7Lb1rvlkPZFNSEjmvM8MRrJ4zhw";
+
+    /// Wrap an already hashed password such as the one stored in the database.
+    pub fn new(hashed: impl Into<String>) -> Self {
+        Self(hashed.into())
+    }
+
+    /// Hash a raw password with Argon2id.
+    pub fn hash(raw: impl AsRef<str>) -> Result<Self, KernelError> {
+        let raw = raw.as_ref();
+        let length = raw.chars().count();
+        if !(Self::MIN_LENGTH..=Self::MAX_LENGTH).contains(&length) {
+            return Err(KernelError::Convert(format!(
+                "password must be between {} and {} characters.",
+                Self::MIN_LENGTH, Self::MAX_LENGTH
+            )));
+        }
+
+        let salt = SaltString::generate(&mut OsRng);
+        let hashed = Argon2::default()
+            .hash_password(raw.as_bytes(), &salt)
+            .map_err(|e| KernelError::External(anyhow::Error::msg(e.to_string())))?
+            .to_string();
+
+        Ok(Self(hashed))
+    }
+
+    /// Verifying against this takes as long as against a stored password, and never succeeds.
+    /// Used where the account is unknown, so that the response time does not tell it apart.
+    pub fn dummy() -> Self {
+        Self(Self::DUMMY.to_string())
+    }
+
+    pub fn verify(&self, raw: impl AsRef<str>) -> Result<bool, KernelError> {
+        let parsed = PasswordHash::new(&self.0)
+            .map_err(|e| KernelError::Convert(format!("failed parse password hash. `argon2`: {}", e)))?;
+        Ok(Argon2::default().verify_password(raw.as_ref().as_bytes(), &parsed).is_ok())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Confidential {
+    id: ConfidentialId,
+    account: AccountId,
+    date: UpdateTime,
+    address: Address,
+    pass: Password,
+    verified: bool
+}
+
+impl Confidential {
+    pub fn new(
+        id: impl Into<Uuid>,
+        account: impl Into<i64>,
+        created_at: impl Into<OffsetDateTime>,
+        updated_at: impl Into<OffsetDateTime>,
+        address: impl Into<String>,
+        pass: impl Into<String>,
+        verified: bool
+    ) -> Self {
+        Self {
+            id: ConfidentialId::new(id.into()),
+            account: AccountId::new(account),
+            date: UpdateTime::new(created_at.into(), updated_at.into()),
+            address: Address::new(address),
+            pass: Password::new(pass),
+            verified
+        }
+    }
+
+    pub fn id(&self) -> &ConfidentialId {
+        &self.id
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn date(&self) -> &UpdateTime {
+        &self.date
+    }
+
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub fn pass(&self) -> &Password {
+        &self.pass
+    }
+
+    /// Whether the owner has proven control of the address.
+    /// Unverified accounts may sign in but may not publish anything.
+    pub fn is_verified(&self) -> bool {
+        self.verified
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, Address, Confidential, ConfidentialId, Password};
+
+    #[test]
+    fn struct_test() {
+        let pass = Password::hash("hunter2hunter2").unwrap();
+        let _confidential = Confidential::new(
+            ConfidentialId::default(),
+            AccountId::default(),
+            OffsetDateTime::now_utc(),
+            OffsetDateTime::now_utc(),
+            "shuttle@example.com",
+            pass,
+            false
+        );
+    }
+
+    #[test]
+    fn password_test() {
+        let pass = Password::hash("hunter2hunter2").unwrap();
+        assert!(pass.verify("hunter2hunter2").unwrap());
+        assert!(!pass.verify("hunter3hunter3").unwrap());
+        assert!(Password::hash("short").is_err());
+        assert!(!Password::dummy().verify("hunter2hunter2").unwrap());
+    }
+
+    #[test]
+    fn address_test() {
+        let address = Address::try_from("Shuttle@EXAMPLE.com".to_string()).unwrap();
+        assert_eq!(address.as_ref(), "Shuttle@example.com");
+        assert!(Address::try_from("shuttle".to_string()).is_err());
+        assert!(Address::try_from("shuttle@localhost".to_string()).is_err());
+        assert!(Address::try_from("shut tle@example.com".to_string()).is_err());
+    }
+}
@@ -0,0 +1,291 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use super::{AccountId, CreatedAt};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeliveryId(Uuid);
+
+impl DeliveryId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for DeliveryId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<DeliveryId> for Uuid {
+    fn from(id: DeliveryId) -> Self {
+        id.0
+    }
+}
+
+impl Default for DeliveryId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// An activity waiting to be posted to the inboxes of `recipients`, who all live on `host`.
+///
+/// Recipients are grouped by host so that a server with a shared inbox gets a single request.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Delivery {
+    id: DeliveryId,
+    sender: AccountId,
+    key_id: String,
+    host: String,
+    recipients: Vec<String>,
+    activity: String,
+    attempts: i32,
+    next_attempt_at: OffsetDateTime,
+    created_at: CreatedAt
+}
+
+impl Delivery {
+    /// Failed attempts after which a delivery is given up. With [`Delivery::backoff`] that is almost three days.
+    pub const MAX_ATTEMPTS: i32 = 16;
+    pub const MIN_BACKOFF: Duration = Duration::seconds(30);
+    pub const MAX_BACKOFF: Duration = Duration::hours(12);
+
+    /// `key_id` is the key the request is signed with, `activity` the JSON body.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<Uuid>,
+        sender: impl Into<i64>,
+        key_id: impl Into<String>,
+        host: impl Into<String>,
+        recipients: impl IntoIterator<Item = impl Into<String>>,
+        activity: impl Into<String>,
+        attempts: i32,
+        next_attempt_at: impl Into<OffsetDateTime>,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: DeliveryId::new(id.into()),
+            sender: AccountId::new(sender),
+            key_id: key_id.into(),
+            host: host.into(),
+            recipients: recipients.into_iter().map(Into::into).collect(),
+            activity: activity.into(),
+            attempts,
+            next_attempt_at: next_attempt_at.into(),
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    /// Delay before the next attempt once `attempts` have failed, doubling from [`Delivery::MIN_BACKOFF`].
+    pub fn backoff(attempts: i32) -> Duration {
+        let exponent = attempts.clamp(1, 31) as u32 - 1;
+        Self::MIN_BACKOFF.checked_mul(2_i32.saturating_pow(exponent))
+            .unwrap_or(Self::MAX_BACKOFF)
+            .min(Self::MAX_BACKOFF)
+    }
+
+    /// Records a failed attempt and schedules the next one.
+    /// `None` once the delivery has run out of attempts.
+    pub fn retry(self, now: OffsetDateTime) -> Option<Self> {
+        let attempts = self.attempts + 1;
+        if attempts >= Self::MAX_ATTEMPTS {
+            return None;
+        }
+        Some(Self {
+            attempts,
+            next_attempt_at: now + Self::backoff(attempts),
+            ..self
+        })
+    }
+
+    pub fn id(&self) -> &DeliveryId {
+        &self.id
+    }
+
+    pub fn sender(&self) -> &AccountId {
+        &self.sender
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Actor ids the activity is addressed to.
+    pub fn recipients(&self) -> &[String] {
+        &self.recipients
+    }
+
+    pub fn activity(&self) -> &str {
+        &self.activity
+    }
+
+    /// Failed attempts so far.
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn next_attempt_at(&self) -> &OffsetDateTime {
+        &self.next_attempt_at
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+/// Inboxes of a remote actor.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inboxes {
+    inbox: String,
+    shared_inbox: Option<String>
+}
+
+impl Inboxes {
+    pub fn new(inbox: impl Into<String>, shared_inbox: Option<impl Into<String>>) -> Self {
+        Self { inbox: inbox.into(), shared_inbox: shared_inbox.map(Into::into) }
+    }
+
+    pub fn inbox(&self) -> &str {
+        &self.inbox
+    }
+
+    pub fn shared_inbox(&self) -> Option<&str> {
+        self.shared_inbox.as_deref()
+    }
+}
+
+/// How a remote server answered a delivery.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryStatus {
+    Delivered,
+    /// The server refused the activity for good, such as with `403` or `410`. Retrying will not help.
+    Rejected,
+    /// Timeouts, connection errors and `5xx`, as well as `408` and `429`.
+    Failed
+}
+
+/// Circuit breaker of a remote host.
+///
+/// A host that keeps failing is marked dead and its deliveries are dropped,
+/// except for one probe per [`DeliveryHost::PROBE_INTERVAL`] that revives it on success.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct DeliveryHost {
+    host: String,
+    failures: i32,
+    dead_since: Option<OffsetDateTime>
+}
+
+impl DeliveryHost {
+    /// Consecutive failed deliveries after which a host is marked dead.
+    pub const DEAD_AFTER: i32 = 10;
+    pub const PROBE_INTERVAL: Duration = Duration::days(1);
+
+    pub fn new(host: impl Into<String>, failures: i32, dead_since: Option<impl Into<OffsetDateTime>>) -> Self {
+        Self { host: host.into(), failures, dead_since: dead_since.map(Into::into) }
+    }
+
+    /// A host nothing has been delivered to yet.
+    pub fn alive(host: impl Into<String>) -> Self {
+        Self::new(host, 0, None::<OffsetDateTime>)
+    }
+
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    pub fn failures(&self) -> i32 {
+        self.failures
+    }
+
+    pub fn dead_since(&self) -> Option<&OffsetDateTime> {
+        self.dead_since.as_ref()
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.dead_since.is_some()
+    }
+
+    /// Whether a delivery may be attempted at `now`, either because the host is alive or to probe it.
+    pub fn is_available(&self, now: OffsetDateTime) -> bool {
+        self.dead_since.is_none_or(|since| now - since >= Self::PROBE_INTERVAL)
+    }
+
+    pub fn succeeded(self) -> Self {
+        Self { failures: 0, dead_since: None, ..self }
+    }
+
+    /// A failed probe of a dead host waits another [`DeliveryHost::PROBE_INTERVAL`].
+    pub fn failed(self, now: OffsetDateTime) -> Self {
+        let failures = self.failures.saturating_add(1);
+        let dead_since = (failures >= Self::DEAD_AFTER).then_some(now);
+        Self { failures, dead_since, ..self }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::{Duration, OffsetDateTime};
+
+    use crate::entities::{AccountId, Delivery, DeliveryHost, DeliveryId};
+
+    #[test]
+    fn struct_test() {
+        let now = OffsetDateTime::now_utc();
+        let delivery = Delivery::new(
+            DeliveryId::default(),
+            AccountId::default(),
+            "https://local.example/users/shuttle#main-key",
+            "remote.example",
+            ["https://remote.example/users/a", "https://remote.example/users/b"],
+            "{}",
+            0,
+            now,
+            now
+        );
+        assert_eq!(delivery.recipients().len(), 2);
+
+        let retried = delivery.retry(now).unwrap();
+        assert_eq!(retried.attempts(), 1);
+        assert_eq!(*retried.next_attempt_at(), now + Delivery::MIN_BACKOFF);
+        assert_eq!(Delivery::backoff(3), Duration::minutes(2));
+        assert_eq!(Delivery::backoff(30), Delivery::MAX_BACKOFF);
+
+        let mut delivery = Some(retried);
+        for _ in 1..Delivery::MAX_ATTEMPTS {
+            delivery = delivery.and_then(|delivery| delivery.retry(now));
+        }
+        assert!(delivery.is_none());
+    }
+
+    #[test]
+    fn circuit_breaker_test() {
+        let now = OffsetDateTime::now_utc();
+        let mut host = DeliveryHost::alive("remote.example");
+        for _ in 0..DeliveryHost::DEAD_AFTER - 1 {
+            host = host.failed(now);
+        }
+        assert!(!host.is_dead());
+
+        let host = host.failed(now);
+        assert!(host.is_dead());
+        assert!(!host.is_available(now + Duration::hours(1)));
+
+        // A probe is allowed after a while, and another failure keeps the host dead.
+        let probe = now + DeliveryHost::PROBE_INTERVAL;
+        assert!(host.is_available(probe));
+        let host = host.failed(probe);
+        assert!(!host.is_available(probe + Duration::hours(1)));
+
+        let host = host.succeeded();
+        assert!(!host.is_dead());
+        assert_eq!(host.failures(), 0);
+    }
+}
\ No newline at end of file
@@ -1,33 +1,126 @@
+use destructure::Destructure;
 use serde::{Deserialize, Serialize};
-use crate::entities::AccountId;
+use time::OffsetDateTime;
+use uuid::Uuid;
 
-#[derive(Debug, Clone, Hash, Default, Serialize, Deserialize)]
-pub struct Follow(Vec<AccountTypes>);
+use super::{AccountId, CreatedAt};
 
-impl AsRef<[AccountTypes]> for Follow {
-    fn as_ref(&self) -> &[AccountTypes] {
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowId(Uuid);
+
+impl FollowId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for FollowId {
+    fn as_ref(&self) -> &Uuid {
         &self.0
     }
 }
 
-impl Follow {
-    pub fn new() -> Self {
-        Self::default()
+impl From<FollowId> for Uuid {
+    fn from(id: FollowId) -> Self {
+        id.0
     }
 }
 
-#[derive(Debug, Clone, Hash, Serialize, Deserialize)]
+impl Default for FollowId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Either end of a follow. Remote accounts are identified by their actor url.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AccountTypes {
     Local(AccountId),
     Federate(String)
 }
 
+impl From<AccountId> for AccountTypes {
+    fn from(id: AccountId) -> Self {
+        Self::Local(id)
+    }
+}
+
+/// A follow of a locked account stays `Pending` until the destination accepts it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FollowState {
+    Pending,
+    Accepted
+}
+
+impl FollowState {
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, Self::Accepted)
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Follow {
+    id: FollowId,
+    source: AccountTypes,
+    destination: AccountTypes,
+    state: FollowState,
+    created_at: CreatedAt
+}
+
+impl Follow {
+    pub fn new(
+        id: impl Into<Uuid>,
+        source: impl Into<AccountTypes>,
+        destination: impl Into<AccountTypes>,
+        state: FollowState,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: FollowId::new(id.into()),
+            source: source.into(),
+            destination: destination.into(),
+            state,
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &FollowId {
+        &self.id
+    }
+
+    pub fn source(&self) -> &AccountTypes {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &AccountTypes {
+        &self.destination
+    }
+
+    pub fn state(&self) -> &FollowState {
+        &self.state
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::entities::Follow;
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, AccountTypes, Follow, FollowId, FollowState};
 
     #[test]
     fn struct_test() {
-        let _follow = Follow::new();
+        let follow = Follow::new(
+            FollowId::default(),
+            AccountId::default(),
+            AccountTypes::Federate("https://remote.example/users/shuttle".to_string()),
+            FollowState::Pending,
+            OffsetDateTime::now_utc()
+        );
+        assert!(matches!(follow.source(), AccountTypes::Local(_)));
+        assert!(!follow.state().is_accepted());
     }
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use unicode_normalization::UnicodeNormalization;
+use uuid::Uuid;
+
+use super::CreatedAt;
+
+use crate::error::KernelError;
+
+/// Reference to a row of `hashtags`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashtagId(Uuid);
+
+impl HashtagId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for HashtagId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<HashtagId> for Uuid {
+    fn from(id: HashtagId) -> Self {
+        id.0
+    }
+}
+
+impl Default for HashtagId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Tag name without the leading `#`, always in normalized form.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashtagName(String);
+
+impl From<HashtagName> for String {
+    fn from(name: HashtagName) -> Self {
+        name.0
+    }
+}
+
+impl AsRef<str> for HashtagName {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl HashtagName {
+    pub const MAX_LENGTH: usize = 128;
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    /// Whether `c` can be part of a tag as typed.
+    pub fn is_tag_char(c: char) -> bool {
+        c.is_alphanumeric() || c == '_'
+    }
+
+    /// NFKC with case folding, so `#ＲＵＳＴ`, `#Rust` and `#rust` are the same tag.
+    pub fn normalize(name: &str) -> String {
+        caseless::default_case_fold_str(&name.nfkc().collect::<String>())
+            .nfkc()
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for HashtagName {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let name = Self::normalize(value);
+        if name.is_empty() || name.chars().count() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "hashtag must be between 1 and {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        if !name.chars().all(Self::is_tag_char) {
+            return Err(KernelError::Convert("hashtag may only contain letters, digits and `_`.".to_string()));
+        }
+        // `#1` is more likely a number than a tag.
+        if name.chars().all(|c| c.is_numeric()) {
+            return Err(KernelError::Convert("hashtag must not be only digits.".to_string()));
+        }
+        Ok(Self(name))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Hashtag {
+    id: HashtagId,
+    name: HashtagName,
+    created_at: CreatedAt
+}
+
+impl Hashtag {
+    pub fn new(
+        id: impl Into<Uuid>,
+        name: impl Into<String>,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: HashtagId::new(id.into()),
+            name: HashtagName::new(name),
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &HashtagId {
+        &self.id
+    }
+
+    pub fn name(&self) -> &HashtagName {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{Hashtag, HashtagId, HashtagName};
+
+    #[test]
+    fn struct_test() {
+        let hashtag = Hashtag::new(HashtagId::default(), "shuttlepub", OffsetDateTime::now_utc());
+        assert_eq!(hashtag.name().as_ref(), "shuttlepub");
+
+        for typed in ["Rust", "RUST", "ｒｕｓｔ", "ＲＵＳＴ"] {
+            assert_eq!(HashtagName::try_from(typed).unwrap().as_ref(), "rust");
+        }
+        assert_eq!(HashtagName::try_from("Straße").unwrap(), HashtagName::try_from("STRASSE").unwrap());
+        assert_eq!(HashtagName::try_from("ﾃｽﾄ").unwrap().as_ref(), "テスト");
+
+        assert!(HashtagName::try_from("").is_err());
+        assert!(HashtagName::try_from("2023").is_err());
+        assert!(HashtagName::try_from("shuttle-pub").is_err());
+        assert!(HashtagName::try_from("a".repeat(HashtagName::MAX_LENGTH + 1).as_str()).is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,224 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use destructure::Destructure;
+use rsa::{
+    pkcs1::DecodeRsaPublicKey,
+    pkcs1v15::{Signature, SigningKey, VerifyingKey},
+    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding},
+    sha2::Sha256,
+    signature::{SignatureEncoding, Signer, Verifier},
+    RsaPrivateKey, RsaPublicKey
+};
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use super::{AccountId, CreatedAt};
+
+use crate::error::KernelError;
+
+/// PEM encoded RSA public key.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicKey(String);
+
+impl PublicKey {
+    pub fn new(pem: impl Into<String>) -> Self {
+        Self(pem.into())
+    }
+
+    /// Verifies an RSASSA-PKCS1-v1_5 SHA-256 signature.
+    /// Keys that fail to parse verify nothing.
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> bool {
+        // Some servers publish PKCS#1 `RSA PUBLIC KEY` blocks instead of SPKI.
+        let Ok(key) = RsaPublicKey::from_public_key_pem(&self.0)
+            .or_else(|_| RsaPublicKey::from_pkcs1_pem(&self.0)) else {
+            return false;
+        };
+        let Ok(signature) = Signature::try_from(signature) else {
+            return false;
+        };
+        VerifyingKey::<Sha256>::new(key).verify(message, &signature).is_ok()
+    }
+}
+
+impl From<PublicKey> for String {
+    fn from(key: PublicKey) -> Self {
+        key.0
+    }
+}
+
+impl AsRef<str> for PublicKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// PEM encoded PKCS#8 RSA private key.
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrivateKey(String);
+
+impl std::fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PrivateKey(**hidden**)")
+    }
+}
+
+impl PrivateKey {
+    pub fn new(pem: impl Into<String>) -> Self {
+        Self(pem.into())
+    }
+
+    /// Signs `message` with RSASSA-PKCS1-v1_5 SHA-256.
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>, KernelError> {
+        let key = RsaPrivateKey::from_pkcs8_pem(&self.0)
+            .map_err(|e| KernelError::Convert(format!("failed parse private key. `rsa`: {}", e)))?;
+        let signature = SigningKey::<Sha256>::new(key).try_sign(message)
+            .map_err(|e| KernelError::External(anyhow::Error::new(e)))?;
+        Ok(signature.to_vec())
+    }
+
+    /// Signs `message` and encodes the signature in base64.
+    pub fn sign_base64(&self, message: &[u8]) -> Result<String, KernelError> {
+        Ok(STANDARD.encode(self.sign(message)?))
+    }
+}
+
+impl From<PrivateKey> for String {
+    fn from(key: PrivateKey) -> Self {
+        key.0
+    }
+}
+
+impl AsRef<str> for PrivateKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Keypair a local account signs its outgoing activities with.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct AccountKey {
+    account: AccountId,
+    public_key: PublicKey,
+    private_key: PrivateKey,
+    created_at: CreatedAt
+}
+
+impl AccountKey {
+    pub const BITS: usize = 2048;
+
+    pub fn new(
+        account: impl Into<i64>,
+        public_key: impl Into<String>,
+        private_key: impl Into<String>,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            account: AccountId::new(account),
+            public_key: PublicKey::new(public_key),
+            private_key: PrivateKey::new(private_key),
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    /// Generates a fresh keypair. This takes a noticeable amount of CPU time.
+    pub fn generate(account: impl Into<i64>) -> Result<Self, KernelError> {
+        let private = RsaPrivateKey::new(&mut rand::thread_rng(), Self::BITS)
+            .map_err(|e| KernelError::External(anyhow::Error::new(e)))?;
+        let public_pem = private.to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .map_err(|e| KernelError::External(anyhow::Error::new(e)))?;
+        let private_pem = private.to_pkcs8_pem(LineEnding::LF)
+            .map_err(|e| KernelError::External(anyhow::Error::new(e)))?;
+
+        Ok(Self::new(account, public_pem, private_pem.as_str(), OffsetDateTime::now_utc()))
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn private_key(&self) -> &PrivateKey {
+        &self.private_key
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+/// Public key of a remote actor, as published in its actor document.
+/// `id` is the `keyId` that signatures refer to and `owner` the actor url.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct RemoteKey {
+    id: String,
+    owner: String,
+    public_key: PublicKey,
+    fetched_at: OffsetDateTime
+}
+
+impl RemoteKey {
+    /// Least time between two fetches of the same key.
+    /// Signatures that fail against a newer key are rejected without asking its server again.
+    pub const REFETCH_INTERVAL: Duration = Duration::minutes(5);
+
+    pub fn new(
+        id: impl Into<String>,
+        owner: impl Into<String>,
+        public_key: impl Into<String>,
+        fetched_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: id.into(),
+            owner: owner.into(),
+            public_key: PublicKey::new(public_key),
+            fetched_at: fetched_at.into()
+        }
+    }
+
+    /// Whether the key may be fetched again at `now`.
+    pub fn can_refetch(&self, now: &OffsetDateTime) -> bool {
+        *now - self.fetched_at >= Self::REFETCH_INTERVAL
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    pub fn fetched_at(&self) -> &OffsetDateTime {
+        &self.fetched_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, AccountKey, RemoteKey};
+
+    #[test]
+    fn struct_test() {
+        let key = AccountKey::generate(AccountId::default()).unwrap();
+        assert!(key.public_key().as_ref().starts_with("-----BEGIN PUBLIC KEY-----"));
+
+        let signature = key.private_key().sign(b"shuttlepub").unwrap();
+        assert!(key.public_key().verify(b"shuttlepub", &signature));
+        assert!(!key.public_key().verify(b"shuttlepub!", &signature));
+
+        let now = OffsetDateTime::now_utc();
+        let remote = RemoteKey::new("https://remote.example/users/a#main-key", "https://remote.example/users/a", key.public_key().as_ref(), now);
+        assert!(remote.public_key().verify(b"shuttlepub", &signature));
+        assert!(!remote.can_refetch(&now));
+        assert!(remote.can_refetch(&(now + RemoteKey::REFETCH_INTERVAL)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+
+use super::Address;
+
+/// Plain text message sent through [`Mailer`](crate::service::Mailer).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Mail {
+    to: Address,
+    subject: String,
+    body: String
+}
+
+impl Mail {
+    pub fn new(
+        to: impl Into<String>,
+        subject: impl Into<String>,
+        body: impl Into<String>
+    ) -> Self {
+        Self {
+            to: Address::new(to),
+            subject: subject.into(),
+            body: body.into()
+        }
+    }
+
+    pub fn to(&self) -> &Address {
+        &self.to
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::entities::Mail;
+
+    #[test]
+    fn struct_test() {
+        let mail = Mail::new("shuttle@example.com", "Welcome", "Hello.");
+        assert_eq!(mail.to().as_ref(), "shuttle@example.com");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,323 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{AccountId, AccountTypes, CreatedAt, HashtagId};
+
+use crate::error::KernelError;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteId(Uuid);
+
+impl NoteId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for NoteId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<NoteId> for Uuid {
+    fn from(id: NoteId) -> Self {
+        id.0
+    }
+}
+
+impl Default for NoteId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Content(String);
+
+impl From<Content> for String {
+    fn from(content: Content) -> Self {
+        content.0
+    }
+}
+
+impl AsRef<str> for Content {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Content {
+    pub const MAX_LENGTH: usize = 3000;
+
+    pub fn new(content: impl Into<String>) -> Self {
+        Self(content.into())
+    }
+}
+
+impl TryFrom<String> for Content {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.trim().is_empty() || value.chars().count() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "content must be between 1 and {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Content warning shown in place of the content until the reader expands it.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentWarning(String);
+
+impl From<ContentWarning> for String {
+    fn from(cw: ContentWarning) -> Self {
+        cw.0
+    }
+}
+
+impl AsRef<str> for ContentWarning {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ContentWarning {
+    pub const MAX_LENGTH: usize = 512;
+
+    pub fn new(cw: impl Into<String>) -> Self {
+        Self(cw.into())
+    }
+}
+
+impl TryFrom<String> for ContentWarning {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.chars().count() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "content warning must be at most {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Either end of a reply or quote. Remote notes are identified by their object url.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteTypes {
+    Local(NoteId),
+    Federate(String)
+}
+
+impl From<NoteId> for NoteTypes {
+    fn from(id: NoteId) -> Self {
+        Self::Local(id)
+    }
+}
+
+/// Who can see a note besides its author.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Visibility {
+    /// Anyone, and listed on public timelines.
+    #[default]
+    Public,
+    /// Anyone with the link, but kept off public timelines.
+    Unlisted,
+    /// Accounts whose follow of the author is accepted.
+    FollowersOnly,
+    /// Mentioned accounts only.
+    Direct
+}
+
+impl Visibility {
+    /// Whether `self` reaches anyone `other` does not.
+    pub fn is_wider_than(&self, other: &Self) -> bool {
+        self.rank() > other.rank()
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Public => 3,
+            Self::Unlisted => 2,
+            Self::FollowersOnly => 1,
+            Self::Direct => 0
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::Unlisted => "unlisted",
+            Self::FollowersOnly => "followers_only",
+            Self::Direct => "direct"
+        }
+    }
+}
+
+impl TryFrom<&str> for Visibility {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "public" => Ok(Self::Public),
+            "unlisted" => Ok(Self::Unlisted),
+            "followers_only" => Ok(Self::FollowersOnly),
+            "direct" => Ok(Self::Direct),
+            _ => Err(KernelError::Convert(format!("`{}` is not a known visibility.", value)))
+        }
+    }
+}
+
+/// Reference to a row of `note_media`.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaId(Uuid);
+
+impl MediaId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for MediaId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<MediaId> for Uuid {
+    fn from(id: MediaId) -> Self {
+        id.0
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Note {
+    id: NoteId,
+    author: AccountId,
+    content: Content,
+    cw: Option<ContentWarning>,
+    visibility: Visibility,
+    in_reply_to: Option<NoteTypes>,
+    quote_of: Option<NoteTypes>,
+    mentions: Vec<AccountTypes>,
+    media: Vec<MediaId>,
+    hashtags: Vec<HashtagId>,
+    created_at: CreatedAt
+}
+
+impl Note {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: impl Into<Uuid>,
+        author: impl Into<i64>,
+        content: impl Into<String>,
+        cw: Option<impl Into<String>>,
+        visibility: Visibility,
+        in_reply_to: Option<NoteTypes>,
+        quote_of: Option<NoteTypes>,
+        mentions: impl IntoIterator<Item = AccountTypes>,
+        media: impl IntoIterator<Item = impl Into<Uuid>>,
+        hashtags: impl IntoIterator<Item = impl Into<Uuid>>,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: NoteId::new(id.into()),
+            author: AccountId::new(author),
+            content: Content::new(content),
+            cw: cw.map(ContentWarning::new),
+            visibility,
+            in_reply_to,
+            quote_of,
+            mentions: mentions.into_iter().collect(),
+            media: media.into_iter().map(|id| MediaId::new(id.into())).collect(),
+            hashtags: hashtags.into_iter().map(|id| HashtagId::new(id.into())).collect(),
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &NoteId {
+        &self.id
+    }
+
+    pub fn author(&self) -> &AccountId {
+        &self.author
+    }
+
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    pub fn cw(&self) -> Option<&ContentWarning> {
+        self.cw.as_ref()
+    }
+
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    pub fn in_reply_to(&self) -> Option<&NoteTypes> {
+        self.in_reply_to.as_ref()
+    }
+
+    /// Note reshared with this note's content as commentary.
+    pub fn quote_of(&self) -> Option<&NoteTypes> {
+        self.quote_of.as_ref()
+    }
+
+    /// Accounts addressed by the content, in order of first appearance.
+    pub fn mentions(&self) -> &[AccountTypes] {
+        &self.mentions
+    }
+
+    pub fn media(&self) -> &[MediaId] {
+        &self.media
+    }
+
+    pub fn hashtags(&self) -> &[HashtagId] {
+        &self.hashtags
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, AccountTypes, Content, ContentWarning, Note, NoteId, NoteTypes, Visibility};
+
+    #[test]
+    fn struct_test() {
+        let note = Note::new(
+            NoteId::default(),
+            AccountId::default(),
+            "Hello, ShuttlePub!",
+            Some("greeting"),
+            Visibility::Unlisted,
+            Some(NoteTypes::Federate("https://remote.example/notes/1".to_string())),
+            Some(NoteTypes::Local(NoteId::default())),
+            [AccountTypes::Local(AccountId::default())],
+            Vec::<uuid::Uuid>::new(),
+            Vec::<uuid::Uuid>::new(),
+            OffsetDateTime::now_utc()
+        );
+        assert_eq!(note.cw().map(AsRef::as_ref), Some("greeting"));
+
+        assert!(Content::try_from("  ".to_string()).is_err());
+        assert!(Content::try_from("a".repeat(Content::MAX_LENGTH + 1)).is_err());
+        assert!(ContentWarning::try_from("a".repeat(ContentWarning::MAX_LENGTH + 1)).is_err());
+
+        for visibility in [Visibility::Public, Visibility::Unlisted, Visibility::FollowersOnly, Visibility::Direct] {
+            assert_eq!(Visibility::try_from(visibility.as_str()).unwrap(), visibility);
+        }
+        assert!(Visibility::try_from("private").is_err());
+
+        assert!(Visibility::Public.is_wider_than(&Visibility::Unlisted));
+        assert!(Visibility::FollowersOnly.is_wider_than(&Visibility::Direct));
+        assert!(!Visibility::Unlisted.is_wider_than(&Visibility::Unlisted));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::{random, AccountId, ApplicationId, CreatedAt, Scopes};
+
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessToken(String);
+
+impl std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AccessToken(**hidden**)")
+    }
+}
+
+impl From<AccessToken> for String {
+    fn from(token: AccessToken) -> Self {
+        token.0
+    }
+}
+
+impl AsRef<str> for AccessToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AccessToken {
+    pub const LENGTH: usize = 64;
+
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl Default for AccessToken {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct OAuthToken {
+    token: AccessToken,
+    application: ApplicationId,
+    account: AccountId,
+    scopes: Scopes,
+    created_at: CreatedAt
+}
+
+impl OAuthToken {
+    pub fn new(
+        token: impl Into<String>,
+        application: impl Into<ApplicationId>,
+        account: impl Into<i64>,
+        scopes: impl Into<Scopes>,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            token: AccessToken::new(token),
+            application: application.into(),
+            account: AccountId::new(account),
+            scopes: scopes.into(),
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn token(&self) -> &AccessToken {
+        &self.token
+    }
+
+    pub fn application(&self) -> &ApplicationId {
+        &self.application
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn scopes(&self) -> &Scopes {
+        &self.scopes
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccessToken, AccountId, ApplicationId, OAuthToken, Scopes};
+
+    #[test]
+    fn struct_test() {
+        let _token = OAuthToken::new(
+            AccessToken::default(),
+            ApplicationId::default(),
+            AccountId::default(),
+            Scopes::all(),
+            OffsetDateTime::now_utc()
+        );
+    }
+}
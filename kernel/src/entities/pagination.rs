@@ -0,0 +1,91 @@
+use uuid::Uuid;
+
+/// Window over a note listing ordered newest first.
+///
+/// Bounds are ids of listed entries, compared by `(created_at, id)` so that pages stay stable as entries are added.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Pagination {
+    max_id: Option<Uuid>,
+    since_id: Option<Uuid>,
+    min_id: Option<Uuid>,
+    limit: i64
+}
+
+impl Pagination {
+    pub const DEFAULT_LIMIT: i64 = 20;
+    pub const MAX_LIMIT: i64 = 40;
+
+    /// `limit` is clamped into `1..=MAX_LIMIT`.
+    pub fn new(max_id: Option<Uuid>, since_id: Option<Uuid>, min_id: Option<Uuid>, limit: Option<i64>) -> Self {
+        Self {
+            max_id,
+            since_id,
+            min_id,
+            limit: limit.unwrap_or(Self::DEFAULT_LIMIT).clamp(1, Self::MAX_LIMIT)
+        }
+    }
+
+    /// Only entries older than this one are listed. It is the id of a note, or of a boost in listings with boosts.
+    pub fn max_id(&self) -> Option<&Uuid> {
+        self.max_id.as_ref()
+    }
+
+    /// Only entries newer than this one are listed, the newest of them first.
+    pub fn since_id(&self) -> Option<&Uuid> {
+        self.since_id.as_ref()
+    }
+
+    /// Only entries newer than this one are listed, those right after it first.
+    /// Takes precedence over `since_id`.
+    pub fn min_id(&self) -> Option<&Uuid> {
+        self.min_id.as_ref()
+    }
+
+    /// Lower bound of the window, `min_id` or else `since_id`.
+    pub fn newer_than(&self) -> Option<&Uuid> {
+        self.min_id().or(self.since_id())
+    }
+
+    /// Whether the window starts from its lower bound, which is the case with `min_id`.
+    /// Such pages are fetched oldest first and still returned newest first.
+    pub fn is_ascending(&self) -> bool {
+        self.min_id.is_some()
+    }
+
+    pub fn limit(&self) -> i64 {
+        self.limit
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self::new(None, None, None, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use uuid::Uuid;
+
+    use crate::entities::Pagination;
+
+    #[test]
+    fn struct_test() {
+        assert_eq!(Pagination::default().limit(), Pagination::DEFAULT_LIMIT);
+        assert_eq!(Pagination::new(None, None, None, Some(0)).limit(), 1);
+        assert_eq!(Pagination::new(None, None, None, Some(1000)).limit(), Pagination::MAX_LIMIT);
+
+        let max_id = Uuid::new_v4();
+        assert_eq!(Pagination::new(Some(max_id), None, None, None).max_id(), Some(&max_id));
+
+        let since_id = Uuid::new_v4();
+        let min_id = Uuid::new_v4();
+        let page = Pagination::new(None, Some(since_id), None, None);
+        assert_eq!(page.newer_than(), Some(&since_id));
+        assert!(!page.is_ascending());
+
+        let page = Pagination::new(None, Some(since_id), Some(min_id), None);
+        assert_eq!(page.newer_than(), Some(&min_id));
+        assert!(page.is_ascending());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,103 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use super::{random, AccountId, CreatedAt, ExpiresAt};
+
+/// Sent to the registered address to allow setting a new password without the old one.
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordResetToken(String);
+
+impl std::fmt::Debug for PasswordResetToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PasswordResetToken(**hidden**)")
+    }
+}
+
+impl From<PasswordResetToken> for String {
+    fn from(token: PasswordResetToken) -> Self {
+        token.0
+    }
+}
+
+impl AsRef<str> for PasswordResetToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PasswordResetToken {
+    pub const LENGTH: usize = 43;
+
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl Default for PasswordResetToken {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct PasswordReset {
+    token: PasswordResetToken,
+    account: AccountId,
+    created_at: CreatedAt,
+    expires_at: ExpiresAt
+}
+
+impl PasswordReset {
+    pub const LIFETIME: Duration = Duration::hours(1);
+
+    pub fn new(
+        token: impl Into<String>,
+        account: impl Into<i64>,
+        created_at: impl Into<OffsetDateTime>,
+        expires_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            token: PasswordResetToken::new(token),
+            account: AccountId::new(account),
+            created_at: CreatedAt::new(created_at.into()),
+            expires_at: ExpiresAt::new(expires_at.into())
+        }
+    }
+
+    pub fn token(&self) -> &PasswordResetToken {
+        &self.token
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &ExpiresAt {
+        &self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, PasswordReset, PasswordResetToken};
+
+    #[test]
+    fn struct_test() {
+        let now = OffsetDateTime::now_utc();
+        let reset = PasswordReset::new(
+            PasswordResetToken::default(),
+            AccountId::default(),
+            now,
+            now + PasswordReset::LIFETIME
+        );
+        assert_eq!(reset.token().as_ref().len(), PasswordResetToken::LENGTH);
+        assert!(!reset.expires_at().is_expired());
+    }
+}
\ No newline at end of file
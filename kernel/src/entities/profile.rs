@@ -1,6 +1,7 @@
 use destructure::Destructure;
 use serde::{Serialize, Deserialize};
 use time::OffsetDateTime;
+use url::Url;
 use uuid::Uuid;
 
 use super::{AccountId, UpdateTime};
@@ -61,11 +62,25 @@ impl AsRef<str> for DisplayName {
 }
 
 impl DisplayName {
+    pub const MAX_LENGTH: usize = 64;
+
     pub fn new(name: impl Into<String>) -> Self {
         Self(name.into())
     }
 }
 
+impl TryFrom<String> for DisplayName {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.chars().count() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "display name must be at most {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
 #[derive(Debug, Clone, Hash, Serialize, Deserialize, Default)]
 pub struct Summary(String);
 
@@ -83,12 +98,40 @@ impl AsRef<str> for Summary {
 }
 
 impl Summary {
+    pub const MAX_LENGTH: usize = 500;
+
     pub fn new(summary: impl Into<String>) -> Self {
         Self(summary.into())
     }
 }
 
-#[derive(Debug, Clone, Hash, Serialize, Deserialize, Default)]
+impl TryFrom<String> for Summary {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.chars().count() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "summary must be at most {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Parses `value` as an absolute http(s) url that fits in `max` characters.
+fn parse_image_url(value: &str, max: usize) -> Result<String, KernelError> {
+    let url = Url::parse(value)
+        .map_err(|e| KernelError::Convert(format!("failed parse url. `url`: {}", e)))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(KernelError::Convert("url must be http(s).".to_string()));
+    }
+    let url = String::from(url);
+    if url.chars().count() > max {
+        return Err(KernelError::Convert(format!("url must be at most {} characters.", max)));
+    }
+    Ok(url)
+}
+
+#[derive(Debug, Clone, Hash, Serialize, Deserialize)]
 pub struct Icon(String);
 
 impl From<Icon> for String {
@@ -104,12 +147,21 @@ impl AsRef<str> for Icon {
 }
 
 impl Icon {
+    pub const MAX_LENGTH: usize = 256;
+
     pub fn new(url: impl Into<String>) -> Self {
         Self(url.into())
     }
 }
 
-#[derive(Debug, Clone, Hash, Serialize, Deserialize, Default)]
+impl TryFrom<String> for Icon {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parse_image_url(&value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Hash, Serialize, Deserialize)]
 pub struct Banner(String);
 
 impl From<Banner> for String {
@@ -125,11 +177,20 @@ impl AsRef<str> for Banner {
 }
 
 impl Banner {
+    pub const MAX_LENGTH: usize = 256;
+
     pub fn new(url: impl Into<String>) -> Self {
         Self(url.into())
     }
 }
 
+impl TryFrom<String> for Banner {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parse_image_url(&value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
 #[derive(Debug, Clone, Hash, Serialize, Deserialize, Destructure)]
 pub struct Profile {
     id: ProfileId,
@@ -137,8 +198,8 @@ pub struct Profile {
     date: UpdateTime,
     name: DisplayName,
     summary: Summary,
-    icon: Icon,
-    banner: Banner
+    icon: Option<Icon>,
+    banner: Option<Banner>
 }
 
 impl Profile {
@@ -150,8 +211,8 @@ impl Profile {
         updated_at: impl Into<OffsetDateTime>,
         name: impl Into<String>,
         summary: impl Into<String>,
-        icon: impl Into<String>,
-        banner: impl Into<String>
+        icon: Option<impl Into<String>>,
+        banner: Option<impl Into<String>>
     ) -> Self {
         Self {
             id: ProfileId::new(id.into()),
@@ -159,8 +220,8 @@ impl Profile {
             date: UpdateTime::new(created_at.into(), updated_at.into()),
             name: DisplayName::new(name),
             summary: Summary::new(summary),
-            icon: Icon::new(icon),
-            banner: Banner::new(banner)
+            icon: icon.map(Icon::new),
+            banner: banner.map(Banner::new)
         }
     }
 
@@ -184,12 +245,12 @@ impl Profile {
         &self.summary
     }
 
-    pub fn icon(&self) -> &Icon {
-        &self.icon
+    pub fn icon(&self) -> Option<&Icon> {
+        self.icon.as_ref()
     }
 
-    pub fn banner(&self) -> &Banner {
-        &self.banner
+    pub fn banner(&self) -> Option<&Banner> {
+        self.banner.as_ref()
     }
 }
 
@@ -198,7 +259,7 @@ mod test {
     use time::OffsetDateTime;
     use uuid::Uuid;
 
-    use crate::entities::{Profile, AccountId};
+    use crate::entities::{Profile, AccountId, DisplayName, Summary, Icon, Banner};
 
     #[test]
     fn struct_test() {
@@ -209,8 +270,20 @@ mod test {
             OffsetDateTime::now_utc(),
             "Shuttle", 
             "This is Shuttle!", 
-            "example.com", 
-            "example.com"
+            Some("https://example.com/icon.png"), 
+            Some("https://example.com/banner.png")
         );
     }
+
+    #[test]
+    fn validation_test() {
+        assert!(DisplayName::try_from("あ".repeat(DisplayName::MAX_LENGTH)).is_ok());
+        assert!(DisplayName::try_from("a".repeat(DisplayName::MAX_LENGTH + 1)).is_err());
+        assert!(Summary::try_from("a".repeat(Summary::MAX_LENGTH + 1)).is_err());
+
+        assert!(Icon::try_from("https://example.com/icon.png".to_string()).is_ok());
+        assert!(Icon::try_from("example.com/icon.png".to_string()).is_err());
+        assert!(Icon::try_from("ftp://example.com/icon.png".to_string()).is_err());
+        assert!(Banner::try_from(format!("https://example.com/{}", "a".repeat(Banner::MAX_LENGTH))).is_err());
+    }
 }
\ No newline at end of file
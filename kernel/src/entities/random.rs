@@ -0,0 +1,10 @@
+use rand::{Rng, distributions::Alphanumeric};
+
+/// Generates an unguessable token for credentials such as sessions and OAuth secrets.
+pub(crate) fn alphanumeric(length: usize) -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(length)
+        .map(char::from)
+        .collect()
+}
\ No newline at end of file
@@ -0,0 +1,385 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{AccountTypes, CreatedAt, NoteId};
+
+use crate::error::KernelError;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionId(Uuid);
+
+impl ReactionId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for ReactionId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<ReactionId> for Uuid {
+    fn from(id: ReactionId) -> Self {
+        id.0
+    }
+}
+
+impl Default for ReactionId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// A single Unicode emoji, possibly joined with ZWJ or modifiers.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Emoji(String);
+
+impl From<Emoji> for String {
+    fn from(emoji: Emoji) -> Self {
+        emoji.0
+    }
+}
+
+impl AsRef<str> for Emoji {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Emoji {
+    /// Long enough for ZWJ sequences such as family emoji.
+    pub const MAX_LENGTH: usize = 16;
+
+    pub fn new(emoji: impl Into<String>) -> Self {
+        Self(emoji.into())
+    }
+}
+
+impl TryFrom<&str> for Emoji {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // Keycaps such as `#️⃣` are the only sequences with ascii in them.
+        let is_keycap = value.ends_with('\u{20E3}');
+        let valid = !value.is_empty()
+            && value.chars().count() <= Self::MAX_LENGTH
+            && value.chars().all(|c| !c.is_whitespace() && !c.is_control() && (is_keycap || !c.is_ascii()))
+            && value.chars().any(|c| c >= '\u{00A9}' && !c.is_alphanumeric());
+        if !valid {
+            return Err(KernelError::Convert(format!("`{}` is not an emoji.", value)));
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ReactionAssetId(Uuid);
+
+impl ReactionAssetId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl AsRef<Uuid> for ReactionAssetId {
+    fn as_ref(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<ReactionAssetId> for Uuid {
+    fn from(id: ReactionAssetId) -> Self {
+        id.0
+    }
+}
+
+impl Default for ReactionAssetId {
+    fn default() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+/// Shortcode of a custom emoji, written as `:alias:` in requests.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionAlias(String);
+
+impl From<ReactionAlias> for String {
+    fn from(alias: ReactionAlias) -> Self {
+        alias.0
+    }
+}
+
+impl AsRef<str> for ReactionAlias {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ReactionAlias {
+    pub const MAX_LENGTH: usize = 128;
+
+    pub fn new(alias: impl Into<String>) -> Self {
+        Self(alias.into())
+    }
+}
+
+impl TryFrom<&str> for ReactionAlias {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let alias = value.strip_prefix(':')
+            .and_then(|alias| alias.strip_suffix(':'))
+            .unwrap_or(value);
+        if alias.is_empty() || alias.len() > Self::MAX_LENGTH {
+            return Err(KernelError::Convert(format!(
+                "alias must be between 1 and {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        if !alias.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(KernelError::Convert("alias may only contain ascii letters, digits and `_`.".to_string()));
+        }
+        Ok(Self(alias.to_string()))
+    }
+}
+
+/// Url the image of a custom emoji is served from.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetUrl(String);
+
+impl From<AssetUrl> for String {
+    fn from(url: AssetUrl) -> Self {
+        url.0
+    }
+}
+
+impl AsRef<str> for AssetUrl {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AssetUrl {
+    pub const MAX_LENGTH: usize = 512;
+
+    pub fn new(url: impl Into<String>) -> Self {
+        Self(url.into())
+    }
+}
+
+impl TryFrom<String> for AssetUrl {
+    type Error = KernelError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let valid = value.len() <= Self::MAX_LENGTH
+            && url::Url::parse(&value).is_ok_and(|url| matches!(url.scheme(), "http" | "https"));
+        if !valid {
+            return Err(KernelError::Convert(format!(
+                "url must be an http(s) url of at most {} characters.", Self::MAX_LENGTH
+            )));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Terms a custom emoji is distributed under.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct License {
+    url: Option<AssetUrl>,
+    spdx: Option<String>
+}
+
+impl License {
+    pub const MAX_SPDX_LENGTH: usize = 128;
+
+    pub fn new(url: Option<impl Into<String>>, spdx: Option<impl Into<String>>) -> Self {
+        Self {
+            url: url.map(AssetUrl::new),
+            spdx: spdx.map(Into::into)
+        }
+    }
+
+    /// Validates `spdx` as a license expression such as `CC-BY-4.0` or `MIT OR Apache-2.0`.
+    pub fn parse(url: Option<String>, spdx: Option<String>) -> Result<Self, KernelError> {
+        let url = url.map(AssetUrl::try_from).transpose()?;
+        if let Some(spdx) = &spdx {
+            let valid = !spdx.trim().is_empty()
+                && spdx.len() <= Self::MAX_SPDX_LENGTH
+                && spdx.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | ':' | '(' | ')' | ' '));
+            if !valid {
+                return Err(KernelError::Convert(format!("`{}` is not an SPDX license expression.", spdx)));
+            }
+        }
+        Ok(Self { url, spdx })
+    }
+
+    pub fn url(&self) -> Option<&AssetUrl> {
+        self.url.as_ref()
+    }
+
+    pub fn spdx(&self) -> Option<&str> {
+        self.spdx.as_deref()
+    }
+}
+
+/// Custom emoji registered by an administrator.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct ReactionAsset {
+    id: ReactionAssetId,
+    alias: ReactionAlias,
+    asset: AssetUrl,
+    license: License,
+    created_at: CreatedAt
+}
+
+impl ReactionAsset {
+    pub fn new(
+        id: impl Into<Uuid>,
+        alias: impl Into<String>,
+        asset: impl Into<String>,
+        license: License,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: ReactionAssetId::new(id.into()),
+            alias: ReactionAlias::new(alias),
+            asset: AssetUrl::new(asset),
+            license,
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &ReactionAssetId {
+        &self.id
+    }
+
+    pub fn alias(&self) -> &ReactionAlias {
+        &self.alias
+    }
+
+    pub fn asset(&self) -> &AssetUrl {
+        &self.asset
+    }
+
+    pub fn license(&self) -> &License {
+        &self.license
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ReactionContent {
+    Emoji(Emoji),
+    Asset(ReactionAssetId)
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Reaction {
+    id: ReactionId,
+    note: NoteId,
+    account: AccountTypes,
+    content: ReactionContent,
+    created_at: CreatedAt
+}
+
+impl Reaction {
+    pub fn new(
+        id: impl Into<Uuid>,
+        note: impl Into<Uuid>,
+        account: impl Into<AccountTypes>,
+        content: ReactionContent,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: ReactionId::new(id.into()),
+            note: NoteId::new(note.into()),
+            account: account.into(),
+            content,
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &ReactionId {
+        &self.id
+    }
+
+    pub fn note(&self) -> &NoteId {
+        &self.note
+    }
+
+    pub fn account(&self) -> &AccountTypes {
+        &self.account
+    }
+
+    pub fn content(&self) -> &ReactionContent {
+        &self.content
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+/// Number of reactions with the same content on a note.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionCount {
+    content: ReactionContent,
+    count: i64
+}
+
+impl ReactionCount {
+    pub fn new(content: ReactionContent, count: i64) -> Self {
+        Self { content, count }
+    }
+
+    pub fn content(&self) -> &ReactionContent {
+        &self.content
+    }
+
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{
+        AccountId, AssetUrl, Emoji, License, NoteId, Reaction, ReactionAlias, ReactionAsset,
+        ReactionAssetId, ReactionContent, ReactionId
+    };
+
+    #[test]
+    fn struct_test() {
+        let emoji = Emoji::try_from("👍").unwrap();
+        let reaction = Reaction::new(
+            ReactionId::default(),
+            NoteId::default(),
+            AccountId::default(),
+            ReactionContent::Emoji(emoji),
+            OffsetDateTime::now_utc()
+        );
+        assert_eq!(reaction.content(), &ReactionContent::Emoji(Emoji::new("👍")));
+
+        for emoji in ["❤️", "👨‍👩‍👧", "👍🏽", "#️⃣", "🇯🇵"] {
+            assert!(Emoji::try_from(emoji).is_ok(), "{}", emoji);
+        }
+        for text in ["", "a", "hello", "あ", ":blobcat:", "👍 👍"] {
+            assert!(Emoji::try_from(text).is_err(), "{}", text);
+        }
+
+        let license = License::parse(Some("https://creativecommons.org/licenses/by/4.0/".to_string()), Some("CC-BY-4.0".to_string())).unwrap();
+        let asset = ReactionAsset::new(ReactionAssetId::default(), "blobcat", "https://cdn.example/blobcat.png", license, OffsetDateTime::now_utc());
+        assert_eq!(asset.license().spdx(), Some("CC-BY-4.0"));
+
+        assert_eq!(ReactionAlias::try_from(":blobcat:").unwrap().as_ref(), "blobcat");
+        assert!(ReactionAlias::try_from("::").is_err());
+        assert!(ReactionAlias::try_from("blob cat").is_err());
+        assert!(AssetUrl::try_from("ftp://cdn.example/blobcat.png".to_string()).is_err());
+        assert!(License::parse(None, Some("MIT; DROP".to_string())).is_err());
+    }
+}
\ No newline at end of file
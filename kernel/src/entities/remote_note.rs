@@ -0,0 +1,114 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::{CreatedAt, NoteTypes, Visibility};
+
+/// ActivityPub id of a note posted on another server.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteNoteId(String);
+
+impl RemoteNoteId {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+}
+
+impl From<RemoteNoteId> for String {
+    fn from(id: RemoteNoteId) -> Self {
+        id.0
+    }
+}
+
+impl AsRef<str> for RemoteNoteId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Note received from another server.
+/// `content` is the HTML the author's server sent, as is.
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct RemoteNote {
+    id: RemoteNoteId,
+    author: String,
+    content: String,
+    cw: Option<String>,
+    visibility: Visibility,
+    in_reply_to: Option<NoteTypes>,
+    created_at: CreatedAt
+}
+
+impl RemoteNote {
+    pub fn new(
+        id: impl Into<String>,
+        author: impl Into<String>,
+        content: impl Into<String>,
+        cw: Option<impl Into<String>>,
+        visibility: Visibility,
+        in_reply_to: Option<NoteTypes>,
+        created_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            id: RemoteNoteId::new(id),
+            author: author.into(),
+            content: content.into(),
+            cw: cw.map(Into::into),
+            visibility,
+            in_reply_to,
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &RemoteNoteId {
+        &self.id
+    }
+
+    /// Id of the actor that posted the note.
+    pub fn author(&self) -> &str {
+        &self.author
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn cw(&self) -> Option<&str> {
+        self.cw.as_deref()
+    }
+
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    pub fn in_reply_to(&self) -> Option<&NoteTypes> {
+        self.in_reply_to.as_ref()
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{NoteId, NoteTypes, RemoteNote, Visibility};
+
+    #[test]
+    fn struct_test() {
+        let reply = NoteId::default();
+        let note = RemoteNote::new(
+            "https://remote.example/notes/1",
+            "https://remote.example/users/shuttle",
+            "<p>Hello</p>",
+            None::<String>,
+            Visibility::Unlisted,
+            Some(reply.into()),
+            OffsetDateTime::now_utc()
+        );
+        assert_eq!(note.id().as_ref(), "https://remote.example/notes/1");
+        assert_eq!(note.in_reply_to(), Some(&NoteTypes::Local(reply)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,136 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::KernelError;
+
+/// OAuth2 scope such as `read`, `write:accounts` or `follow`.
+///
+/// A granular scope (`write:accounts`) is covered by its top-level scope (`write`).
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Scope(String);
+
+impl Scope {
+    pub const TOP_LEVEL: [&'static str; 4] = ["read", "write", "follow", "push"];
+
+    pub fn new(scope: impl Into<String>) -> Self {
+        Self(scope.into())
+    }
+
+    fn parent(&self) -> Option<&str> {
+        self.0.split_once(':').map(|(parent, _)| parent)
+    }
+}
+
+impl From<Scope> for String {
+    fn from(scope: Scope) -> Self {
+        scope.0
+    }
+}
+
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for Scope {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let valid = match value.split_once(':') {
+            None => Self::TOP_LEVEL.contains(&value),
+            Some((parent, resource)) => matches!(parent, "read" | "write")
+                && !resource.is_empty()
+                && resource.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+        };
+
+        if !valid {
+            return Err(KernelError::Convert(format!("`{}` is not a known scope.", value)));
+        }
+
+        Ok(Self(value.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Scopes(BTreeSet<Scope>);
+
+impl Scopes {
+    pub fn new(scopes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self(scopes.into_iter().map(Scope::new).collect())
+    }
+
+    /// Every top-level scope. Used for first-party sessions.
+    pub fn all() -> Self {
+        Self::new(Scope::TOP_LEVEL)
+    }
+
+    pub fn permits(&self, required: &Scope) -> bool {
+        self.0.contains(required)
+            || required.parent().is_some_and(|parent| self.0.contains(&Scope::new(parent)))
+    }
+
+    pub fn is_subset(&self, other: &Scopes) -> bool {
+        self.0.iter().all(|scope| other.permits(scope))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Scope> {
+        self.0.iter()
+    }
+}
+
+impl AsRef<BTreeSet<Scope>> for Scopes {
+    fn as_ref(&self) -> &BTreeSet<Scope> {
+        &self.0
+    }
+}
+
+impl From<Scopes> for Vec<String> {
+    fn from(scopes: Scopes) -> Self {
+        scopes.0.into_iter().map(String::from).collect()
+    }
+}
+
+impl std::fmt::Display for Scopes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let joined = self.0.iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<&str>>()
+            .join(" ");
+        f.write_str(&joined)
+    }
+}
+
+/// Parses a space separated scope list as sent by OAuth2 clients.
+impl TryFrom<&str> for Scopes {
+    type Error = KernelError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let scopes = value.split_whitespace()
+            .map(Scope::try_from)
+            .collect::<Result<BTreeSet<Scope>, KernelError>>()?;
+        Ok(Self(scopes))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::entities::{Scope, Scopes};
+
+    #[test]
+    fn permits_test() {
+        let scopes = Scopes::try_from("read write:accounts").unwrap();
+        assert!(scopes.permits(&Scope::new("read")));
+        assert!(scopes.permits(&Scope::new("read:statuses")));
+        assert!(scopes.permits(&Scope::new("write:accounts")));
+        assert!(!scopes.permits(&Scope::new("write")));
+        assert!(!scopes.permits(&Scope::new("write:statuses")));
+
+        assert!(scopes.is_subset(&Scopes::all()));
+        assert!(!Scopes::all().is_subset(&scopes));
+        assert!(Scopes::try_from("read admin").is_err());
+    }
+}
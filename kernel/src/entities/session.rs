@@ -0,0 +1,103 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use super::{random, AccountId, CreatedAt, ExpiresAt};
+
+/// Opaque bearer token. It carries no information about the account.
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionToken(String);
+
+impl std::fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionToken(**hidden**)")
+    }
+}
+
+impl From<SessionToken> for String {
+    fn from(token: SessionToken) -> Self {
+        token.0
+    }
+}
+
+impl AsRef<str> for SessionToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl SessionToken {
+    pub const LENGTH: usize = 64;
+
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl Default for SessionToken {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Session {
+    token: SessionToken,
+    account: AccountId,
+    created_at: CreatedAt,
+    expires_at: ExpiresAt
+}
+
+impl Session {
+    pub const LIFETIME: Duration = Duration::days(30);
+
+    pub fn new(
+        token: impl Into<String>,
+        account: impl Into<i64>,
+        created_at: impl Into<OffsetDateTime>,
+        expires_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            token: SessionToken::new(token),
+            account: AccountId::new(account),
+            created_at: CreatedAt::new(created_at.into()),
+            expires_at: ExpiresAt::new(expires_at.into())
+        }
+    }
+
+    pub fn token(&self) -> &SessionToken {
+        &self.token
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &ExpiresAt {
+        &self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, Session, SessionToken};
+
+    #[test]
+    fn struct_test() {
+        let now = OffsetDateTime::now_utc();
+        let session = Session::new(
+            SessionToken::default(),
+            AccountId::default(),
+            now,
+            now + Session::LIFETIME
+        );
+        assert_eq!(session.token().as_ref().len(), SessionToken::LENGTH);
+        assert!(!session.expires_at().is_expired());
+    }
+}
@@ -0,0 +1,248 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use sha2::{Digest, Sha256};
+use time::{Duration, OffsetDateTime};
+use url::{Position, Url};
+
+use super::{PrivateKey, PublicKey};
+
+use crate::error::KernelError;
+
+/// `Digest` header value (RFC 3230) of `body`.
+pub fn body_digest(body: &[u8]) -> String {
+    format!("SHA-256={}", STANDARD.encode(Sha256::digest(body)))
+}
+
+/// Formats `time` as the IMF-fixdate used by the `Date` header.
+pub fn http_date(time: OffsetDateTime) -> String {
+    httpdate::fmt_http_date(time.into())
+}
+
+pub fn parse_http_date(value: &str) -> Option<OffsetDateTime> {
+    httpdate::parse_http_date(value).ok().map(Into::into)
+}
+
+/// `Signature` header of draft-cavage-http-signatures-12.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpSignature {
+    key_id: String,
+    algorithm: Option<String>,
+    headers: Vec<String>,
+    signature: Vec<u8>
+}
+
+impl HttpSignature {
+    /// `rsa-sha256` is what the fediverse signs with. `hs2019` leaves the algorithm to the key.
+    pub const ALGORITHMS: [&'static str; 2] = ["rsa-sha256", "hs2019"];
+
+    /// How far the `Date` of a signed request may be from now.
+    pub const MAX_SKEW: Duration = Duration::hours(12);
+
+    pub fn new(
+        key_id: impl Into<String>,
+        algorithm: Option<String>,
+        headers: impl IntoIterator<Item = impl Into<String>>,
+        signature: impl Into<Vec<u8>>
+    ) -> Self {
+        Self {
+            key_id: key_id.into(),
+            algorithm,
+            headers: headers.into_iter().map(Into::into).collect(),
+            signature: signature.into()
+        }
+    }
+
+    /// Parses `keyId="..",algorithm="..",headers="..",signature=".."`.
+    /// `headers` defaults to `date` when omitted.
+    pub fn parse(value: &str) -> Result<Self, KernelError> {
+        let (mut key_id, mut algorithm, mut headers, mut signature) = (None, None, None, None);
+
+        let mut rest = value.trim();
+        while !rest.is_empty() {
+            let Some((name, after)) = rest.split_once('=') else {
+                return Err(KernelError::Convert(format!("`{}` is not a signature parameter.", rest)));
+            };
+            let (value, after) = match after.strip_prefix('"') {
+                Some(quoted) => quoted.split_once('"')
+                    .ok_or_else(|| KernelError::Convert("unterminated signature parameter.".to_string()))?,
+                None => after.split_once(',').unwrap_or((after, ""))
+            };
+            match name.trim() {
+                "keyId" => key_id = Some(value.to_string()),
+                "algorithm" => algorithm = Some(value.to_ascii_lowercase()),
+                "headers" => headers = Some(value.split_whitespace().map(str::to_ascii_lowercase).collect::<Vec<_>>()),
+                "signature" => signature = Some(STANDARD.decode(value)
+                    .map_err(|e| KernelError::Convert(format!("failed decode signature. `base64`: {}", e)))?),
+                _ => {}
+            }
+            rest = after.trim_start_matches([',', ' ']);
+        }
+
+        let (Some(key_id), Some(signature)) = (key_id, signature) else {
+            return Err(KernelError::Convert("`keyId` and `signature` are required.".to_string()));
+        };
+
+        Ok(Self {
+            key_id,
+            algorithm,
+            headers: headers.unwrap_or_else(|| vec!["date".to_string()]),
+            signature
+        })
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    pub fn algorithm(&self) -> Option<&str> {
+        self.algorithm.as_deref()
+    }
+
+    /// Lowercase names of the signed headers, in signing order.
+    pub fn headers(&self) -> &[String] {
+        &self.headers
+    }
+
+    pub fn covers(&self, header: &str) -> bool {
+        self.headers.iter().any(|signed| signed == header)
+    }
+
+    /// Builds the string that is signed, one `name: value` line per signed header.
+    /// `(request-target)` comes from `method` and `path`, other headers from `lookup` by lowercase name.
+    pub fn signing_string(
+        &self,
+        method: &str,
+        path: &str,
+        lookup: impl Fn(&str) -> Option<String>
+    ) -> Result<String, KernelError> {
+        self.headers.iter()
+            .map(|name| match name.as_str() {
+                "(request-target)" => Ok(format!("(request-target): {} {}", method.to_ascii_lowercase(), path)),
+                _ => lookup(name)
+                    .map(|value| format!("{}: {}", name, value))
+                    .ok_or_else(|| KernelError::Convert(format!("signed header `{}` is missing.", name)))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(|lines| lines.join("\n"))
+    }
+
+    pub fn verify(&self, signing_string: &str, key: &PublicKey) -> bool {
+        key.verify(signing_string.as_bytes(), &self.signature)
+    }
+
+    pub fn to_header(&self) -> String {
+        format!(
+            r#"keyId="{}",algorithm="{}",headers="{}",signature="{}""#,
+            self.key_id,
+            self.algorithm.as_deref().unwrap_or(Self::ALGORITHMS[0]),
+            self.headers.join(" "),
+            STANDARD.encode(&self.signature)
+        )
+    }
+}
+
+/// Headers to set on a request signed by [`RequestSigner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedHeaders {
+    pub host: String,
+    pub date: String,
+    pub digest: Option<String>,
+    pub signature: String
+}
+
+/// Signs outgoing requests over `(request-target) host date`, plus `digest` for requests with a body.
+#[derive(Debug, Clone)]
+pub struct RequestSigner {
+    key_id: String,
+    key: PrivateKey
+}
+
+impl RequestSigner {
+    /// `key_id` is the `publicKey.id` of the signing actor.
+    pub fn new(key_id: impl Into<String>, key: PrivateKey) -> Self {
+        Self { key_id: key_id.into(), key }
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    pub fn sign(&self, method: &str, url: &Url, body: Option<&[u8]>) -> Result<SignedHeaders, KernelError> {
+        let host = url[Position::BeforeHost..Position::AfterPort].to_string();
+        let path = &url[Position::BeforePath..Position::AfterQuery];
+        let date = http_date(OffsetDateTime::now_utc());
+        let digest = body.map(body_digest);
+
+        let mut headers = vec!["(request-target)", "host", "date"];
+        if digest.is_some() {
+            headers.push("digest");
+        }
+
+        let unsigned = HttpSignature::new(&self.key_id, Some(HttpSignature::ALGORITHMS[0].to_string()), headers, Vec::new());
+        let signing_string = unsigned.signing_string(method, path, |name| match name {
+            "host" => Some(host.clone()),
+            "date" => Some(date.clone()),
+            "digest" => digest.clone(),
+            _ => None
+        })?;
+
+        let signature = HttpSignature {
+            signature: self.key.sign(signing_string.as_bytes())?,
+            ..unsigned
+        };
+
+        Ok(SignedHeaders { host, date, digest, signature: signature.to_header() })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use crate::entities::{body_digest, parse_http_date, AccountId, AccountKey, HttpSignature, RequestSigner};
+
+    #[test]
+    fn struct_test() {
+        let header = r#"keyId="https://remote.example/users/a#main-key",algorithm="rsa-sha256",headers="(request-target) host date digest",signature="c2lnbmF0dXJl""#;
+        let parsed = HttpSignature::parse(header).unwrap();
+        assert_eq!(parsed.key_id(), "https://remote.example/users/a#main-key");
+        assert_eq!(parsed.algorithm(), Some("rsa-sha256"));
+        assert_eq!(parsed.headers(), ["(request-target)", "host", "date", "digest"]);
+        assert_eq!(HttpSignature::parse(&parsed.to_header()).unwrap(), parsed);
+
+        let defaulted = HttpSignature::parse(r#"keyId="key", signature="c2lnbmF0dXJl""#).unwrap();
+        assert_eq!(defaulted.headers(), ["date"]);
+
+        assert!(HttpSignature::parse(r#"keyId="key""#).is_err());
+        assert!(HttpSignature::parse(r#"keyId="key,signature=c2lnbmF0dXJl"#).is_err());
+    }
+
+    #[test]
+    fn sign_test() {
+        let key = AccountKey::generate(AccountId::default()).unwrap();
+        let signer = RequestSigner::new("https://example.com/users/a#main-key", key.private_key().clone());
+        let url = Url::parse("https://remote.example:8443/inbox?x=1").unwrap();
+
+        let body = br#"{"type":"Follow"}"#;
+        let signed = signer.sign("POST", &url, Some(body)).unwrap();
+        assert_eq!(signed.host, "remote.example:8443");
+        assert_eq!(signed.digest.as_deref(), Some(body_digest(body).as_str()));
+        assert!(parse_http_date(&signed.date).is_some());
+
+        let signature = HttpSignature::parse(&signed.signature).unwrap();
+        let lookup = |name: &str| match name {
+            "host" => Some(signed.host.clone()),
+            "date" => Some(signed.date.clone()),
+            "digest" => signed.digest.clone(),
+            _ => None
+        };
+        let signing_string = signature.signing_string("POST", "/inbox?x=1", lookup).unwrap();
+        assert!(signature.verify(&signing_string, key.public_key()));
+
+        let tampered = signature.signing_string("POST", "/inbox", lookup).unwrap();
+        assert!(!signature.verify(&tampered, key.public_key()));
+
+        let unsigned_body = signer.sign("GET", &url, None).unwrap();
+        assert!(unsigned_body.digest.is_none());
+        assert!(!HttpSignature::parse(&unsigned_body.signature).unwrap().covers("digest"));
+    }
+}
\ No newline at end of file
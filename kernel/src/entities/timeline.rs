@@ -0,0 +1,110 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use super::{Boost, CreatedAt, Note, RemoteNote};
+
+/// Position of an entry in a note listing, which is ordered by `(created_at, id)`.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct TimelineCursor {
+    id: Uuid,
+    created_at: CreatedAt
+}
+
+impl TimelineCursor {
+    /// Entries a cached home timeline keeps. Older ones are read from the database.
+    pub const HOME_CAPACITY: i64 = 800;
+
+    pub fn new(id: impl Into<Uuid>, created_at: impl Into<OffsetDateTime>) -> Self {
+        Self {
+            id: id.into(),
+            created_at: CreatedAt::new(created_at.into())
+        }
+    }
+
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+}
+
+/// Entry of a note listing. A boost carries the note it reshares.
+/// A remote note comes with the id it is paged by, since its own id is a url.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum TimelineEntry {
+    Note(Note),
+    Boost(Boost, Note),
+    Remote(Uuid, RemoteNote)
+}
+
+impl TimelineEntry {
+    /// Id of the note or the boost, used as the pagination cursor.
+    pub fn id(&self) -> &Uuid {
+        match self {
+            Self::Note(note) => note.id().as_ref(),
+            Self::Boost(boost, _) => boost.id().as_ref(),
+            Self::Remote(cursor, _) => cursor
+        }
+    }
+
+    /// When the entry appeared, which is when the boost was made for boosts.
+    pub fn created_at(&self) -> &CreatedAt {
+        match self {
+            Self::Note(note) => note.created_at(),
+            Self::Boost(boost, _) => boost.created_at(),
+            Self::Remote(_, note) => note.created_at()
+        }
+    }
+
+    pub fn cursor(&self) -> TimelineCursor {
+        TimelineCursor::new(*self.id(), *self.created_at().as_ref())
+    }
+
+    /// The local note listed or reshared. `None` for remote notes.
+    pub fn note(&self) -> Option<&Note> {
+        match self {
+            Self::Note(note) | Self::Boost(_, note) => Some(note),
+            Self::Remote(..) => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+    use uuid::Uuid;
+
+    use crate::entities::{AccountId, Boost, BoostId, Note, NoteId, RemoteNote, TimelineEntry, Visibility};
+
+    #[test]
+    fn struct_test() {
+        let author = AccountId::new(1);
+        let note = Note::new(
+            NoteId::default(), author, "test", None::<String>, Visibility::Public, None, None, [],
+            Vec::<Uuid>::new(), Vec::<Uuid>::new(), OffsetDateTime::UNIX_EPOCH
+        );
+        let boost = Boost::new(BoostId::default(), AccountId::new(2), *note.id(), Visibility::Public, false, OffsetDateTime::now_utc());
+
+        let entry = TimelineEntry::Note(note.clone());
+        assert_eq!(entry.cursor().id(), note.id().as_ref());
+
+        // A boost is placed by when it was made, not by its note.
+        let entry = TimelineEntry::Boost(boost.clone(), note.clone());
+        assert_eq!(entry.cursor().id(), boost.id().as_ref());
+        assert_eq!(entry.cursor().created_at(), boost.created_at());
+        assert_eq!(entry.note(), Some(&note));
+
+        // A remote note is paged by its cursor rather than by its url.
+        let remote = RemoteNote::new(
+            "https://remote.example/notes/1", "https://remote.example/users/a", "<p>hi</p>", None::<String>,
+            Visibility::Public, None, OffsetDateTime::UNIX_EPOCH
+        );
+        let cursor = Uuid::new_v4();
+        let entry = TimelineEntry::Remote(cursor, remote.clone());
+        assert_eq!(entry.cursor().id(), &cursor);
+        assert_eq!(entry.cursor().created_at(), remote.created_at());
+        assert!(entry.note().is_none());
+    }
+}
\ No newline at end of file
@@ -70,3 +70,27 @@ impl UpdateTime {
     }
 }
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpiresAt(OffsetDateTime);
+
+impl ExpiresAt {
+    pub fn new(time: OffsetDateTime) -> Self {
+        Self(time)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.0 <= OffsetDateTime::now_utc()
+    }
+}
+
+impl From<ExpiresAt> for OffsetDateTime {
+    fn from(date: ExpiresAt) -> Self {
+        date.0
+    }
+}
+
+impl AsRef<OffsetDateTime> for ExpiresAt {
+    fn as_ref(&self) -> &OffsetDateTime {
+        &self.0
+    }
+}
@@ -0,0 +1,103 @@
+use destructure::Destructure;
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+
+use super::{random, AccountId, CreatedAt, ExpiresAt};
+
+/// Sent to the registered address to prove that the account owns it.
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationToken(String);
+
+impl std::fmt::Debug for VerificationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("VerificationToken(**hidden**)")
+    }
+}
+
+impl From<VerificationToken> for String {
+    fn from(token: VerificationToken) -> Self {
+        token.0
+    }
+}
+
+impl AsRef<str> for VerificationToken {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl VerificationToken {
+    pub const LENGTH: usize = 43;
+
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl Default for VerificationToken {
+    fn default() -> Self {
+        Self(random::alphanumeric(Self::LENGTH))
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize, Destructure)]
+pub struct Verification {
+    token: VerificationToken,
+    account: AccountId,
+    created_at: CreatedAt,
+    expires_at: ExpiresAt
+}
+
+impl Verification {
+    pub const LIFETIME: Duration = Duration::days(1);
+
+    pub fn new(
+        token: impl Into<String>,
+        account: impl Into<i64>,
+        created_at: impl Into<OffsetDateTime>,
+        expires_at: impl Into<OffsetDateTime>
+    ) -> Self {
+        Self {
+            token: VerificationToken::new(token),
+            account: AccountId::new(account),
+            created_at: CreatedAt::new(created_at.into()),
+            expires_at: ExpiresAt::new(expires_at.into())
+        }
+    }
+
+    pub fn token(&self) -> &VerificationToken {
+        &self.token
+    }
+
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn created_at(&self) -> &CreatedAt {
+        &self.created_at
+    }
+
+    pub fn expires_at(&self) -> &ExpiresAt {
+        &self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use time::OffsetDateTime;
+
+    use crate::entities::{AccountId, Verification, VerificationToken};
+
+    #[test]
+    fn struct_test() {
+        let now = OffsetDateTime::now_utc();
+        let verification = Verification::new(
+            VerificationToken::default(),
+            AccountId::default(),
+            now,
+            now + Verification::LIFETIME
+        );
+        assert_eq!(verification.token().as_ref().len(), VerificationToken::LENGTH);
+        assert!(!verification.expires_at().is_expired());
+    }
+}
\ No newline at end of file
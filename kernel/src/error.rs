@@ -6,6 +6,12 @@ pub enum KernelError {
         entity: &'static str,
         id: String
     },
+    /// A unique value such as an account name is already taken.
+    #[error("`{id}:{entity}` already exists.")]
+    Conflict {
+        entity: &'static str,
+        id: String
+    },
     #[error("this value illegal. {0}")]
     Convert(String),
     #[error(transparent)]
@@ -1,5 +1,6 @@
 pub mod entities;
 pub mod repository;
+pub mod service;
 mod error;
 
 pub use self::error::*;
\ No newline at end of file
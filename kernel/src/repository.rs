@@ -1,9 +1,45 @@
 mod account;
 mod profile;
 mod follow;
+mod confidential;
+mod session;
+mod application;
+mod authorization_code;
+mod oauth_token;
+mod verification;
+mod password_reset;
+mod note;
+mod hashtag;
+mod reaction;
+mod boost;
+mod timeline;
+mod timeline_cache;
+mod remote_note;
+mod received_activity;
+mod delivery;
+mod account_key;
+mod remote_key;
 
 pub use self::{
     account::*,
     profile::*,
-    follow::*
+    follow::*,
+    confidential::*,
+    session::*,
+    application::*,
+    authorization_code::*,
+    oauth_token::*,
+    verification::*,
+    password_reset::*,
+    note::*,
+    hashtag::*,
+    reaction::*,
+    boost::*,
+    timeline::*,
+    timeline_cache::*,
+    remote_note::*,
+    received_activity::*,
+    delivery::*,
+    account_key::*,
+    remote_key::*
 };
\ No newline at end of file
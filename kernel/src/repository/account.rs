@@ -1,13 +1,22 @@
-use crate::entities::{Account, AccountId, AccountName};
+use crate::entities::{Account, AccountId, AccountKey, AccountName, Confidential};
 use crate::error::KernelError;
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 #[async_trait::async_trait]
 pub trait AccountRepository: Send + Sync + 'static {
     async fn create(&self, create: &Account) -> Result<(), KernelError>;
+    /// Creates a new account together with its confidential and key, all or nothing.
+    /// A taken name or address fails with [`KernelError::Conflict`].
+    async fn register(&self, account: &Account, confidential: &Confidential, key: &AccountKey) -> Result<(), KernelError>;
+    async fn update(&self, update: &Account) -> Result<(), KernelError>;
     async fn delete(&self, delete: &AccountId) -> Result<(), KernelError>;
 
     async fn find_all(&self) -> Result<Vec<Account>, KernelError>;
     async fn find_by_id(&self, id: &AccountId) -> Result<Option<Account>, KernelError>;
+    /// Unknown ids are skipped. The order of the result is unspecified.
+    async fn find_by_ids(&self, ids: &[AccountId]) -> Result<Vec<Account>, KernelError>;
+    /// Names are compared case-insensitively.
     async fn find_by_name(&self, name: &AccountName) -> Result<Option<Account>, KernelError>;
+
+    async fn count(&self) -> Result<i64, KernelError>;
 }
\ No newline at end of file
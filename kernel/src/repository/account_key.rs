@@ -0,0 +1,10 @@
+use crate::{error::KernelError, entities::{AccountKey, AccountId}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountKeyRepository: Send + Sync + 'static {
+    /// Does nothing if the account has a key already. Read the key back to get the one that was kept.
+    async fn create(&self, create: &AccountKey) -> Result<(), KernelError>;
+
+    async fn find_by_account_id(&self, id: &AccountId) -> Result<Option<AccountKey>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{Application, ApplicationId, ClientId}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ApplicationRepository: Send + Sync + 'static {
+    async fn create(&self, create: &Application) -> Result<(), KernelError>;
+    async fn delete(&self, delete: &ApplicationId) -> Result<(), KernelError>;
+
+    async fn find_by_id(&self, id: &ApplicationId) -> Result<Option<Application>, KernelError>;
+    async fn find_by_client_id(&self, client_id: &ClientId) -> Result<Option<Application>, KernelError>;
+}
\ No newline at end of file
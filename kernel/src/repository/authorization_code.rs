@@ -0,0 +1,10 @@
+use crate::{error::KernelError, entities::{AuthorizationCode, Code}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait AuthorizationCodeRepository: Send + Sync + 'static {
+    async fn create(&self, create: &AuthorizationCode) -> Result<(), KernelError>;
+
+    /// Removes the code and returns it, so that a code can be exchanged only once.
+    async fn take(&self, code: &Code) -> Result<Option<AuthorizationCode>, KernelError>;
+}
\ No newline at end of file
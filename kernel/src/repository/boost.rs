@@ -0,0 +1,13 @@
+use crate::{entities::{AccountTypes, Boost, NoteTypes}, error::KernelError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait BoostRepository: Send + Sync + 'static {
+    /// Does nothing if the account has boosted the note already.
+    async fn create(&self, create: &Boost) -> Result<(), KernelError>;
+    async fn delete(&self, account: &AccountTypes, note: &NoteTypes) -> Result<(), KernelError>;
+
+    async fn find(&self, account: &AccountTypes, note: &NoteTypes) -> Result<Option<Boost>, KernelError>;
+    /// Boosts of `note`, oldest first.
+    async fn find_by_note(&self, note: &NoteTypes) -> Result<Vec<Boost>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{Confidential, AccountId, Address}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ConfidentialRepository: Send + Sync + 'static {
+    async fn create(&self, create: &Confidential) -> Result<(), KernelError>;
+    async fn update(&self, update: &Confidential) -> Result<(), KernelError>;
+
+    async fn find_by_account_id(&self, id: &AccountId) -> Result<Option<Confidential>, KernelError>;
+    async fn find_by_address(&self, address: &Address) -> Result<Option<Confidential>, KernelError>;
+}
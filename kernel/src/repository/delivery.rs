@@ -0,0 +1,25 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::{entities::{Delivery, DeliveryHost, DeliveryId}, error::KernelError};
+
+/// Queue of outgoing deliveries. Several workers may take from it at once.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait DeliveryRepository: Send + Sync + 'static {
+    async fn create(&self, create: &Delivery) -> Result<(), KernelError>;
+    /// Only the attempts and the time of the next attempt can be changed.
+    async fn update(&self, update: &Delivery) -> Result<(), KernelError>;
+    async fn delete(&self, delete: &DeliveryId) -> Result<(), KernelError>;
+
+    /// Takes up to `limit` deliveries due at `now`, oldest first.
+    /// They are hidden from other workers until `now + lease`, and come back after that unless updated or deleted.
+    async fn claim(&self, now: &OffsetDateTime, lease: &Duration, limit: i64) -> Result<Vec<Delivery>, KernelError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait DeliveryHostRepository: Send + Sync + 'static {
+    async fn save(&self, save: &DeliveryHost) -> Result<(), KernelError>;
+
+    async fn find_by_host(&self, host: &str) -> Result<Option<DeliveryHost>, KernelError>;
+}
\ No newline at end of file
@@ -1,11 +1,21 @@
-use crate::{entities::{AccountTypes, AccountId}, error::KernelError};
+use crate::{entities::{AccountTypes, Follow, FollowId}, error::KernelError};
 
 #[cfg_attr(feature = "mock", mockall::automock)]
 #[async_trait::async_trait]
 pub trait FollowRepository: Send + Sync + 'static {
-    async fn add(account: &AccountTypes) -> Result<(), KernelError>;
-    async fn remove(account: &AccountTypes) -> Result<(), KernelError>;
+    /// Does nothing if `source` already follows or requested to follow `destination`.
+    async fn create(&self, create: &Follow) -> Result<(), KernelError>;
+    /// Only the state can be changed.
+    async fn update(&self, update: &Follow) -> Result<(), KernelError>;
+    async fn delete(&self, source: &AccountTypes, destination: &AccountTypes) -> Result<(), KernelError>;
 
-    async fn find_all_by_src(id: &AccountId) -> Result<Vec<AccountTypes>, KernelError>;
-    async fn find_all_by_local(id: &AccountId) -> Result<Vec<AccountId>, KernelError>;
+    async fn find_by_id(&self, id: &FollowId) -> Result<Option<Follow>, KernelError>;
+    /// Returns the follow in any state.
+    async fn find(&self, source: &AccountTypes, destination: &AccountTypes) -> Result<Option<Follow>, KernelError>;
+    /// Accepted follows whose destination is `account`, newest first.
+    async fn find_followers(&self, account: &AccountTypes) -> Result<Vec<Follow>, KernelError>;
+    /// Accepted follows whose source is `account`, newest first.
+    async fn find_followings(&self, account: &AccountTypes) -> Result<Vec<Follow>, KernelError>;
+    /// Pending follows whose destination is `account`, oldest first.
+    async fn find_requests(&self, account: &AccountTypes) -> Result<Vec<Follow>, KernelError>;
 }
\ No newline at end of file
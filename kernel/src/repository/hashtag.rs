@@ -0,0 +1,10 @@
+use crate::{entities::{Hashtag, HashtagName}, error::KernelError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait HashtagRepository: Send + Sync + 'static {
+    /// Creates the tags that do not exist yet and returns all of `names`, in the same order.
+    async fn upsert(&self, names: &[HashtagName]) -> Result<Vec<Hashtag>, KernelError>;
+
+    async fn find_by_name(&self, name: &HashtagName) -> Result<Option<Hashtag>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use time::OffsetDateTime;
+
+use crate::{entities::{HashtagId, Note, NoteId, Pagination}, error::KernelError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait NoteRepository: Send + Sync + 'static {
+    async fn create(&self, create: &Note) -> Result<(), KernelError>;
+    async fn delete(&self, delete: &NoteId) -> Result<(), KernelError>;
+
+    async fn find_by_id(&self, id: &NoteId) -> Result<Option<Note>, KernelError>;
+    /// Local notes `id` replies to, up to `depth` levels, the root first.
+    /// Stops at the first remote note.
+    async fn find_ancestors(&self, id: &NoteId, depth: i64) -> Result<Vec<Note>, KernelError>;
+    /// Local replies to `id`, up to `depth` levels, oldest first.
+    async fn find_descendants(&self, id: &NoteId, depth: i64) -> Result<Vec<Note>, KernelError>;
+    /// Public notes tagged with `hashtag`, newest first.
+    async fn find_by_hashtag(&self, hashtag: &HashtagId, page: &Pagination) -> Result<Vec<Note>, KernelError>;
+
+    async fn count(&self) -> Result<i64, KernelError>;
+    /// Number of accounts that posted a note at or after `since`.
+    async fn count_authors(&self, since: &OffsetDateTime) -> Result<i64, KernelError>;
+}
\ No newline at end of file
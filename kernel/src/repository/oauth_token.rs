@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{OAuthToken, AccessToken, AccountId}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait OAuthTokenRepository: Send + Sync + 'static {
+    async fn create(&self, create: &OAuthToken) -> Result<(), KernelError>;
+    async fn delete(&self, delete: &AccessToken) -> Result<(), KernelError>;
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError>;
+
+    async fn find_by_token(&self, token: &AccessToken) -> Result<Option<OAuthToken>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{PasswordReset, PasswordResetToken, AccountId}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait PasswordResetRepository: Send + Sync + 'static {
+    async fn create(&self, create: &PasswordReset) -> Result<(), KernelError>;
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError>;
+
+    /// Removes the token and returns it, so that a token can be used only once.
+    async fn take(&self, token: &PasswordResetToken) -> Result<Option<PasswordReset>, KernelError>;
+}
\ No newline at end of file
@@ -3,6 +3,7 @@ use crate::{error::KernelError, entities::{Profile, AccountId}};
 #[cfg_attr(feature = "mock", mockall::automock)]
 #[async_trait::async_trait]
 pub trait ProfileRepository: Send + Sync + 'static {
+    /// Fails with `Conflict` if the account has a profile already.
     async fn create(&self, create: &Profile) -> Result<(), KernelError>;
     async fn update(&self, update: &Profile) -> Result<(), KernelError>;
 
@@ -0,0 +1,29 @@
+use crate::{
+    entities::{AccountTypes, NoteId, Reaction, ReactionAlias, ReactionAsset, ReactionAssetId, ReactionCount},
+    error::KernelError
+};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReactionRepository: Send + Sync + 'static {
+    /// Replaces the reaction `account` already has on the note, if any.
+    async fn create(&self, create: &Reaction) -> Result<(), KernelError>;
+    async fn delete(&self, note: &NoteId, account: &AccountTypes) -> Result<(), KernelError>;
+
+    async fn find(&self, note: &NoteId, account: &AccountTypes) -> Result<Option<Reaction>, KernelError>;
+    /// Reactions on `note` grouped by content, most used first.
+    async fn count(&self, note: &NoteId) -> Result<Vec<ReactionCount>, KernelError>;
+}
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReactionAssetRepository: Send + Sync + 'static {
+    async fn create(&self, create: &ReactionAsset) -> Result<(), KernelError>;
+    async fn delete(&self, delete: &ReactionAssetId) -> Result<(), KernelError>;
+
+    async fn find_all(&self) -> Result<Vec<ReactionAsset>, KernelError>;
+    async fn find_by_id(&self, id: &ReactionAssetId) -> Result<Option<ReactionAsset>, KernelError>;
+    /// Unknown ids are skipped. The order of the result is unspecified.
+    async fn find_by_ids(&self, ids: &[ReactionAssetId]) -> Result<Vec<ReactionAsset>, KernelError>;
+    async fn find_by_alias(&self, alias: &ReactionAlias) -> Result<Option<ReactionAsset>, KernelError>;
+}
\ No newline at end of file
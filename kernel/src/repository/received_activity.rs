@@ -0,0 +1,11 @@
+use crate::{entities::ActivityId, error::KernelError};
+
+/// Ids of inbox activities that have been handled, so that redeliveries can be skipped.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReceivedActivityRepository: Send + Sync + 'static {
+    /// Records the id, returning `false` when it was already recorded.
+    async fn claim(&self, id: &ActivityId) -> Result<bool, KernelError>;
+
+    async fn release(&self, id: &ActivityId) -> Result<(), KernelError>;
+}
\ No newline at end of file
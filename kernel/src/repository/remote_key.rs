@@ -0,0 +1,12 @@
+use crate::{error::KernelError, entities::RemoteKey};
+
+/// Cache of remote actors' public keys, keyed by `keyId`.
+/// Entries expire, so a missing key has to be fetched again.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RemoteKeyRepository: Send + Sync + 'static {
+    async fn save(&self, save: &RemoteKey) -> Result<(), KernelError>;
+    async fn delete(&self, key_id: &str) -> Result<(), KernelError>;
+
+    async fn find_by_id(&self, key_id: &str) -> Result<Option<RemoteKey>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use crate::{entities::{NoteId, RemoteNote, RemoteNoteId}, error::KernelError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait RemoteNoteRepository: Send + Sync + 'static {
+    /// Does nothing if a note with the same id has been stored already.
+    async fn create(&self, create: &RemoteNote) -> Result<(), KernelError>;
+    /// Only deletes the note if it was posted by `author`.
+    async fn delete(&self, id: &RemoteNoteId, author: &str) -> Result<(), KernelError>;
+
+    async fn find_by_id(&self, id: &RemoteNoteId) -> Result<Option<RemoteNote>, KernelError>;
+    /// Replies to any of the local `targets`, and the remote replies to those up to `depth` levels, oldest first.
+    async fn find_replies(&self, targets: &[NoteId], depth: i64) -> Result<Vec<RemoteNote>, KernelError>;
+}
\ No newline at end of file
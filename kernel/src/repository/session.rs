@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{Session, SessionToken, AccountId}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait SessionRepository: Send + Sync + 'static {
+    async fn create(&self, create: &Session) -> Result<(), KernelError>;
+    async fn delete(&self, delete: &SessionToken) -> Result<(), KernelError>;
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError>;
+
+    async fn find_by_token(&self, token: &SessionToken) -> Result<Option<Session>, KernelError>;
+}
@@ -0,0 +1,35 @@
+use uuid::Uuid;
+
+use crate::{entities::{AccountId, Pagination, TimelineCursor, TimelineEntry, Visibility}, error::KernelError};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait TimelineRepository: Send + Sync + 'static {
+    /// Notes and boosts of `account`, newest first.
+    ///
+    /// Entries are limited to `visibilities`, plus notes mentioning `viewer`.
+    /// Boosts of remote notes are left out.
+    async fn find_by_account(
+        &self,
+        account: &AccountId,
+        visibilities: &[Visibility],
+        viewer: Option<AccountId>,
+        page: &Pagination
+    ) -> Result<Vec<TimelineEntry>, KernelError>;
+
+    /// Notes and boosts of `account` and of `following`, newest first.
+    ///
+    /// Followers-only notes of `following` are included, so it must only hold accepted follows.
+    /// Direct notes are limited to those written by or mentioning `account`.
+    async fn find_home(&self, account: &AccountId, following: &[AccountId], page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError>;
+    /// Public notes, newest first. Boosts are left out.
+    async fn find_public(&self, page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError>;
+    /// Public notes of this server and those received from others, newest first. Boosts are left out.
+    async fn find_federated(&self, page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError>;
+    /// Notes of other accounts mentioning `account`, newest first, whatever their visibility.
+    async fn find_mentions(&self, account: &AccountId, page: &Pagination) -> Result<Vec<TimelineEntry>, KernelError>;
+    /// Positions of the newest `limit` entries of the home timeline, as `find_home` lists them.
+    async fn find_home_cursors(&self, account: &AccountId, following: &[AccountId], limit: i64) -> Result<Vec<TimelineCursor>, KernelError>;
+    /// Notes and boosts by their entry ids, newest first. Ids that no longer exist are skipped.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TimelineEntry>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+use uuid::Uuid;
+
+use crate::{entities::{AccountId, Pagination, TimelineCursor}, error::KernelError};
+
+/// Home timelines kept per account as the positions of their newest entries.
+/// At most `TimelineCursor::HOME_CAPACITY` entries are kept, and a cache may drop a timeline at any time.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait TimelineCacheRepository: Send + Sync + 'static {
+    /// Adds `entry` to the cached home timelines of `accounts`, trimming the oldest entries.
+    /// Accounts without a cached timeline are skipped, so that their next read rebuilds it whole.
+    async fn push(&self, accounts: &[AccountId], entry: &TimelineCursor) -> Result<(), KernelError>;
+    /// Replaces the home timeline of `account` with `entries`, which are its newest entries in any order.
+    async fn rebuild(&self, account: &AccountId, entries: &[TimelineCursor]) -> Result<(), KernelError>;
+    /// Drops the home timeline of `account`, such as when whom it follows changes.
+    async fn delete(&self, account: &AccountId) -> Result<(), KernelError>;
+
+    /// Ids of the entries in `page` of the home timeline of `account`, newest first.
+    ///
+    /// `None` if the timeline is not cached, or the page reaches beyond what is cached.
+    async fn find(&self, account: &AccountId, page: &Pagination) -> Result<Option<Vec<Uuid>>, KernelError>;
+}
\ No newline at end of file
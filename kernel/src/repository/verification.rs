@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{Verification, VerificationToken, AccountId}};
+
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait VerificationRepository: Send + Sync + 'static {
+    async fn create(&self, create: &Verification) -> Result<(), KernelError>;
+    async fn delete_all_by_account(&self, account: &AccountId) -> Result<(), KernelError>;
+
+    /// Removes the token and returns it, so that a token can be used only once.
+    async fn take(&self, token: &VerificationToken) -> Result<Option<Verification>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+mod mailer;
+mod key_fetcher;
+mod deliverer;
+mod actor_resolver;
+
+pub use self::{
+    mailer::*,
+    key_fetcher::*,
+    deliverer::*,
+    actor_resolver::*
+};
\ No newline at end of file
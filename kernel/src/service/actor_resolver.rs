@@ -0,0 +1,9 @@
+use crate::error::KernelError;
+
+/// Looks up remote actors by their `@name@host` handle.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait ActorResolver: Send + Sync + 'static {
+    /// The actor id of `name` on `host`, or `None` if the host does not know the name.
+    async fn resolve_handle(&self, name: &str, host: &str) -> Result<Option<String>, KernelError>;
+}
\ No newline at end of file
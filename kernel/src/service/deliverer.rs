@@ -0,0 +1,11 @@
+use crate::{error::KernelError, entities::{DeliveryStatus, Inboxes, RequestSigner}};
+
+/// Posts activities to the inboxes of remote actors.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait Deliverer: Send + Sync + 'static {
+    /// `None` if `actor` is gone or does not have an inbox.
+    async fn resolve(&self, actor: &str) -> Result<Option<Inboxes>, KernelError>;
+    /// Network failures are reported as [`DeliveryStatus::Failed`] rather than as errors.
+    async fn deliver(&self, inbox: &str, activity: &[u8], signer: &RequestSigner) -> Result<DeliveryStatus, KernelError>;
+}
\ No newline at end of file
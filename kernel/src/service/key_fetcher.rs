@@ -0,0 +1,9 @@
+use crate::{error::KernelError, entities::RemoteKey};
+
+/// Retrieves public keys from the servers that publish them.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait KeyFetcher: Send + Sync + 'static {
+    /// `None` if `key_id` does not resolve to a key owned by an actor on the same host.
+    async fn fetch(&self, key_id: &str) -> Result<Option<RemoteKey>, KernelError>;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::{error::KernelError, entities::Mail};
+
+/// Outgoing mail. Unlike repositories, nothing is read back.
+#[cfg_attr(feature = "mock", mockall::automock)]
+#[async_trait::async_trait]
+pub trait Mailer: Send + Sync + 'static {
+    async fn send(&self, mail: &Mail) -> Result<(), KernelError>;
+}
\ No newline at end of file
@@ -69,41 +69,66 @@ ALTER SEQUENCE auto_increment OWNED BY profiles.id;
 ALTER SEQUENCE auto_increment OWNED BY metadata.id;
 ALTER SEQUENCE auto_increment OWNED BY confidentials.id;
 
+CREATE TABLE note_media (
+  id          UUID    NOT NULL PRIMARY KEY,
+  sensitive   BOOLEAN NOT NULL,
+  description VARCHAR(512),
+  content     VARCHAR(512),
+
+  license_url  VARCHAR(512),
+  license_spdx VARCHAR(128)
+);
+
+CREATE TABLE hashtags (
+  id   UUID         NOT NULL PRIMARY KEY,
+  name VARCHAR(128) NOT NULL UNIQUE,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp()
+);
+
 CREATE TABLE notes (
   id UUID NOT NULL PRIMARY KEY,
-  account BIGSERIAL NOT NULL,
+  account BIGINT NOT NULL,
   created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
 
-  hashtag UUID[] NOT NULL,
-  media   UUID[] NOT NULL,
   content TEXT   NOT NULL,
   cw      VARCHAR(512),
 
-  FOREIGN KEY (account) REFERENCES accounts(id) ON DELETE CASCADE,
-  FOREIGN KEY (EACH ELEMENT OF mention) REFERENCES accounts(id)   ON DELETE CASCADE,
-  FOREIGN KEY (EACH ELEMENT OF media)   REFERENCES note_media(id) ON DELETE CASCADE,
-  FOREIGN KEY (EACH ELEMENT OF hashtag) REFERENCES hashtags(id)   ON DELETE CASCADE
+  FOREIGN KEY (account) REFERENCES accounts(id) ON DELETE CASCADE
 );
 
-CREATE TABLE note_media (
-  id          UUID    NOT NULL PRIMARY KEY,
-  sensitive   BOOLEAN NOT NULL,
-  description VARCHAR(512),
-  content     VARCHAR(512),
+CREATE INDEX notes_account_idx ON notes (account);
 
-  license_url  VARCHAR(512),
-  license_spdx VARCHAR(128)
+CREATE TABLE note_attachments (
+  note     UUID     NOT NULL,
+  media    UUID     NOT NULL,
+  position SMALLINT NOT NULL,
+
+  PRIMARY KEY (note, media),
+  FOREIGN KEY (note)  REFERENCES notes(id)      ON DELETE CASCADE,
+  FOREIGN KEY (media) REFERENCES note_media(id) ON DELETE CASCADE
 );
 
+CREATE TABLE note_hashtags (
+  note    UUID NOT NULL,
+  hashtag UUID NOT NULL,
+
+  PRIMARY KEY (note, hashtag),
+  FOREIGN KEY (note)    REFERENCES notes(id)    ON DELETE CASCADE,
+  FOREIGN KEY (hashtag) REFERENCES hashtags(id) ON DELETE CASCADE
+);
+
+CREATE INDEX note_hashtags_hashtag_idx ON note_hashtags (hashtag);
+
 CREATE TABLE note_reaction (
   id   UUID NOT NULL PRIMARY KEY,
   note UUID NOT NULL,
-  account BIGSERIAL NOT NULL,
+  account BIGINT NOT NULL,
 
   created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
 
-  FOREIGN KEY (note)    REFERENCES note(id)    ON DELETE CASCADE,
-  FOREIGN KEY (account) REFERENCES account(id) ON DELETE CASCADE
+  FOREIGN KEY (note)    REFERENCES notes(id)    ON DELETE CASCADE,
+  FOREIGN KEY (account) REFERENCES accounts(id) ON DELETE CASCADE
 );
 
 CREATE TABLE note_reply (
@@ -126,13 +151,13 @@ CREATE TABLE note_mention (
 
   origin_local UUID,
 
-  target_local UUID,
+  target_local BIGINT,
   target_remote VARCHAR(512),
 
   created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
 
-  FOREIGN KEY (origin_local) REFERENCES notes(id) ON DELETE CASCADE,
-  FOREIGN KEY (target_local) REFERENCES notes(id) ON DELETE CASCADE
+  FOREIGN KEY (origin_local) REFERENCES notes(id)    ON DELETE CASCADE,
+  FOREIGN KEY (target_local) REFERENCES accounts(id) ON DELETE CASCADE
 );
 
 CREATE TABLE note_turbo_quote (
@@ -155,7 +180,7 @@ CREATE TABLE note_turbo_quote (
 CREATE TABLE note_turbo (
   id UUID NOT NULL PRIMARY KEY,
   
-  origin_account BIGSERIAL,
+  origin_local BIGINT,
   origin_remote VARCHAR(512),
 
   target_local UUID,
@@ -165,8 +190,8 @@ CREATE TABLE note_turbo (
 
   implicit boolean NOT NULL,
 
-  FOREIGN KEY (origin_local) REFERENCES account(id) ON DELETE CASCADE,
-  FOREIGN KEY (target_local) REFERENCES notes(id) ON DELETE CASCADE
+  FOREIGN KEY (origin_local) REFERENCES accounts(id) ON DELETE CASCADE,
+  FOREIGN KEY (target_local) REFERENCES notes(id)    ON DELETE CASCADE
 );
 
 CREATE TABLE reaction_asset (
@@ -178,11 +203,3 @@ CREATE TABLE reaction_asset (
   license_url  VARCHAR(512),
   license_spdx VARCHAR(128)
 );
-
-CREATE TABLE hashtags (
-  id   UUID         NOT NULL PRIMARY KEY,
-  name VARCHAR(128) NOT NULL UNIQUE,
-
-  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
-);
-
@@ -0,0 +1,44 @@
+CREATE TABLE oauth_applications (
+  id UUID NOT NULL PRIMARY KEY,
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+  updated_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+
+  name          VARCHAR(128) NOT NULL,
+  website       VARCHAR(512),
+  client_id     VARCHAR(64)  NOT NULL UNIQUE,
+  client_secret VARCHAR(64)  NOT NULL,
+  redirect_uris TEXT[]       NOT NULL,
+  scopes        TEXT[]       NOT NULL
+);
+
+CREATE TABLE oauth_authorization_codes (
+  code VARCHAR(64) NOT NULL PRIMARY KEY,
+  application UUID NOT NULL,
+  account BIGINT NOT NULL,
+
+  redirect_uri TEXT   NOT NULL,
+  scopes       TEXT[] NOT NULL,
+  code_challenge        VARCHAR(128),
+  code_challenge_method VARCHAR(8),
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+  expires_at TIMESTAMPTZ NOT NULL,
+
+  FOREIGN KEY (application) REFERENCES oauth_applications(id) ON DELETE CASCADE,
+  FOREIGN KEY (account)     REFERENCES accounts(id)           ON DELETE CASCADE
+);
+
+CREATE TABLE oauth_tokens (
+  token VARCHAR(64) NOT NULL PRIMARY KEY,
+  application UUID NOT NULL,
+  account BIGINT NOT NULL,
+
+  scopes TEXT[] NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+
+  FOREIGN KEY (application) REFERENCES oauth_applications(id) ON DELETE CASCADE,
+  FOREIGN KEY (account)     REFERENCES accounts(id)           ON DELETE CASCADE
+);
+
+CREATE INDEX oauth_tokens_account_idx ON oauth_tokens (account);
@@ -0,0 +1,24 @@
+ALTER TABLE confidentials ADD COLUMN verified BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE verifications (
+  token VARCHAR(64) NOT NULL PRIMARY KEY,
+  account BIGINT NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+  expires_at TIMESTAMPTZ NOT NULL,
+
+  FOREIGN KEY (account) REFERENCES accounts(id) ON DELETE CASCADE
+);
+
+CREATE TABLE password_resets (
+  token VARCHAR(64) NOT NULL PRIMARY KEY,
+  account BIGINT NOT NULL,
+
+  created_at TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+  expires_at TIMESTAMPTZ NOT NULL,
+
+  FOREIGN KEY (account) REFERENCES accounts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX verifications_account_idx ON verifications (account);
+CREATE INDEX password_resets_account_idx ON password_resets (account);
@@ -0,0 +1,2 @@
+-- Account names are unique regardless of case.
+CREATE UNIQUE INDEX accounts_name_lower_idx ON accounts (LOWER(name));
@@ -0,0 +1,26 @@
+-- Either side of a follow may be a local account or a remote actor.
+ALTER TABLE follows RENAME COLUMN source TO source_local;
+
+ALTER TABLE follows
+  ALTER COLUMN source_local DROP DEFAULT,
+  ALTER COLUMN source_local DROP NOT NULL,
+  ALTER COLUMN destination_local DROP DEFAULT,
+  ALTER COLUMN destination_local DROP NOT NULL,
+  ADD COLUMN source_remote VARCHAR(512),
+  ADD CONSTRAINT follows_source_check
+    CHECK ((source_local IS NULL) <> (source_remote IS NULL)),
+  ADD CONSTRAINT follows_destination_check
+    CHECK ((destination_local IS NULL) <> (destination_remote IS NULL));
+
+DROP SEQUENCE IF EXISTS follows_source_seq;
+DROP SEQUENCE IF EXISTS follows_destination_local_seq;
+
+CREATE UNIQUE INDEX follows_pair_idx ON follows (
+  COALESCE(source_local::TEXT, source_remote),
+  COALESCE(destination_local::TEXT, destination_remote)
+);
+
+CREATE INDEX follows_source_local_idx ON follows (source_local);
+CREATE INDEX follows_destination_local_idx ON follows (destination_local);
+CREATE INDEX follows_source_remote_idx ON follows (source_remote);
+CREATE INDEX follows_destination_remote_idx ON follows (destination_remote);
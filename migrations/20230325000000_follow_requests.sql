@@ -0,0 +1,6 @@
+ALTER TABLE accounts ADD COLUMN locked BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Follows of a locked account stay pending until the target accepts them.
+ALTER TABLE follows ADD COLUMN accepted BOOLEAN NOT NULL DEFAULT TRUE;
+
+CREATE INDEX follows_pending_idx ON follows (destination_local) WHERE NOT accepted;
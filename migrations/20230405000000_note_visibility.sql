@@ -0,0 +1,2 @@
+ALTER TABLE notes ADD COLUMN visibility VARCHAR(16) NOT NULL DEFAULT 'public'
+  CHECK (visibility IN ('public', 'unlisted', 'followers_only', 'direct'));
@@ -0,0 +1,14 @@
+-- A note replies to at most one note.
+CREATE UNIQUE INDEX note_reply_origin_local_idx ON note_reply (origin_local);
+CREATE INDEX note_reply_target_local_idx ON note_reply (target_local);
+
+-- Notes with everything the `Note` aggregate holds.
+CREATE VIEW note_details AS
+  SELECT
+    notes.*,
+    ARRAY(SELECT media FROM note_attachments WHERE note = notes.id ORDER BY position) AS media,
+    ARRAY(SELECT hashtag FROM note_hashtags WHERE note = notes.id) AS hashtags,
+    note_reply.target_local AS reply_local,
+    note_reply.target_remote AS reply_remote
+  FROM notes
+  LEFT JOIN note_reply ON note_reply.origin_local = notes.id;
@@ -0,0 +1,20 @@
+-- Mentions keep the order they appear in the content.
+ALTER TABLE note_mention ADD COLUMN position BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE note_mention ADD CONSTRAINT note_mention_target_check
+  CHECK ((target_local IS NULL) <> (target_remote IS NULL));
+
+CREATE UNIQUE INDEX note_mention_origin_local_idx ON note_mention (origin_local, position);
+CREATE INDEX note_mention_target_local_idx ON note_mention (target_local);
+
+-- Mentions are parallel arrays, with NULL on the side the target is not on.
+CREATE OR REPLACE VIEW note_details AS
+  SELECT
+    notes.*,
+    ARRAY(SELECT media FROM note_attachments WHERE note = notes.id ORDER BY position) AS media,
+    ARRAY(SELECT hashtag FROM note_hashtags WHERE note = notes.id) AS hashtags,
+    note_reply.target_local AS reply_local,
+    note_reply.target_remote AS reply_remote,
+    ARRAY(SELECT target_local FROM note_mention WHERE origin_local = notes.id ORDER BY position) AS mention_local,
+    ARRAY(SELECT target_remote FROM note_mention WHERE origin_local = notes.id ORDER BY position) AS mention_remote
+  FROM notes
+  LEFT JOIN note_reply ON note_reply.origin_local = notes.id;
@@ -0,0 +1,2 @@
+-- Listings page through notes newest first on (created_at, id).
+CREATE INDEX notes_created_at_idx ON notes (created_at DESC, id DESC);
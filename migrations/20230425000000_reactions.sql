@@ -0,0 +1,8 @@
+-- A reaction is either a Unicode emoji or a registered custom emoji.
+ALTER TABLE note_reaction ADD COLUMN emoji VARCHAR(64);
+ALTER TABLE note_reaction ADD COLUMN asset UUID REFERENCES reaction_asset(id) ON DELETE CASCADE;
+ALTER TABLE note_reaction ADD CONSTRAINT note_reaction_content_check
+  CHECK ((emoji IS NULL) <> (asset IS NULL));
+
+-- One reaction per account per note.
+CREATE UNIQUE INDEX note_reaction_note_account_idx ON note_reaction (note, account);
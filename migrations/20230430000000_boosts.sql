@@ -0,0 +1,44 @@
+-- Boosts have a visibility of their own, never wider than the boosted note.
+ALTER TABLE note_turbo
+  ADD COLUMN visibility VARCHAR(16) NOT NULL DEFAULT 'public'
+    CHECK (visibility IN ('public', 'unlisted', 'followers_only', 'direct')),
+  ALTER COLUMN implicit SET DEFAULT FALSE,
+  ADD CONSTRAINT note_turbo_origin_check
+    CHECK ((origin_local IS NULL) <> (origin_remote IS NULL)),
+  ADD CONSTRAINT note_turbo_target_check
+    CHECK ((target_local IS NULL) <> (target_remote IS NULL));
+
+-- An account boosts a note at most once.
+CREATE UNIQUE INDEX note_turbo_pair_idx ON note_turbo (
+  COALESCE(origin_local::TEXT, origin_remote),
+  COALESCE(target_local::TEXT, target_remote)
+);
+
+CREATE INDEX note_turbo_origin_local_idx ON note_turbo (origin_local, created_at DESC, id DESC);
+CREATE INDEX note_turbo_target_local_idx ON note_turbo (target_local);
+
+ALTER TABLE note_turbo_quote
+  ALTER COLUMN implicit SET DEFAULT FALSE,
+  ADD CONSTRAINT note_turbo_quote_target_check
+    CHECK ((target_local IS NULL) <> (target_remote IS NULL));
+
+-- A note quotes at most one note.
+CREATE UNIQUE INDEX note_turbo_quote_origin_local_idx ON note_turbo_quote (origin_local);
+CREATE INDEX note_turbo_quote_target_local_idx ON note_turbo_quote (target_local);
+
+CREATE INDEX notes_account_created_at_idx ON notes (account, created_at DESC, id DESC);
+
+CREATE OR REPLACE VIEW note_details AS
+  SELECT
+    notes.*,
+    ARRAY(SELECT media FROM note_attachments WHERE note = notes.id ORDER BY position) AS media,
+    ARRAY(SELECT hashtag FROM note_hashtags WHERE note = notes.id) AS hashtags,
+    note_reply.target_local AS reply_local,
+    note_reply.target_remote AS reply_remote,
+    ARRAY(SELECT target_local FROM note_mention WHERE origin_local = notes.id ORDER BY position) AS mention_local,
+    ARRAY(SELECT target_remote FROM note_mention WHERE origin_local = notes.id ORDER BY position) AS mention_remote,
+    note_turbo_quote.target_local AS quote_local,
+    note_turbo_quote.target_remote AS quote_remote
+  FROM notes
+  LEFT JOIN note_reply ON note_reply.origin_local = notes.id
+  LEFT JOIN note_turbo_quote ON note_turbo_quote.origin_local = notes.id;
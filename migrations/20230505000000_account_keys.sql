@@ -0,0 +1,9 @@
+-- RSA keypair each local account signs its outgoing activities with.
+CREATE TABLE account_keys (
+  account     BIGINT      NOT NULL PRIMARY KEY,
+  public_key  TEXT        NOT NULL,
+  private_key TEXT        NOT NULL,
+  created_at  TIMESTAMPTZ NOT NULL DEFAULT clock_timestamp(),
+
+  FOREIGN KEY (account) REFERENCES accounts(id) ON DELETE CASCADE
+);
@@ -0,0 +1,38 @@
+-- Remote actors can react to local notes as well.
+ALTER TABLE note_reaction RENAME COLUMN account TO account_local;
+
+ALTER TABLE note_reaction
+  ALTER COLUMN account_local DROP NOT NULL,
+  ADD COLUMN account_remote VARCHAR(512),
+  ADD CONSTRAINT note_reaction_account_check
+    CHECK ((account_local IS NULL) <> (account_remote IS NULL));
+
+DROP INDEX note_reaction_note_account_idx;
+CREATE UNIQUE INDEX note_reaction_note_account_idx ON note_reaction (
+  note,
+  COALESCE(account_local::TEXT, account_remote)
+);
+
+-- Notes received from other servers, keyed by their ActivityPub id.
+CREATE TABLE remote_notes (
+  id           VARCHAR(512) NOT NULL PRIMARY KEY,
+  author       VARCHAR(512) NOT NULL,
+  content      TEXT         NOT NULL,
+  cw           TEXT,
+  visibility   VARCHAR(16)  NOT NULL
+    CHECK (visibility IN ('public', 'unlisted', 'followers_only', 'direct')),
+  reply_local  UUID,
+  reply_remote VARCHAR(512),
+  created_at   TIMESTAMPTZ  NOT NULL,
+
+  FOREIGN KEY (reply_local) REFERENCES notes(id) ON DELETE SET NULL,
+  CHECK (reply_local IS NULL OR reply_remote IS NULL)
+);
+
+CREATE INDEX remote_notes_author_idx ON remote_notes (author);
+
+-- Ids of handled inbox activities, so that redeliveries are not applied twice.
+CREATE TABLE received_activities (
+  id          VARCHAR(512) NOT NULL PRIMARY KEY,
+  received_at TIMESTAMPTZ  NOT NULL DEFAULT clock_timestamp()
+);
@@ -0,0 +1,23 @@
+-- Outgoing activities waiting to be posted, one row per destination host.
+CREATE TABLE deliveries (
+  id              UUID         NOT NULL PRIMARY KEY,
+  sender          BIGINT       NOT NULL,
+  key_id          VARCHAR(512) NOT NULL,
+  host            VARCHAR(255) NOT NULL,
+  recipients      TEXT[]       NOT NULL,
+  activity        TEXT         NOT NULL,
+  attempts        INTEGER      NOT NULL DEFAULT 0,
+  next_attempt_at TIMESTAMPTZ  NOT NULL DEFAULT clock_timestamp(),
+  created_at      TIMESTAMPTZ  NOT NULL DEFAULT clock_timestamp(),
+
+  FOREIGN KEY (sender) REFERENCES accounts(id) ON DELETE CASCADE
+);
+
+CREATE INDEX deliveries_next_attempt_at_idx ON deliveries (next_attempt_at);
+
+-- Consecutive delivery failures per host. Hosts that keep failing are marked dead.
+CREATE TABLE delivery_hosts (
+  host       VARCHAR(255) NOT NULL PRIMARY KEY,
+  failures   INTEGER      NOT NULL DEFAULT 0,
+  dead_since TIMESTAMPTZ
+);
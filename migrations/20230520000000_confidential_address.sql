@@ -0,0 +1,2 @@
+-- An address belongs to one account. The check at signup alone races with concurrent signups.
+CREATE UNIQUE INDEX confidentials_address_idx ON confidentials (address);
@@ -0,0 +1,5 @@
+-- Remote note ids are urls, so listings page over remote notes by a uuid of their own.
+ALTER TABLE remote_notes ADD COLUMN cursor UUID NOT NULL DEFAULT gen_random_uuid();
+
+CREATE UNIQUE INDEX remote_notes_cursor_idx ON remote_notes (cursor);
+CREATE INDEX remote_notes_public_idx ON remote_notes (created_at, cursor) WHERE visibility = 'public';
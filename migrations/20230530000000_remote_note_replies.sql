@@ -0,0 +1,3 @@
+-- Threads read remote replies by the note they answer.
+CREATE INDEX remote_notes_reply_local_idx ON remote_notes (reply_local) WHERE reply_local IS NOT NULL;
+CREATE INDEX remote_notes_reply_remote_idx ON remote_notes (reply_remote) WHERE reply_remote IS NOT NULL;
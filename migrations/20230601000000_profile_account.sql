@@ -0,0 +1,9 @@
+-- An account has one profile. Duplicates created before this keep the most recently updated row.
+DELETE FROM profiles WHERE id IN (
+  SELECT id FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY account ORDER BY updated_at DESC, id) AS position FROM profiles
+  ) ranked
+  WHERE position > 1
+);
+
+CREATE UNIQUE INDEX profiles_account_idx ON profiles (account);
@@ -0,0 +1,78 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header::{ACCEPT, CONTENT_TYPE, VARY}, request::Parts, HeaderValue},
+    response::{IntoResponse, Response},
+    Json
+};
+use application::transfer::ACTIVITY_STREAMS;
+use serde::Serialize;
+
+use crate::ServerError;
+
+pub const ACTIVITY_JSON: &str = "application/activity+json";
+pub const LD_JSON: &str = "application/ld+json; profile=\"https://www.w3.org/ns/activitystreams\"";
+
+/// Negotiates an ActivityStreams representation from the `Accept` header.
+/// A missing header or a wildcard gets `application/activity+json`,
+/// and requests accepting neither representation are rejected with `406 Not Acceptable`.
+pub struct ActivityType(&'static str);
+
+impl ActivityType {
+    /// The preferred of the acceptable representations, or `None` if there is none.
+    fn negotiate(accept: &str) -> Option<&'static str> {
+        let mut best: Option<(&'static str, f32)> = None;
+        for range in accept.split(',') {
+            let mut params = range.split(';').map(str::trim);
+            let media = params.next().unwrap_or_default().to_ascii_lowercase();
+            let mut quality = 1.0;
+            let mut profile = None;
+            for param in params {
+                let Some((key, value)) = param.split_once('=') else {
+                    continue;
+                };
+                let value = value.trim().trim_matches('"');
+                match key.trim().to_ascii_lowercase().as_str() {
+                    "q" => quality = value.parse().unwrap_or(0.0),
+                    "profile" => profile = Some(value),
+                    _ => {}
+                }
+            }
+
+            // JSON-LD with some other profile is a different document.
+            let streams = profile.is_none_or(|profile| profile.split_whitespace().any(|uri| uri == ACTIVITY_STREAMS));
+            let offered = match media.as_str() {
+                "application/activity+json" | "application/*" | "*/*" => ACTIVITY_JSON,
+                "application/ld+json" if streams => LD_JSON,
+                _ => continue
+            };
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((offered, quality));
+            }
+        }
+        best.map(|(offered, _)| offered)
+    }
+
+    pub fn respond(self, body: impl Serialize) -> Response {
+        let mut response = Json(body).into_response();
+        let headers = response.headers_mut();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static(self.0));
+        headers.insert(VARY, HeaderValue::from_static("Accept"));
+        response
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ActivityType
+  where S: Send + Sync
+{
+    type Rejection = ServerError;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        let Some(accept) = parts.headers.get(ACCEPT) else {
+            return Ok(Self(ACTIVITY_JSON));
+        };
+        let accept = accept.to_str().map_err(|_| ServerError::NotAcceptable)?;
+        Self::negotiate(accept).map(Self).ok_or(ServerError::NotAcceptable)
+    }
+}
\ No newline at end of file
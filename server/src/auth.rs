@@ -0,0 +1,91 @@
+use application::{adaptor::AuthenticateAdaptor, ApplicationError};
+use axum::{async_trait, extract::FromRequestParts, http::{header::AUTHORIZATION, request::Parts}};
+use kernel::entities::{AccountId, Scope, Scopes};
+
+use crate::{di::AppHandler, ServerError};
+
+/// Resolves `Authorization: Bearer <token>` to the account that owns the session or OAuth token.
+pub struct Authenticated {
+    account: AccountId,
+    token: String,
+    scopes: Scopes,
+    session: bool
+}
+
+impl Authenticated {
+    pub fn account(&self) -> &AccountId {
+        &self.account
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Rejects tokens whose granted scopes do not cover `scope`.
+    pub fn require(&self, scope: &str) -> Result<(), ServerError> {
+        if !self.scopes.permits(&Scope::new(scope)) {
+            return Err(ApplicationError::Forbidden(format!("`{}` scope is required.", scope)).into());
+        }
+        Ok(())
+    }
+
+    /// Rejects OAuth tokens. Used for operations that only the account owner may perform directly.
+    pub fn require_session(&self) -> Result<(), ServerError> {
+        if !self.session {
+            return Err(ApplicationError::Forbidden("this operation requires a login session.".to_string()).into());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppHandler> for Authenticated {
+    type Rejection = ServerError;
+
+    async fn from_request_parts(parts: &mut Parts, handler: &AppHandler) -> Result<Self, Self::Rejection> {
+        let token = parts.headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or(ApplicationError::Unauthorized)?;
+
+        let credential = handler.authenticate().authenticate(token.to_string()).await?;
+
+        Ok(Self {
+            account: AccountId::new(credential.account),
+            token: credential.token,
+            scopes: Scopes::new(credential.scopes),
+            session: credential.session
+        })
+    }
+}
+
+/// Like [`Authenticated`], but anonymous when the request has no `Authorization` header.
+/// A header with an invalid token is still rejected.
+pub struct Viewer(Option<Authenticated>);
+
+impl Viewer {
+    /// The viewing account, after checking that its token covers `scope`.
+    pub fn account(&self, scope: &str) -> Result<Option<i64>, ServerError> {
+        let Some(auth) = &self.0 else {
+            return Ok(None);
+        };
+        auth.require(scope)?;
+        Ok(Some(*auth.account().as_ref()))
+    }
+}
+
+#[async_trait]
+impl FromRequestParts<AppHandler> for Viewer {
+    type Rejection = ServerError;
+
+    async fn from_request_parts(parts: &mut Parts, handler: &AppHandler) -> Result<Self, Self::Rejection> {
+        if !parts.headers.contains_key(AUTHORIZATION) {
+            return Ok(Self(None));
+        }
+        Authenticated::from_request_parts(parts, handler).await
+            .map(|auth| Self(Some(auth)))
+    }
+}
\ No newline at end of file
@@ -1,18 +1,488 @@
-use application::interactor::{CreateAccountInteractor, DeleteAccountInteractor, CreateProfileInteractor, UpdateProfileInteractor};
+use std::sync::Arc;
+
+use application::{
+    adaptor::{
+        CreateAccountAdaptor, UpdateAccountAdaptor, DeleteAccountAdaptor, GetAccountAdaptor, LoginAdaptor,
+        CreateProfileAdaptor, UpdateProfileAdaptor, GetProfileAdaptor,
+        AuthenticateAdaptor, LogoutAdaptor, RevokeSessionAdaptor,
+        RegisterApplicationAdaptor, VerifyAuthorizationAdaptor, AuthorizeAdaptor,
+        IssueTokenAdaptor, RevokeTokenAdaptor,
+        VerifyAccountAdaptor, ResendVerificationAdaptor, RequestPasswordResetAdaptor, ResetPasswordAdaptor,
+        FollowAccountAdaptor, UnfollowAccountAdaptor, GetFollowersAdaptor, GetFollowingAdaptor,
+        GetFollowRequestsAdaptor, AcceptFollowRequestAdaptor, RejectFollowRequestAdaptor,
+        CreateNoteAdaptor, GetNoteAdaptor, GetNoteContextAdaptor, DeleteNoteAdaptor,
+        GetHashtagNotesAdaptor,
+        ReactToNoteAdaptor, UnreactToNoteAdaptor, GetReactionsAdaptor,
+        RegisterReactionAssetAdaptor, DeleteReactionAssetAdaptor, GetReactionAssetsAdaptor,
+        BoostNoteAdaptor, UnboostNoteAdaptor, GetBoostsAdaptor,
+        GetAccountNotesAdaptor, GetHomeTimelineAdaptor, GetLocalTimelineAdaptor, GetFederatedTimelineAdaptor,
+        GetNotificationsAdaptor,
+        GetActorAdaptor, WebFingerAdaptor, GetNodeInfoAdaptor, VerifySignatureAdaptor, ReceiveActivityAdaptor
+    },
+    interactor::{
+        CreateAccountInteractor, UpdateAccountInteractor, DeleteAccountInteractor, GetAccountInteractor, LoginInteractor,
+        CreateProfileInteractor, UpdateProfileInteractor, GetProfileInteractor,
+        AuthenticateInteractor, LogoutInteractor, RevokeSessionInteractor,
+        RegisterApplicationInteractor, VerifyAuthorizationInteractor, AuthorizeInteractor,
+        IssueTokenInteractor, RevokeTokenInteractor,
+        VerifyAccountInteractor, ResendVerificationInteractor, RequestPasswordResetInteractor, ResetPasswordInteractor,
+        FollowAccountInteractor, UnfollowAccountInteractor, GetFollowersInteractor, GetFollowingInteractor,
+        GetFollowRequestsInteractor, AcceptFollowRequestInteractor, RejectFollowRequestInteractor,
+        CreateNoteInteractor, GetNoteInteractor, GetNoteContextInteractor, DeleteNoteInteractor,
+        GetHashtagNotesInteractor,
+        ReactToNoteInteractor, UnreactToNoteInteractor, GetReactionsInteractor,
+        RegisterReactionAssetInteractor, DeleteReactionAssetInteractor, GetReactionAssetsInteractor,
+        BoostNoteInteractor, UnboostNoteInteractor, GetBoostsInteractor,
+        GetAccountNotesInteractor, GetHomeTimelineInteractor, GetLocalTimelineInteractor, GetFederatedTimelineInteractor,
+        GetNotificationsInteractor,
+        GetActorInteractor, WebFingerInteractor, GetNodeInfoInteractor, VerifySignatureInteractor, ReceiveActivityInteractor
+    }
+};
 use driver::{
-    postgres::DataBaseDriver, 
-    database::{AccountDataBase, ProfileDataBase}
+    postgres::DataBaseDriver,
+    redis::RedisDriver,
+    mail::MailDriver,
+    http::HttpDriver,
+    delivery::DeliveryWorker,
+    database::{
+        AccountDataBase, ProfileDataBase, ConfidentialDataBase, SessionDataBase,
+        ApplicationDataBase, AuthorizationCodeDataBase, OAuthTokenDataBase,
+        VerificationDataBase, PasswordResetDataBase, FollowDataBase, NoteDataBase, HashtagDataBase,
+        ReactionDataBase, ReactionAssetDataBase, BoostDataBase, TimelineDataBase, TimelineCacheDataBase,
+        AccountKeyDataBase, RemoteKeyDataBase, RemoteNoteDataBase, ReceivedActivityDataBase, DeliveryDataBase,
+        DeliveryHostDataBase
+    }
 };
+use kernel::entities::{Administrators, ReservedNames};
+
+pub type AppHandler = Arc<Handler>;
+
+pub struct Handler {
+    account_create: CreateAccountInteractor<AccountDataBase, ConfidentialDataBase, VerificationDataBase, MailDriver>,
+    account_update: UpdateAccountInteractor<AccountDataBase, DeliveryDataBase, FollowDataBase, TimelineCacheDataBase>,
+    account_delete: DeleteAccountInteractor<AccountDataBase>,
+    account_get: GetAccountInteractor<AccountDataBase>,
+    login: LoginInteractor<AccountDataBase, ConfidentialDataBase, SessionDataBase>,
+    profile_create: CreateProfileInteractor<ProfileDataBase>,
+    profile_update: UpdateProfileInteractor<ProfileDataBase>,
+    profile_get: GetProfileInteractor<AccountDataBase, ProfileDataBase>,
+    authenticate: AuthenticateInteractor<SessionDataBase, OAuthTokenDataBase>,
+    logout: LogoutInteractor<SessionDataBase>,
+    revoke_session: RevokeSessionInteractor<SessionDataBase>,
+    application_register: RegisterApplicationInteractor<ApplicationDataBase>,
+    authorization_verify: VerifyAuthorizationInteractor<ApplicationDataBase>,
+    authorize: AuthorizeInteractor<ApplicationDataBase, AuthorizationCodeDataBase>,
+    token_issue: IssueTokenInteractor<ApplicationDataBase, AuthorizationCodeDataBase, OAuthTokenDataBase>,
+    token_revoke: RevokeTokenInteractor<ApplicationDataBase, OAuthTokenDataBase>,
+    account_verify: VerifyAccountInteractor<ConfidentialDataBase, VerificationDataBase>,
+    verification_resend: ResendVerificationInteractor<ConfidentialDataBase, VerificationDataBase, MailDriver>,
+    password_reset_request: RequestPasswordResetInteractor<ConfidentialDataBase, PasswordResetDataBase, MailDriver>,
+    password_reset: ResetPasswordInteractor<ConfidentialDataBase, PasswordResetDataBase, SessionDataBase, OAuthTokenDataBase>,
+    follow: FollowAccountInteractor<AccountDataBase, FollowDataBase, TimelineCacheDataBase>,
+    unfollow: UnfollowAccountInteractor<AccountDataBase, FollowDataBase, TimelineCacheDataBase>,
+    followers_get: GetFollowersInteractor<AccountDataBase, FollowDataBase>,
+    following_get: GetFollowingInteractor<AccountDataBase, FollowDataBase>,
+    follow_requests_get: GetFollowRequestsInteractor<AccountDataBase, FollowDataBase>,
+    follow_request_accept: AcceptFollowRequestInteractor<AccountDataBase, DeliveryDataBase, FollowDataBase, TimelineCacheDataBase>,
+    follow_request_reject: RejectFollowRequestInteractor<AccountDataBase, DeliveryDataBase, FollowDataBase>,
+    note_create: CreateNoteInteractor<AccountDataBase, ConfidentialDataBase, DeliveryDataBase, FollowDataBase, HashtagDataBase, NoteDataBase, TimelineCacheDataBase, HttpDriver>,
+    note_get: GetNoteInteractor<FollowDataBase, NoteDataBase>,
+    note_context: GetNoteContextInteractor<FollowDataBase, NoteDataBase, RemoteNoteDataBase>,
+    note_delete: DeleteNoteInteractor<AccountDataBase, DeliveryDataBase, FollowDataBase, NoteDataBase>,
+    hashtag_notes: GetHashtagNotesInteractor<HashtagDataBase, NoteDataBase>,
+    react: ReactToNoteInteractor<FollowDataBase, NoteDataBase, ReactionDataBase, ReactionAssetDataBase>,
+    unreact: UnreactToNoteInteractor<FollowDataBase, NoteDataBase, ReactionDataBase>,
+    reactions_get: GetReactionsInteractor<FollowDataBase, NoteDataBase, ReactionDataBase, ReactionAssetDataBase>,
+    reaction_asset_register: RegisterReactionAssetInteractor<AccountDataBase, ReactionAssetDataBase>,
+    reaction_asset_delete: DeleteReactionAssetInteractor<AccountDataBase, ReactionAssetDataBase>,
+    reaction_assets_get: GetReactionAssetsInteractor<ReactionAssetDataBase>,
+    note_boost: BoostNoteInteractor<AccountDataBase, BoostDataBase, FollowDataBase, NoteDataBase, TimelineCacheDataBase>,
+    note_unboost: UnboostNoteInteractor<BoostDataBase>,
+    boosts_get: GetBoostsInteractor<AccountDataBase, BoostDataBase, FollowDataBase, NoteDataBase>,
+    account_notes: GetAccountNotesInteractor<AccountDataBase, FollowDataBase, TimelineDataBase>,
+    home_timeline: GetHomeTimelineInteractor<AccountDataBase, FollowDataBase, TimelineDataBase, TimelineCacheDataBase>,
+    local_timeline: GetLocalTimelineInteractor<TimelineDataBase>,
+    federated_timeline: GetFederatedTimelineInteractor<TimelineDataBase>,
+    notifications: GetNotificationsInteractor<TimelineDataBase>,
+    actor_get: GetActorInteractor<AccountDataBase, ProfileDataBase, AccountKeyDataBase>,
+    webfinger: WebFingerInteractor<AccountDataBase>,
+    nodeinfo: GetNodeInfoInteractor<AccountDataBase, NoteDataBase>,
+    signature_verify: VerifySignatureInteractor<RemoteKeyDataBase, HttpDriver>,
+    activity_receive: ReceiveActivityInteractor<AccountDataBase, BoostDataBase, DeliveryDataBase, FollowDataBase, ReceivedActivityDataBase, NoteDataBase, RemoteNoteDataBase, ReactionDataBase>,
+    delivery_worker: DeliveryWorker<DeliveryDataBase, DeliveryHostDataBase, AccountKeyDataBase, HttpDriver>
+}
+
+impl Handler {
+    pub fn account_create(&self) -> &impl CreateAccountAdaptor {
+        &self.account_create
+    }
+
+    pub fn account_update(&self) -> &impl UpdateAccountAdaptor {
+        &self.account_update
+    }
+
+    pub fn account_delete(&self) -> &impl DeleteAccountAdaptor {
+        &self.account_delete
+    }
+
+    pub fn account_get(&self) -> &impl GetAccountAdaptor {
+        &self.account_get
+    }
+
+    pub fn login(&self) -> &impl LoginAdaptor {
+        &self.login
+    }
+
+    pub fn profile_create(&self) -> &impl CreateProfileAdaptor {
+        &self.profile_create
+    }
+
+    pub fn profile_update(&self) -> &impl UpdateProfileAdaptor {
+        &self.profile_update
+    }
+
+    pub fn profile_get(&self) -> &impl GetProfileAdaptor {
+        &self.profile_get
+    }
+
+    pub fn authenticate(&self) -> &impl AuthenticateAdaptor {
+        &self.authenticate
+    }
+
+    pub fn logout(&self) -> &impl LogoutAdaptor {
+        &self.logout
+    }
+
+    pub fn revoke_session(&self) -> &impl RevokeSessionAdaptor {
+        &self.revoke_session
+    }
+
+    pub fn application_register(&self) -> &impl RegisterApplicationAdaptor {
+        &self.application_register
+    }
+
+    pub fn authorization_verify(&self) -> &impl VerifyAuthorizationAdaptor {
+        &self.authorization_verify
+    }
+
+    pub fn authorize(&self) -> &impl AuthorizeAdaptor {
+        &self.authorize
+    }
+
+    pub fn token_issue(&self) -> &impl IssueTokenAdaptor {
+        &self.token_issue
+    }
+
+    pub fn token_revoke(&self) -> &impl RevokeTokenAdaptor {
+        &self.token_revoke
+    }
+
+    pub fn account_verify(&self) -> &impl VerifyAccountAdaptor {
+        &self.account_verify
+    }
+
+    pub fn verification_resend(&self) -> &impl ResendVerificationAdaptor {
+        &self.verification_resend
+    }
+
+    pub fn password_reset_request(&self) -> &impl RequestPasswordResetAdaptor {
+        &self.password_reset_request
+    }
+
+    pub fn password_reset(&self) -> &impl ResetPasswordAdaptor {
+        &self.password_reset
+    }
+
+    pub fn follow(&self) -> &impl FollowAccountAdaptor {
+        &self.follow
+    }
+
+    pub fn unfollow(&self) -> &impl UnfollowAccountAdaptor {
+        &self.unfollow
+    }
+
+    pub fn followers_get(&self) -> &impl GetFollowersAdaptor {
+        &self.followers_get
+    }
+
+    pub fn following_get(&self) -> &impl GetFollowingAdaptor {
+        &self.following_get
+    }
+
+    pub fn follow_requests_get(&self) -> &impl GetFollowRequestsAdaptor {
+        &self.follow_requests_get
+    }
+
+    pub fn follow_request_accept(&self) -> &impl AcceptFollowRequestAdaptor {
+        &self.follow_request_accept
+    }
+
+    pub fn follow_request_reject(&self) -> &impl RejectFollowRequestAdaptor {
+        &self.follow_request_reject
+    }
+
+    pub fn note_create(&self) -> &impl CreateNoteAdaptor {
+        &self.note_create
+    }
+
+    pub fn note_get(&self) -> &impl GetNoteAdaptor {
+        &self.note_get
+    }
+
+    pub fn note_context(&self) -> &impl GetNoteContextAdaptor {
+        &self.note_context
+    }
+
+    pub fn note_delete(&self) -> &impl DeleteNoteAdaptor {
+        &self.note_delete
+    }
+
+    pub fn hashtag_notes(&self) -> &impl GetHashtagNotesAdaptor {
+        &self.hashtag_notes
+    }
 
-pub async fn inject() -> anyhow::Result<()> {
+    pub fn react(&self) -> &impl ReactToNoteAdaptor {
+        &self.react
+    }
+
+    pub fn unreact(&self) -> &impl UnreactToNoteAdaptor {
+        &self.unreact
+    }
+
+    pub fn reactions_get(&self) -> &impl GetReactionsAdaptor {
+        &self.reactions_get
+    }
+
+    pub fn reaction_asset_register(&self) -> &impl RegisterReactionAssetAdaptor {
+        &self.reaction_asset_register
+    }
+
+    pub fn reaction_asset_delete(&self) -> &impl DeleteReactionAssetAdaptor {
+        &self.reaction_asset_delete
+    }
+
+    pub fn reaction_assets_get(&self) -> &impl GetReactionAssetsAdaptor {
+        &self.reaction_assets_get
+    }
+
+    pub fn note_boost(&self) -> &impl BoostNoteAdaptor {
+        &self.note_boost
+    }
+
+    pub fn note_unboost(&self) -> &impl UnboostNoteAdaptor {
+        &self.note_unboost
+    }
+
+    pub fn boosts_get(&self) -> &impl GetBoostsAdaptor {
+        &self.boosts_get
+    }
+
+    pub fn account_notes(&self) -> &impl GetAccountNotesAdaptor {
+        &self.account_notes
+    }
+
+    pub fn home_timeline(&self) -> &impl GetHomeTimelineAdaptor {
+        &self.home_timeline
+    }
+
+    pub fn local_timeline(&self) -> &impl GetLocalTimelineAdaptor {
+        &self.local_timeline
+    }
+
+    pub fn federated_timeline(&self) -> &impl GetFederatedTimelineAdaptor {
+        &self.federated_timeline
+    }
+
+    pub fn notifications(&self) -> &impl GetNotificationsAdaptor {
+        &self.notifications
+    }
+
+    pub fn actor_get(&self) -> &impl GetActorAdaptor {
+        &self.actor_get
+    }
+
+    pub fn webfinger(&self) -> &impl WebFingerAdaptor {
+        &self.webfinger
+    }
+
+    pub fn nodeinfo(&self) -> &impl GetNodeInfoAdaptor {
+        &self.nodeinfo
+    }
+
+    pub fn signature_verify(&self) -> &impl VerifySignatureAdaptor {
+        &self.signature_verify
+    }
+
+    pub fn activity_receive(&self) -> &impl ReceiveActivityAdaptor {
+        &self.activity_receive
+    }
+
+    pub fn delivery_worker(&self) -> &DeliveryWorker<DeliveryDataBase, DeliveryHostDataBase, AccountKeyDataBase, HttpDriver> {
+        &self.delivery_worker
+    }
+}
+
+/// Built-in reserved account names plus the comma separated `RESERVED_ACCOUNT_NAMES`.
+fn reserved_names() -> ReservedNames {
+    let configured = std::env::var("RESERVED_ACCOUNT_NAMES").unwrap_or_default();
+    ReservedNames::default().with(configured.split(',').map(str::trim).filter(|name| !name.is_empty()))
+}
+
+/// Accounts named in the comma separated `ADMIN_ACCOUNTS`.
+fn administrators() -> Administrators {
+    let configured = std::env::var("ADMIN_ACCOUNTS").unwrap_or_default();
+    Administrators::new(configured.split(',').map(str::trim).filter(|name| !name.is_empty()))
+}
+
+/// Domain this server is reachable at, from `SERVER_HOST`.
+fn server_host() -> String {
+    std::env::var("SERVER_HOST").unwrap_or_else(|_| "localhost".to_string())
+}
+
+pub async fn inject() -> anyhow::Result<AppHandler> {
     let pool = DataBaseDriver::setup().await?;
+    let redis = RedisDriver::setup().await?;
+    let mailer = MailDriver::setup()?;
+    let http = HttpDriver::setup()?;
     let account_repository = AccountDataBase::new(pool.clone());
-    let profile_repository = ProfileDataBase::new(pool);
-
-    let _account_create = CreateAccountInteractor::new(account_repository.clone());
-    let _account_delete = DeleteAccountInteractor::new(account_repository);
-    
-    let _profile_create = CreateProfileInteractor::new(profile_repository.clone());
-    let _profile_update = UpdateProfileInteractor::new(profile_repository);
-    Ok(())
-}
\ No newline at end of file
+    let profile_repository = ProfileDataBase::new(pool.clone());
+    let confidential_repository = ConfidentialDataBase::new(pool.clone());
+    let application_repository = ApplicationDataBase::new(pool.clone());
+    let authorization_code_repository = AuthorizationCodeDataBase::new(pool.clone());
+    let oauth_token_repository = OAuthTokenDataBase::new(pool.clone());
+    let verification_repository = VerificationDataBase::new(pool.clone());
+    let password_reset_repository = PasswordResetDataBase::new(pool.clone());
+    let follow_repository = FollowDataBase::new(pool.clone());
+    let hashtag_repository = HashtagDataBase::new(pool.clone());
+    let reaction_repository = ReactionDataBase::new(pool.clone());
+    let reaction_asset_repository = ReactionAssetDataBase::new(pool.clone());
+    let boost_repository = BoostDataBase::new(pool.clone());
+    let timeline_repository = TimelineDataBase::new(pool.clone());
+    let account_key_repository = AccountKeyDataBase::new(pool.clone());
+    let remote_note_repository = RemoteNoteDataBase::new(pool.clone());
+    let received_activity_repository = ReceivedActivityDataBase::new(pool.clone());
+    let delivery_repository = DeliveryDataBase::new(pool.clone());
+    let delivery_host_repository = DeliveryHostDataBase::new(pool.clone());
+    let note_repository = NoteDataBase::new(pool);
+    let timeline_cache = TimelineCacheDataBase::new(redis.clone());
+    let remote_key_repository = RemoteKeyDataBase::new(redis.clone());
+    let session_repository = SessionDataBase::new(redis);
+
+    let account_create = CreateAccountInteractor::new(account_repository.clone(), confidential_repository.clone(), verification_repository.clone(), mailer.clone(), reserved_names());
+    let account_update = UpdateAccountInteractor::new(account_repository.clone(), delivery_repository.clone(), follow_repository.clone(), timeline_cache.clone(), server_host());
+    let account_delete = DeleteAccountInteractor::new(account_repository.clone());
+    let account_get = GetAccountInteractor::new(account_repository.clone());
+    let login = LoginInteractor::new(account_repository.clone(), confidential_repository.clone(), session_repository.clone());
+
+    let account_verify = VerifyAccountInteractor::new(confidential_repository.clone(), verification_repository.clone());
+    let verification_resend = ResendVerificationInteractor::new(confidential_repository.clone(), verification_repository, mailer.clone());
+    let password_reset_request = RequestPasswordResetInteractor::new(confidential_repository.clone(), password_reset_repository.clone(), mailer);
+    let password_reset = ResetPasswordInteractor::new(confidential_repository.clone(), password_reset_repository, session_repository.clone(), oauth_token_repository.clone());
+
+    let profile_create = CreateProfileInteractor::new(profile_repository.clone());
+    let profile_update = UpdateProfileInteractor::new(profile_repository.clone());
+    let profile_get = GetProfileInteractor::new(account_repository.clone(), profile_repository.clone());
+    let actor_get = GetActorInteractor::new(account_repository.clone(), profile_repository, account_key_repository.clone(), server_host());
+    let webfinger = WebFingerInteractor::new(account_repository.clone(), server_host());
+
+    let follow = FollowAccountInteractor::new(account_repository.clone(), follow_repository.clone(), timeline_cache.clone());
+    let unfollow = UnfollowAccountInteractor::new(account_repository.clone(), follow_repository.clone(), timeline_cache.clone());
+    let followers_get = GetFollowersInteractor::new(account_repository.clone(), follow_repository.clone());
+    let following_get = GetFollowingInteractor::new(account_repository.clone(), follow_repository.clone());
+    let follow_requests_get = GetFollowRequestsInteractor::new(account_repository.clone(), follow_repository.clone());
+    let follow_request_accept = AcceptFollowRequestInteractor::new(account_repository.clone(), delivery_repository.clone(), follow_repository.clone(), timeline_cache.clone(), server_host());
+    let follow_request_reject = RejectFollowRequestInteractor::new(account_repository.clone(), delivery_repository.clone(), follow_repository.clone(), server_host());
+
+    let note_create = CreateNoteInteractor::new(account_repository.clone(), confidential_repository, delivery_repository.clone(), follow_repository.clone(), hashtag_repository.clone(), note_repository.clone(), timeline_cache.clone(), http.clone(), server_host());
+    let note_get = GetNoteInteractor::new(follow_repository.clone(), note_repository.clone());
+    let note_context = GetNoteContextInteractor::new(follow_repository.clone(), note_repository.clone(), remote_note_repository.clone());
+    let note_delete = DeleteNoteInteractor::new(account_repository.clone(), delivery_repository.clone(), follow_repository.clone(), note_repository.clone(), server_host());
+
+    let hashtag_notes = GetHashtagNotesInteractor::new(hashtag_repository, note_repository.clone());
+
+    let react = ReactToNoteInteractor::new(follow_repository.clone(), note_repository.clone(), reaction_repository.clone(), reaction_asset_repository.clone());
+    let unreact = UnreactToNoteInteractor::new(follow_repository.clone(), note_repository.clone(), reaction_repository.clone());
+    let reactions_get = GetReactionsInteractor::new(follow_repository.clone(), note_repository.clone(), reaction_repository.clone(), reaction_asset_repository.clone());
+    let reaction_asset_register = RegisterReactionAssetInteractor::new(account_repository.clone(), reaction_asset_repository.clone(), administrators());
+    let reaction_asset_delete = DeleteReactionAssetInteractor::new(account_repository.clone(), reaction_asset_repository.clone(), administrators());
+    let reaction_assets_get = GetReactionAssetsInteractor::new(reaction_asset_repository);
+
+    let note_boost = BoostNoteInteractor::new(account_repository.clone(), boost_repository.clone(), follow_repository.clone(), note_repository.clone(), timeline_cache.clone());
+    let note_unboost = UnboostNoteInteractor::new(boost_repository.clone());
+    let boosts_get = GetBoostsInteractor::new(account_repository.clone(), boost_repository.clone(), follow_repository.clone(), note_repository.clone());
+    let account_notes = GetAccountNotesInteractor::new(account_repository.clone(), follow_repository.clone(), timeline_repository.clone());
+    let home_timeline = GetHomeTimelineInteractor::new(account_repository.clone(), follow_repository.clone(), timeline_repository.clone(), timeline_cache);
+    let local_timeline = GetLocalTimelineInteractor::new(timeline_repository.clone());
+    let federated_timeline = GetFederatedTimelineInteractor::new(timeline_repository.clone());
+    let notifications = GetNotificationsInteractor::new(timeline_repository);
+    let nodeinfo = GetNodeInfoInteractor::new(account_repository.clone(), note_repository.clone(), server_host());
+
+    let signature_verify = VerifySignatureInteractor::new(remote_key_repository, http.clone());
+    let activity_receive = ReceiveActivityInteractor::new(account_repository, boost_repository, delivery_repository.clone(), follow_repository, received_activity_repository, note_repository, remote_note_repository, reaction_repository, server_host());
+    let delivery_worker = DeliveryWorker::new(delivery_repository, delivery_host_repository, account_key_repository, http);
+
+    let authenticate = AuthenticateInteractor::new(session_repository.clone(), oauth_token_repository.clone());
+    let logout = LogoutInteractor::new(session_repository.clone());
+    let revoke_session = RevokeSessionInteractor::new(session_repository);
+
+    let application_register = RegisterApplicationInteractor::new(application_repository.clone());
+    let authorization_verify = VerifyAuthorizationInteractor::new(application_repository.clone());
+    let authorize = AuthorizeInteractor::new(application_repository.clone(), authorization_code_repository.clone());
+    let token_issue = IssueTokenInteractor::new(application_repository.clone(), authorization_code_repository, oauth_token_repository.clone());
+    let token_revoke = RevokeTokenInteractor::new(application_repository, oauth_token_repository);
+
+    Ok(Arc::new(Handler {
+        account_create,
+        account_update,
+        account_delete,
+        account_get,
+        login,
+        profile_create,
+        profile_update,
+        profile_get,
+        authenticate,
+        logout,
+        revoke_session,
+        application_register,
+        authorization_verify,
+        authorize,
+        token_issue,
+        token_revoke,
+        account_verify,
+        verification_resend,
+        password_reset_request,
+        password_reset,
+        follow,
+        unfollow,
+        followers_get,
+        following_get,
+        follow_requests_get,
+        follow_request_accept,
+        follow_request_reject,
+        note_create,
+        note_get,
+        note_context,
+        note_delete,
+        hashtag_notes,
+        react,
+        unreact,
+        reactions_get,
+        reaction_asset_register,
+        reaction_asset_delete,
+        reaction_assets_get,
+        note_boost,
+        note_unboost,
+        boosts_get,
+        account_notes,
+        home_timeline,
+        local_timeline,
+        federated_timeline,
+        notifications,
+        actor_get,
+        webfinger,
+        nodeinfo,
+        signature_verify,
+        activity_receive,
+        delivery_worker
+    }))
+}
@@ -0,0 +1,50 @@
+use application::ApplicationError;
+use axum::{response::{IntoResponse, Response}, http::{StatusCode, header::WWW_AUTHENTICATE}, Json};
+use serde_json::json;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ServerError {
+    #[error(transparent)]
+    Application(#[from] ApplicationError),
+    #[error("no acceptable representation is available.")]
+    NotAcceptable
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            ServerError::Application(e) => match e {
+                ApplicationError::NotFound { .. } => StatusCode::NOT_FOUND,
+                ApplicationError::Conflict { .. } => StatusCode::CONFLICT,
+                ApplicationError::Convert(_) => StatusCode::BAD_REQUEST,
+                ApplicationError::InvalidField { .. } => StatusCode::BAD_REQUEST,
+                ApplicationError::Unauthorized => StatusCode::UNAUTHORIZED,
+                ApplicationError::Forbidden(_) => StatusCode::FORBIDDEN,
+                ApplicationError::OAuth { error: "invalid_client", .. } => StatusCode::UNAUTHORIZED,
+                ApplicationError::OAuth { .. } => StatusCode::BAD_REQUEST,
+                ApplicationError::External(_) => StatusCode::INTERNAL_SERVER_ERROR
+            },
+            ServerError::NotAcceptable => StatusCode::NOT_ACCEPTABLE
+        };
+
+        if status.is_server_error() {
+            tracing::error!("{:?}", self);
+            return (status, Json(json!({ "error": "internal server error." }))).into_response();
+        }
+
+        if let ServerError::Application(ApplicationError::InvalidField { field, .. }) = &self {
+            return (status, Json(json!({ "error": self.to_string(), "field": field }))).into_response();
+        }
+
+        // RFC 6749 5.2 error response.
+        if let ServerError::Application(ApplicationError::OAuth { error, description }) = &self {
+            return (status, Json(json!({ "error": error, "error_description": description }))).into_response();
+        }
+
+        if status == StatusCode::UNAUTHORIZED {
+            return (status, [(WWW_AUTHENTICATE, "Bearer")], Json(json!({ "error": self.to_string() }))).into_response();
+        }
+
+        (status, Json(json!({ "error": self.to_string() }))).into_response()
+    }
+}
@@ -1,2 +1,9 @@
 pub mod di;
+pub mod auth;
+pub mod activitypub;
+pub mod signature;
 pub mod routes;
+pub mod worker;
+mod error;
+
+pub use self::error::*;
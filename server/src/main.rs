@@ -19,11 +19,15 @@ async fn main() -> anyhow::Result<()> {
             .with_filter(tracing_subscriber::filter::LevelFilter::DEBUG))
         .init();
 
-    di::inject().await?;
+    let handler = di::inject().await?;
+
+    server::worker::spawn_delivery_workers(&handler);
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
     let routes = Router::new()
-        .nest("/v0/", server::routes::v0())
+        .nest("/v0/", server::routes::v0(handler.clone()))
+        .nest("/oauth/", server::routes::oauth(handler.clone()))
+        .merge(server::routes::activitypub(handler))
         .into_make_service();
 
     #[allow(clippy::let_unit_value)]
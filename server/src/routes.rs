@@ -1,11 +1,63 @@
 use axum::Router;
 
+use crate::di::AppHandler;
+
 mod account;
+mod accounts;
+mod actors;
+mod admin;
+mod apps;
+mod follow_requests;
+mod inbox;
+mod nodeinfo;
+mod notes;
+mod notifications;
+mod oauth;
+mod profile;
+mod reaction_assets;
+mod tags;
+mod timelines;
+mod well_known;
 
-use self::account::users;
+use self::{
+    account::users, accounts::accounts, actors::actors, admin::admin, apps::apps, follow_requests::follow_requests, inbox::inbox, nodeinfo::nodeinfo, notes::notes,
+    notifications::notifications, profile::profile, reaction_assets::reaction_assets, tags::tags, timelines::timelines,
+    well_known::well_known
+};
 
 // http://api.shuttle.pub/v0/account
-pub fn v0() -> Router {
+pub fn v0(handler: AppHandler) -> Router {
     Router::new()
+        .nest("/account/profile", profile())
+        .nest("/account/follow_requests", follow_requests())
         .nest("/account", users())
+        .nest("/accounts", accounts())
+        .nest("/admin", admin())
+        .nest("/apps", apps())
+        .nest("/notes", notes())
+        .nest("/notifications", notifications())
+        .nest("/reaction_assets", reaction_assets())
+        .nest("/tags", tags())
+        .nest("/timelines", timelines())
+        .with_state(handler)
+}
+
+// http://api.shuttle.pub/oauth/token
+pub fn oauth(handler: AppHandler) -> Router {
+    Router::new()
+        .merge(self::oauth::oauth())
+        .with_state(handler)
+}
+
+// http://shuttle.pub/users/{name}
+// http://shuttle.pub/users/{name}/inbox
+// http://shuttle.pub/.well-known/webfinger
+pub fn activitypub(handler: AppHandler) -> Router {
+    // Actors and inboxes share the `/users` prefix, so both are merged instead of nested.
+    Router::new()
+        .merge(actors())
+        .merge(inbox(handler.clone()))
+        .nest("/.well-known", well_known())
+        .nest("/nodeinfo", nodeinfo())
+        .with_state(handler)
 }
\ No newline at end of file
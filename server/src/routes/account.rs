@@ -1,15 +1,111 @@
-use axum::{Router, response::IntoResponse, routing::post};
+use application::{
+    adaptor::{
+        CreateAccountAdaptor, UpdateAccountAdaptor, DeleteAccountAdaptor, LoginAdaptor, LogoutAdaptor, RevokeSessionAdaptor,
+        VerifyAccountAdaptor, ResendVerificationAdaptor, RequestPasswordResetAdaptor, ResetPasswordAdaptor
+    },
+    transfer::{CreateAccountDto, UpdateAccountDto, LoginDto, VerifyAccountDto, RequestPasswordResetDto, ResetPasswordDto}
+};
+use axum::{Router, Json, extract::State, http::StatusCode, response::IntoResponse, routing::{post, patch, delete}};
 
-pub fn users() -> Router {
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+pub fn users() -> Router<AppHandler> {
     Router::new()
+        .route("/", patch(update).delete(withdraw))
         .route("/signup", post(signup))
         .route("/login", post(login))
+        .route("/logout", post(logout))
+        .route("/sessions", delete(revoke))
+        .route("/verify", post(verify))
+        .route("/verify/resend", post(resend))
+        .route("/password/forgot", post(forgot))
+        .route("/password/reset", post(reset))
+}
+
+async fn signup(
+    State(handler): State<AppHandler>,
+    Json(account): Json<CreateAccountDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let created = handler.account_create().create(account).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+async fn login(
+    State(handler): State<AppHandler>,
+    Json(login): Json<LoginDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let session = handler.login().login(login).await?;
+    Ok(Json(session))
+}
+
+async fn logout(
+    State(handler): State<AppHandler>,
+    auth: Authenticated
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    handler.logout().logout(auth.token().to_string()).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn revoke(
+    State(handler): State<AppHandler>,
+    auth: Authenticated
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    handler.revoke_session().revoke_all(*auth.account().as_ref()).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn update(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Json(account): Json<UpdateAccountDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:accounts")?;
+    let updated = handler.account_update().update(*auth.account().as_ref(), account).await?;
+    Ok(Json(updated))
+}
+
+async fn withdraw(
+    State(handler): State<AppHandler>,
+    auth: Authenticated
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    let account = *auth.account().as_ref();
+    handler.revoke_session().revoke_all(account).await?;
+    handler.account_delete().delete(account).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn verify(
+    State(handler): State<AppHandler>,
+    Json(verify): Json<VerifyAccountDto>
+) -> Result<impl IntoResponse, ServerError> {
+    handler.account_verify().verify(verify).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn resend(
+    State(handler): State<AppHandler>,
+    auth: Authenticated
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    handler.verification_resend().resend(*auth.account().as_ref()).await?;
+    Ok(StatusCode::ACCEPTED)
 }
 
-async fn signup() -> impl IntoResponse {
-    todo!()
+async fn forgot(
+    State(handler): State<AppHandler>,
+    Json(request): Json<RequestPasswordResetDto>
+) -> Result<impl IntoResponse, ServerError> {
+    handler.password_reset_request().request(request).await?;
+    Ok(StatusCode::ACCEPTED)
 }
 
-async fn login() -> impl IntoResponse {
-    todo!()
+async fn reset(
+    State(handler): State<AppHandler>,
+    Json(reset): Json<ResetPasswordDto>
+) -> Result<impl IntoResponse, ServerError> {
+    handler.password_reset().reset(reset).await?;
+    Ok(StatusCode::NO_CONTENT)
 }
\ No newline at end of file
@@ -0,0 +1,84 @@
+use application::{
+    adaptor::{
+        GetAccountAdaptor, GetProfileAdaptor,
+        FollowAccountAdaptor, UnfollowAccountAdaptor, GetFollowersAdaptor, GetFollowingAdaptor,
+        GetAccountNotesAdaptor
+    },
+    transfer::PaginationDto
+};
+use axum::{Router, Json, extract::{State, Path, Query}, http::StatusCode, response::IntoResponse, routing::{get, post}};
+
+use crate::{auth::{Authenticated, Viewer}, di::AppHandler, ServerError};
+
+pub fn accounts() -> Router<AppHandler> {
+    Router::new()
+        .route("/:name", get(account))
+        .route("/:name/profile", get(profile))
+        .route("/:name/follow", post(follow))
+        .route("/:name/unfollow", post(unfollow))
+        .route("/:name/followers", get(followers))
+        .route("/:name/following", get(following))
+        .route("/:name/notes", get(notes))
+}
+
+async fn account(
+    State(handler): State<AppHandler>,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    let account = handler.account_get().get_by_name(name).await?;
+    Ok(Json(account))
+}
+
+async fn profile(
+    State(handler): State<AppHandler>,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    let profile = handler.profile_get().get_by_name(name).await?;
+    Ok(Json(profile))
+}
+
+async fn follow(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:follows")?;
+    let relationship = handler.follow().follow(*auth.account().as_ref(), name).await?;
+    Ok(Json(relationship))
+}
+
+async fn unfollow(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:follows")?;
+    handler.unfollow().unfollow(*auth.account().as_ref(), name).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn followers(
+    State(handler): State<AppHandler>,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    let followers = handler.followers_get().followers(name).await?;
+    Ok(Json(followers))
+}
+
+async fn following(
+    State(handler): State<AppHandler>,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    let following = handler.following_get().following(name).await?;
+    Ok(Json(following))
+}
+
+async fn notes(
+    State(handler): State<AppHandler>,
+    viewer: Viewer,
+    Path(name): Path<String>,
+    Query(page): Query<PaginationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let notes = handler.account_notes().notes(viewer.account("read:statuses")?, name, page).await?;
+    Ok(Json(notes))
+}
\ No newline at end of file
@@ -0,0 +1,18 @@
+use application::adaptor::GetActorAdaptor;
+use axum::{Router, extract::{State, Path}, response::IntoResponse, routing::get};
+
+use crate::{activitypub::ActivityType, di::AppHandler, ServerError};
+
+pub fn actors() -> Router<AppHandler> {
+    Router::new()
+        .route("/users/:name", get(actor))
+}
+
+async fn actor(
+    State(handler): State<AppHandler>,
+    accept: ActivityType,
+    Path(name): Path<String>
+) -> Result<impl IntoResponse, ServerError> {
+    let actor = handler.actor_get().actor(name).await?;
+    Ok(accept.respond(actor))
+}
\ No newline at end of file
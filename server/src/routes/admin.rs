@@ -0,0 +1,35 @@
+use application::{
+    adaptor::{RegisterReactionAssetAdaptor, DeleteReactionAssetAdaptor},
+    transfer::CreateReactionAssetDto
+};
+use axum::{Router, Json, extract::{State, Path}, http::StatusCode, response::IntoResponse, routing::{post, delete}};
+use uuid::Uuid;
+
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+/// Instance management. Only first-party sessions of administrators are accepted.
+pub fn admin() -> Router<AppHandler> {
+    Router::new()
+        .route("/reaction_assets", post(register_asset))
+        .route("/reaction_assets/:id", delete(delete_asset))
+}
+
+async fn register_asset(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Json(asset): Json<CreateReactionAssetDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    let registered = handler.reaction_asset_register().register(*auth.account().as_ref(), asset).await?;
+    Ok((StatusCode::CREATED, Json(registered)))
+}
+
+async fn delete_asset(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    handler.reaction_asset_delete().delete(*auth.account().as_ref(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use application::{adaptor::RegisterApplicationAdaptor, transfer::RegisterApplicationDto};
+use axum::{Router, Json, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+
+use crate::{di::AppHandler, ServerError};
+
+pub fn apps() -> Router<AppHandler> {
+    Router::new()
+        .route("/", post(register))
+}
+
+async fn register(
+    State(handler): State<AppHandler>,
+    Json(app): Json<RegisterApplicationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let registered = handler.application_register().register(app).await?;
+    Ok((StatusCode::CREATED, Json(registered)))
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use application::adaptor::{GetFollowRequestsAdaptor, AcceptFollowRequestAdaptor, RejectFollowRequestAdaptor};
+use axum::{Router, Json, extract::{State, Path}, http::StatusCode, response::IntoResponse, routing::{get, post}};
+use uuid::Uuid;
+
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+pub fn follow_requests() -> Router<AppHandler> {
+    Router::new()
+        .route("/", get(requests))
+        .route("/:id/accept", post(accept))
+        .route("/:id/reject", post(reject))
+}
+
+async fn requests(
+    State(handler): State<AppHandler>,
+    auth: Authenticated
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("read:follows")?;
+    let requests = handler.follow_requests_get().requests(*auth.account().as_ref()).await?;
+    Ok(Json(requests))
+}
+
+async fn accept(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:follows")?;
+    handler.follow_request_accept().accept(*auth.account().as_ref(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn reject(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:follows")?;
+    handler.follow_request_reject().reject(*auth.account().as_ref(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use application::{adaptor::ReceiveActivityAdaptor, transfer::{ActivityDto, SignerDto}};
+use axum::{Extension, Json, Router, extract::{State, Path}, http::StatusCode, middleware, routing::post};
+
+use crate::{di::AppHandler, signature::verify_signature, ServerError};
+
+/// Deliveries are only accepted with a valid HTTP Signature.
+pub fn inbox(handler: AppHandler) -> Router<AppHandler> {
+    Router::new()
+        .route("/inbox", post(shared_inbox))
+        .route("/users/:name/inbox", post(user_inbox))
+        .route_layer(middleware::from_fn_with_state(handler, verify_signature))
+}
+
+async fn shared_inbox(
+    State(handler): State<AppHandler>,
+    Extension(signer): Extension<SignerDto>,
+    Json(activity): Json<ActivityDto>
+) -> Result<StatusCode, ServerError> {
+    handler.activity_receive().receive(signer, None, activity).await?;
+    Ok(StatusCode::ACCEPTED)
+}
+
+async fn user_inbox(
+    State(handler): State<AppHandler>,
+    Extension(signer): Extension<SignerDto>,
+    Path(name): Path<String>,
+    Json(activity): Json<ActivityDto>
+) -> Result<StatusCode, ServerError> {
+    handler.activity_receive().receive(signer, Some(name), activity).await?;
+    Ok(StatusCode::ACCEPTED)
+}
\ No newline at end of file
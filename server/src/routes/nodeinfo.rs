@@ -0,0 +1,19 @@
+use application::adaptor::GetNodeInfoAdaptor;
+use axum::{Router, Json, extract::State, http::header::CONTENT_TYPE, response::IntoResponse, routing::get};
+
+use crate::{di::AppHandler, ServerError};
+
+pub fn nodeinfo() -> Router<AppHandler> {
+    Router::new()
+        .route("/2.1", get(nodeinfo_2_1))
+}
+
+async fn nodeinfo_2_1(
+    State(handler): State<AppHandler>
+) -> Result<impl IntoResponse, ServerError> {
+    let nodeinfo = handler.nodeinfo().nodeinfo().await?;
+    Ok((
+        [(CONTENT_TYPE, r#"application/json; profile="http://nodeinfo.diaspora.software/ns/schema/2.1#""#)],
+        Json(nodeinfo)
+    ))
+}
\ No newline at end of file
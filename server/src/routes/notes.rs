@@ -0,0 +1,122 @@
+use application::{
+    adaptor::{
+        CreateNoteAdaptor, GetNoteAdaptor, GetNoteContextAdaptor, DeleteNoteAdaptor,
+        ReactToNoteAdaptor, UnreactToNoteAdaptor, GetReactionsAdaptor,
+        BoostNoteAdaptor, UnboostNoteAdaptor, GetBoostsAdaptor
+    },
+    transfer::{BoostNoteDto, CreateNoteDto, CreateReactionDto}
+};
+use axum::{Router, Json, extract::{State, Path}, http::StatusCode, response::IntoResponse, routing::{get, post}};
+use uuid::Uuid;
+
+use crate::{auth::{Authenticated, Viewer}, di::AppHandler, ServerError};
+
+pub fn notes() -> Router<AppHandler> {
+    Router::new()
+        .route("/", post(create))
+        .route("/:id", get(note).delete(delete))
+        .route("/:id/context", get(context))
+        .route("/:id/reactions", get(reactions).post(react).delete(unreact))
+        .route("/:id/boost", post(boost).delete(unboost))
+        .route("/:id/boosted_by", get(boosted_by))
+}
+
+async fn create(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Json(note): Json<CreateNoteDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:statuses")?;
+    let created = handler.note_create().create(*auth.account().as_ref(), note).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+async fn note(
+    State(handler): State<AppHandler>,
+    viewer: Viewer,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    let note = handler.note_get().get(viewer.account("read:statuses")?, id).await?;
+    Ok(Json(note))
+}
+
+async fn context(
+    State(handler): State<AppHandler>,
+    viewer: Viewer,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    let context = handler.note_context().context(viewer.account("read:statuses")?, id).await?;
+    Ok(Json(context))
+}
+
+async fn delete(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:statuses")?;
+    handler.note_delete().delete(*auth.account().as_ref(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn reactions(
+    State(handler): State<AppHandler>,
+    viewer: Viewer,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    let reactions = handler.reactions_get().reactions(viewer.account("read:statuses")?, id).await?;
+    Ok(Json(reactions))
+}
+
+async fn react(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>,
+    Json(reaction): Json<CreateReactionDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:reactions")?;
+    let reactions = handler.react().react(*auth.account().as_ref(), id, reaction).await?;
+    Ok(Json(reactions))
+}
+
+async fn unreact(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:reactions")?;
+    handler.unreact().unreact(*auth.account().as_ref(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// The body is optional, so that a plain POST boosts with the visibility of the note.
+async fn boost(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>,
+    boost: Option<Json<BoostNoteDto>>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:statuses")?;
+    let boost = boost.map(|Json(boost)| boost).unwrap_or_default();
+    let boost = handler.note_boost().boost(*auth.account().as_ref(), id, boost).await?;
+    Ok(Json(boost))
+}
+
+async fn unboost(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:statuses")?;
+    handler.note_unboost().unboost(*auth.account().as_ref(), id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn boosted_by(
+    State(handler): State<AppHandler>,
+    viewer: Viewer,
+    Path(id): Path<Uuid>
+) -> Result<impl IntoResponse, ServerError> {
+    let boosts = handler.boosts_get().boosted_by(viewer.account("read:statuses")?, id).await?;
+    Ok(Json(boosts))
+}
\ No newline at end of file
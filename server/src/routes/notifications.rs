@@ -0,0 +1,19 @@
+use application::{adaptor::GetNotificationsAdaptor, transfer::PaginationDto};
+use axum::{Router, Json, extract::{State, Query}, response::IntoResponse, routing::get};
+
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+pub fn notifications() -> Router<AppHandler> {
+    Router::new()
+        .route("/", get(list))
+}
+
+async fn list(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Query(page): Query<PaginationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("read:notifications")?;
+    let notifications = handler.notifications().notifications(*auth.account().as_ref(), page).await?;
+    Ok(Json(notifications))
+}
\ No newline at end of file
@@ -0,0 +1,69 @@
+use application::{
+    adaptor::{VerifyAuthorizationAdaptor, AuthorizeAdaptor, IssueTokenAdaptor, RevokeTokenAdaptor},
+    transfer::{AuthorizeDto, TokenRequestDto, RevokeTokenDto},
+    ApplicationError
+};
+use axum::{Router, Json, Form, extract::{State, Query}, http::StatusCode, response::IntoResponse, routing::{get, post}};
+use kernel::entities::RedirectUri;
+use serde_json::json;
+use url::Url;
+
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+pub fn oauth() -> Router<AppHandler> {
+    Router::new()
+        .route("/authorize", get(verify).post(authorize))
+        .route("/token", post(token))
+        .route("/revoke", post(revoke))
+}
+
+/// Validates the authorization request and returns what the consent screen should show.
+async fn verify(
+    State(handler): State<AppHandler>,
+    Query(request): Query<AuthorizeDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let request = handler.authorization_verify().verify(request).await?;
+    Ok(Json(request))
+}
+
+/// Called when the logged-in user approves the request.
+async fn authorize(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Json(request): Json<AuthorizeDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require_session()?;
+    let code = handler.authorize().authorize(*auth.account().as_ref(), request).await?;
+
+    if code.redirect_uri == RedirectUri::OUT_OF_BAND {
+        return Ok(Json(json!({ "code": code.code })));
+    }
+
+    let mut redirect = Url::parse(&code.redirect_uri)
+        .map_err(|e| ApplicationError::Convert(e.to_string()))?;
+    {
+        let mut query = redirect.query_pairs_mut();
+        query.append_pair("code", &code.code);
+        if let Some(state) = &code.state {
+            query.append_pair("state", state);
+        }
+    }
+
+    Ok(Json(json!({ "redirect_uri": redirect.as_str() })))
+}
+
+async fn token(
+    State(handler): State<AppHandler>,
+    Form(request): Form<TokenRequestDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let token = handler.token_issue().issue(request).await?;
+    Ok(Json(token))
+}
+
+async fn revoke(
+    State(handler): State<AppHandler>,
+    Form(request): Form<RevokeTokenDto>
+) -> Result<impl IntoResponse, ServerError> {
+    handler.token_revoke().revoke(request).await?;
+    Ok(StatusCode::OK)
+}
\ No newline at end of file
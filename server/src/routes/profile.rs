@@ -0,0 +1,32 @@
+use application::{
+    adaptor::{CreateProfileAdaptor, UpdateProfileAdaptor},
+    transfer::{CreateProfileDto, UpdateProfileDto}
+};
+use axum::{Router, Json, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+pub fn profile() -> Router<AppHandler> {
+    Router::new()
+        .route("/", post(create).patch(update))
+}
+
+async fn create(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Json(profile): Json<CreateProfileDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:accounts")?;
+    let created = handler.profile_create().create(*auth.account().as_ref(), profile).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+async fn update(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Json(profile): Json<UpdateProfileDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("write:accounts")?;
+    let updated = handler.profile_update().update(*auth.account().as_ref(), profile).await?;
+    Ok(Json(updated))
+}
\ No newline at end of file
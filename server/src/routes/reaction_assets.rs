@@ -0,0 +1,16 @@
+use application::adaptor::GetReactionAssetsAdaptor;
+use axum::{Router, Json, extract::State, response::IntoResponse, routing::get};
+
+use crate::{di::AppHandler, ServerError};
+
+pub fn reaction_assets() -> Router<AppHandler> {
+    Router::new()
+        .route("/", get(assets))
+}
+
+async fn assets(
+    State(handler): State<AppHandler>
+) -> Result<impl IntoResponse, ServerError> {
+    let assets = handler.reaction_assets_get().assets().await?;
+    Ok(Json(assets))
+}
\ No newline at end of file
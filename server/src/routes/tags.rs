@@ -0,0 +1,18 @@
+use application::{adaptor::GetHashtagNotesAdaptor, transfer::PaginationDto};
+use axum::{Router, Json, extract::{State, Path, Query}, response::IntoResponse, routing::get};
+
+use crate::{di::AppHandler, ServerError};
+
+pub fn tags() -> Router<AppHandler> {
+    Router::new()
+        .route("/:name/notes", get(notes))
+}
+
+async fn notes(
+    State(handler): State<AppHandler>,
+    Path(name): Path<String>,
+    Query(page): Query<PaginationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let notes = handler.hashtag_notes().notes(name, page).await?;
+    Ok(Json(notes))
+}
\ No newline at end of file
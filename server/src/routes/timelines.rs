@@ -0,0 +1,40 @@
+use application::{
+    adaptor::{GetHomeTimelineAdaptor, GetLocalTimelineAdaptor, GetFederatedTimelineAdaptor},
+    transfer::PaginationDto
+};
+use axum::{Router, Json, extract::{State, Query}, response::IntoResponse, routing::get};
+
+use crate::{auth::Authenticated, di::AppHandler, ServerError};
+
+pub fn timelines() -> Router<AppHandler> {
+    Router::new()
+        .route("/home", get(home))
+        .route("/local", get(local))
+        .route("/federated", get(federated))
+}
+
+async fn home(
+    State(handler): State<AppHandler>,
+    auth: Authenticated,
+    Query(page): Query<PaginationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    auth.require("read:statuses")?;
+    let entries = handler.home_timeline().home(*auth.account().as_ref(), page).await?;
+    Ok(Json(entries))
+}
+
+async fn local(
+    State(handler): State<AppHandler>,
+    Query(page): Query<PaginationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let entries = handler.local_timeline().local(page).await?;
+    Ok(Json(entries))
+}
+
+async fn federated(
+    State(handler): State<AppHandler>,
+    Query(page): Query<PaginationDto>
+) -> Result<impl IntoResponse, ServerError> {
+    let entries = handler.federated_timeline().federated(page).await?;
+    Ok(Json(entries))
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use application::adaptor::{WebFingerAdaptor, GetNodeInfoAdaptor};
+use axum::{
+    Router, Json,
+    extract::{State, Query},
+    http::header::{ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_TYPE},
+    response::IntoResponse,
+    routing::get
+};
+use serde::Deserialize;
+
+use crate::{di::AppHandler, ServerError};
+
+pub fn well_known() -> Router<AppHandler> {
+    Router::new()
+        .route("/webfinger", get(webfinger))
+        .route("/host-meta", get(host_meta))
+        .route("/nodeinfo", get(nodeinfo))
+}
+
+#[derive(Deserialize)]
+struct WebFingerQuery {
+    resource: String
+}
+
+async fn webfinger(
+    State(handler): State<AppHandler>,
+    Query(query): Query<WebFingerQuery>
+) -> Result<impl IntoResponse, ServerError> {
+    let jrd = handler.webfinger().webfinger(query.resource).await?;
+    Ok((
+        [(CONTENT_TYPE, "application/jrd+json"), (ACCESS_CONTROL_ALLOW_ORIGIN, "*")],
+        Json(jrd)
+    ))
+}
+
+async fn host_meta(
+    State(handler): State<AppHandler>
+) -> Result<impl IntoResponse, ServerError> {
+    let host_meta = handler.webfinger().host_meta().await?;
+    let xrd = format!(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#, "\n",
+        r#"<XRD xmlns="http://docs.oasis-open.org/ns/xri/xrd-1.0">"#, "\n",
+        r#"  <Link rel="lrdd" template="{}"/>"#, "\n",
+        r#"</XRD>"#, "\n"
+    ), host_meta.lrdd);
+    Ok((
+        [(CONTENT_TYPE, "application/xrd+xml"), (ACCESS_CONTROL_ALLOW_ORIGIN, "*")],
+        xrd
+    ))
+}
+
+async fn nodeinfo(
+    State(handler): State<AppHandler>
+) -> Result<impl IntoResponse, ServerError> {
+    let links = handler.nodeinfo().links().await?;
+    Ok(Json(links))
+}
\ No newline at end of file
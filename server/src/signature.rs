@@ -0,0 +1,61 @@
+use application::{adaptor::VerifySignatureAdaptor, transfer::SignedRequestDto};
+use axum::{
+    body::{Body, Bytes},
+    extract::{FromRequest, OriginalUri, State},
+    http::{header::HOST, Request},
+    middleware::Next,
+    response::{IntoResponse, Response}
+};
+
+use crate::{di::AppHandler, ServerError};
+
+/// Rejects requests without a valid HTTP Signature.
+/// The verified signer is available to handlers as `Extension<SignerDto>`.
+pub async fn verify_signature(
+    State(handler): State<AppHandler>,
+    request: Request<Body>,
+    next: Next<Body>
+) -> Response {
+    let (mut parts, body) = request.into_parts();
+
+    let body = match Bytes::from_request(Request::new(body), &()).await {
+        Ok(body) => body,
+        Err(rejection) => return rejection.into_response()
+    };
+
+    // Nested routers see the uri with their prefix stripped, but the signature covers the original one.
+    let uri = parts.extensions.get::<OriginalUri>()
+        .map(|original| original.0.clone())
+        .unwrap_or_else(|| parts.uri.clone());
+
+    let mut headers = std::collections::HashMap::<String, String>::new();
+    for (name, value) in &parts.headers {
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+        headers.entry(name.as_str().to_string())
+            .and_modify(|joined| {
+                joined.push_str(", ");
+                joined.push_str(value);
+            })
+            .or_insert_with(|| value.to_string());
+    }
+    if let Some(authority) = uri.authority().filter(|_| !parts.headers.contains_key(HOST)) {
+        headers.insert(HOST.as_str().to_string(), authority.to_string());
+    }
+
+    let signed = SignedRequestDto {
+        method: parts.method.to_string(),
+        path: uri.path_and_query().map_or("/", |path| path.as_str()).to_string(),
+        headers,
+        body: body.to_vec()
+    };
+
+    let signer = match handler.signature_verify().verify(signed).await {
+        Ok(signer) => signer,
+        Err(e) => return ServerError::from(e).into_response()
+    };
+
+    parts.extensions.insert(signer);
+    next.run(Request::from_parts(parts, Body::from(body))).await
+}
\ No newline at end of file
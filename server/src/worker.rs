@@ -0,0 +1,18 @@
+use crate::di::AppHandler;
+
+/// Workers started when `DELIVERY_WORKERS` is not set.
+const DEFAULT_DELIVERY_WORKERS: usize = 2;
+
+/// Starts the outbound delivery workers in the background, `DELIVERY_WORKERS` of them.
+pub fn spawn_delivery_workers(handler: &AppHandler) {
+    let workers = std::env::var("DELIVERY_WORKERS").ok()
+        .and_then(|workers| workers.parse().ok())
+        .unwrap_or(DEFAULT_DELIVERY_WORKERS);
+
+    for _ in 0..workers {
+        let handler = handler.clone();
+        tokio::spawn(async move {
+            handler.delivery_worker().run().await
+        });
+    }
+}
\ No newline at end of file